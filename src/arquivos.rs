@@ -0,0 +1,269 @@
+// --- MÓDULO ARQUIVOS ---
+// 'read_file' e 'edit_file': ler (ou resumir) um arquivo e editar via diff.
+// No 'edit_file' o Oráculo propõe um diff unificado, a gente pinta ele,
+// confere se aplica LIMPO, e só escreve depois do seu "s".
+// Antes de escrever, tira a foto do arquivo no diário de undo da tarefa.
+
+use crate::desfazer;
+use crate::eventos::{self, Desfecho};
+use crate::executor;
+use crate::msg;
+use crate::oraculo;
+use crate::resolvedor;
+use crate::risco::Nivel;
+use console::style;
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+
+// --- CONSTANTES (só dos Arquivos) ---
+const MAX_BYTES_ORACULO: usize = 100_000; // Arquivo maior que isso não vai pro Oráculo inteiro
+
+// --- CONTRATO ---
+
+// "main.rs:10-40" => caminho + faixa de linhas (1-based, inclusiva)
+#[derive(Debug, PartialEq)]
+pub struct Trecho {
+    pub caminho: String,
+    pub inicio: Option<usize>,
+    pub fim: Option<usize>,
+}
+
+// --- FUNÇÕES PÚBLICAS ---
+
+// Separa "arquivo:10-40" (ou "arquivo:10") em caminho e faixa.
+// (Se o arquivo existe COM os dois-pontos no nome, respeita o nome.
+// "Existe" é a partir da 'cwd' da sessão.)
+pub fn separar_trecho(texto: &str, cwd: &Path) -> Trecho {
+    let inteiro = Trecho {
+        caminho: texto.to_string(),
+        inicio: None,
+        fim: None,
+    };
+    if cwd.join(texto).exists() {
+        return inteiro;
+    }
+
+    let Some((caminho, faixa)) = texto.rsplit_once(':') else {
+        return inteiro;
+    };
+    if caminho.is_empty() {
+        return inteiro;
+    }
+
+    let (inicio, fim) = match faixa.split_once('-') {
+        Some((a, b)) => (a.trim().parse::<usize>().ok(), b.trim().parse::<usize>().ok()),
+        None => {
+            let n = faixa.trim().parse::<usize>().ok();
+            (n, n)
+        }
+    };
+
+    match (inicio, fim) {
+        (Some(a), Some(b)) if a >= 1 && b >= a => Trecho {
+            caminho: caminho.to_string(),
+            inicio: Some(a),
+            fim: Some(b),
+        },
+        _ => inteiro,
+    }
+}
+
+// Mostra o arquivo (ou o trecho) com número de linha.
+// Com "resumir": true nos ARGS, manda o trecho pro Oráculo resumir.
+pub async fn handle_read_file(args: Option<Value>, cwd: &Path) {
+    let Some(args_map) = args.as_ref().and_then(|a| a.as_object()) else {
+        eprintln!("{}", msg!("arquivos.sem_args", "read_file"));
+        return;
+    };
+    let Some(path) = args_map.get("path").and_then(|v| v.as_str()) else {
+        eprintln!("{}", msg!("main.sem_arquivo", "read_file"));
+        return;
+    };
+    let resumir = args_map.get("resumir").and_then(|v| v.as_bool()).unwrap_or(false);
+    let pedido = args_map.get("pedido").and_then(|v| v.as_str()).unwrap_or("");
+
+    let mut trecho = separar_trecho(path, cwd);
+    match resolvedor::resolver(&trecho.caminho, cwd).await {
+        Some(caminho) => trecho.caminho = caminho,
+        None => return,
+    }
+
+    let conteudo = match ler_texto(&cwd.join(&trecho.caminho)) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("{}", msg!("geral.erro", e));
+            return;
+        }
+    };
+
+    let linhas: Vec<&str> = conteudo.lines().collect();
+    let inicio = trecho.inicio.unwrap_or(1);
+    let fim = trecho.fim.unwrap_or(linhas.len()).min(linhas.len());
+    if inicio > linhas.len() {
+        eprintln!("{}", msg!("arquivos.so_tem", trecho.caminho, linhas.len()));
+        return;
+    }
+    let selecionadas = &linhas[inicio - 1..fim];
+
+    println!("{}", msg!("arquivos.trecho", trecho.caminho, inicio, fim, linhas.len()));
+    let largura = fim.to_string().len();
+    for (i, linha) in selecionadas.iter().enumerate() {
+        println!("{} {}", style(format!("{:>largura$} │", inicio + i)).dim(), linha);
+    }
+    println!("{}", msg!("geral.separador"));
+
+    if !resumir {
+        return;
+    }
+
+    let mut texto = selecionadas.join("\n");
+    if texto.len() > MAX_BYTES_ORACULO {
+        let mut corte = MAX_BYTES_ORACULO;
+        while !texto.is_char_boundary(corte) {
+            corte -= 1;
+        }
+        texto.truncate(corte);
+        println!("{}", msg!("arquivos.grandao", corte));
+    }
+
+    println!("{}", msg!("arquivos.pedindo_resumo"));
+    match oraculo::resumir_arquivo(&trecho.caminho, &texto, pedido).await {
+        Ok(resumo) => println!("{}", msg!("arquivos.resumo", resumo)),
+        Err(e) => eprintln!("{}", msg!("arquivos.erro_resumo", e)),
+    }
+}
+
+// Pede o diff pro Oráculo, pinta, confere se aplica e (com o seu "s") escreve.
+// No dry-run mostra o diff e para por aí. No não-interativo o diff só se
+// aplica sozinho se o 'risco' da tarefa cabe no 'confirmacao.risco_maximo'.
+pub async fn handle_edit_file(args: Option<Value>, cwd: &Path, dry_run: bool) {
+    let Some(args_map) = args.as_ref().and_then(|a| a.as_object()) else {
+        eprintln!("{}", msg!("arquivos.sem_args", "edit_file"));
+        return;
+    };
+    let Some(path) = args_map.get("path").and_then(|v| v.as_str()) else {
+        eprintln!("{}", msg!("main.sem_arquivo", "edit_file"));
+        return;
+    };
+    let instrucao = args_map.get("instrucao").and_then(|v| v.as_str()).unwrap_or("");
+    let id = args_map.get("task_id").and_then(|v| v.as_str()).unwrap_or("sem-id");
+    // Sem risco nos ARGS, vale o pior caso
+    let risco = args_map
+        .get("risco")
+        .and_then(|v| serde_json::from_value::<Nivel>(v.clone()).ok())
+        .unwrap_or(Nivel::Alto);
+
+    let Some(caminho) = resolvedor::resolver(path, cwd).await else {
+        return;
+    };
+    let completo = cwd.join(&caminho);
+
+    let original = match ler_texto(&completo) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("{}", msg!("geral.erro", e));
+            return;
+        }
+    };
+    if original.len() > MAX_BYTES_ORACULO {
+        eprintln!("{}", msg!("arquivos.grande_demais", caminho, original.len(), MAX_BYTES_ORACULO));
+        return;
+    }
+
+    println!("{}", msg!("arquivos.pedindo_diff"));
+    let diff = match oraculo::propor_diff(&caminho, &original, instrucao).await {
+        Ok(d) => d,
+        Err(e) => {
+            eprintln!("{}", msg!("arquivos.erro_diff", e));
+            return;
+        }
+    };
+
+    println!("{}", msg!("arquivos.diff_titulo", caminho));
+    mostrar_diff(&diff);
+    println!("{}", msg!("geral.separador"));
+
+    // Confere se aplica LIMPO antes de perguntar qualquer coisa
+    let novo = match aplicar_diff(&original, &diff) {
+        Ok(n) => n,
+        Err(e) => {
+            eprintln!("{}", msg!("arquivos.nao_aplica", caminho, e));
+            eprintln!("{}", msg!("arquivos.nada_escrito"));
+            return;
+        }
+    };
+    if novo == original {
+        println!("{}", msg!("arquivos.nao_muda"));
+        return;
+    }
+
+    if dry_run {
+        println!("{}", msg!("arquivos.dry_run"));
+        if let Err(e) = executor::log_evento("DRY-RUN", &json!({ "edit_file": caminho, "diff": diff })) {
+            eprintln!("{}", msg!("executor.erro_log_dry_run", e));
+        }
+        return;
+    }
+
+    if !executor::confirmar_passo(&msg!("arquivos.aplica"), executor::risco_liberado(risco)).await {
+        println!("{}", msg!("arquivos.intocado"));
+        return;
+    }
+
+    // Sem foto, sem edição
+    let diario = match desfazer::abrir(id, "edit_file", instrucao, std::slice::from_ref(&completo)) {
+        Ok(d) => d,
+        Err(e) => {
+            eprintln!("{}", msg!("arquivos.sem_undo", e));
+            return;
+        }
+    };
+
+    // Só sela o que foi escrito: falhou, o diário vai embora (não tem o que desfazer)
+    match fs::write(&completo, &novo) {
+        Ok(_) => {
+            diario.selar();
+            println!("{}", msg!("arquivos.feito", caminho));
+            let evento = json!({ "id": id, "arquivo": completo.display().to_string(), "diff": diff });
+            if let Err(e) = executor::log_evento("EDIT", &evento) {
+                eprintln!("{}", msg!("arquivos.erro_log_edicao", e));
+            }
+        }
+        Err(e) => {
+            diario.descartar();
+            eprintln!("{}", msg!("arquivos.erro_escrever", caminho, e));
+            eventos::marcar(Desfecho::Falha);
+        }
+    }
+}
+
+// --- FUNÇÕES PRIVADAS ---
+
+fn ler_texto(caminho: &Path) -> Result<String, String> {
+    let bytes = fs::read(caminho).map_err(|e| msg!("arquivos.nao_leu", caminho.display(), e))?;
+    String::from_utf8(bytes).map_err(|_| msg!("arquivos.nao_texto", caminho.display()))
+}
+
+fn aplicar_diff(original: &str, diff: &str) -> Result<String, String> {
+    let patch = diffy::Patch::from_str(diff).map_err(|e| e.to_string())?;
+    diffy::apply(original, &patch).map_err(|e| e.to_string())
+}
+
+// Verde = entra, vermelho = sai, ciano = hunk
+fn mostrar_diff(diff: &str) {
+    for linha in diff.lines() {
+        let pintada = if linha.starts_with("+++") || linha.starts_with("---") {
+            style(linha).bold()
+        } else if linha.starts_with("@@") {
+            style(linha).cyan()
+        } else if linha.starts_with('+') {
+            style(linha).green()
+        } else if linha.starts_with('-') {
+            style(linha).red()
+        } else {
+            style(linha)
+        };
+        println!("{}", pintada);
+    }
+}
@@ -0,0 +1,258 @@
+// --- MÓDULO COMANDOS (DO REPL) ---
+// Tirando o 'sair', o modo interativo mandava TUDO pro Oráculo.
+// Agora o que começa com ':' é nosso: resolve aqui mesmo, sem nuvem.
+// A tabela abaixo é a fonte da verdade: o ':help', o Tab e o
+// "esse comando não existe" saem todos dela.
+// (Os que mexem na sessão, tipo ':set' e ':retry', o main resolve.)
+
+use crate::config;
+use crate::escopo::Escopo;
+use crate::executor;
+use crate::mensagens;
+use crate::msg;
+use crate::parada;
+use std::env;
+use std::path::Path;
+
+// --- CONSTANTES (só dos Comandos) ---
+const HISTORICO_PADRAO: usize = 20;
+
+// O que um engagement pode ter (tudo relativo à pasta onde o Fenrir abriu)
+// (O "o que é" e a ajuda dos comandos são chaves do catálogo das mensagens)
+const ARQUIVOS_ENGAGEMENT: &[(&str, &str)] = &[
+    (".fenrir.toml", "engagement.config"),
+    (".fenrir/politica.toml", "engagement.politica"),
+    (".fenrir/limites.toml", "engagement.limites"),
+    (".fenrir/escopo.toml", "engagement.escopo"),
+    (".fenrir/variaveis.toml", "engagement.variaveis"),
+    (".fenrir/macros.toml", "engagement.macros"),
+    (".fenrir/segredos.toml", "engagement.segredos"),
+    (".fenrir/segredos.json", "engagement.mapa_segredos"),
+    (".fenrir/historico", "engagement.historico"),
+    (".fenrir/undo", "engagement.undo"),
+];
+const COMANDOS: &[Comando] = &[
+    Comando { nome: ":help", apelidos: &[":ajuda", "?"], uso: "", ajuda: "cmd.help", meta: Meta::Ajuda },
+    Comando { nome: ":history", apelidos: &[":historico"], uso: "[n]", ajuda: "cmd.history", meta: Meta::Historico },
+    Comando { nome: ":jobs", apelidos: &[], uso: "", ajuda: "cmd.jobs", meta: Meta::Jobs },
+    Comando { nome: ":stop-all", apelidos: &[], uso: "", ajuda: "cmd.stop-all", meta: Meta::PararTudo },
+    Comando { nome: ":set", apelidos: &[], uso: "[chave=valor | NOME=valor]", ajuda: "cmd.set", meta: Meta::Set },
+    Comando { nome: ":backend", apelidos: &[], uso: "[binário [modelo]]", ajuda: "cmd.backend", meta: Meta::Backend },
+    Comando { nome: ":scope", apelidos: &[":escopo"], uso: "[add|rm alvo... | clear]", ajuda: "cmd.scope", meta: Meta::Escopo },
+    Comando { nome: ":engagement", apelidos: &[], uso: "", ajuda: "cmd.engagement", meta: Meta::Engagement },
+    Comando { nome: ":dry-run", apelidos: &[], uso: "[on|off]", ajuda: "cmd.dry-run", meta: Meta::DryRun },
+    Comando { nome: ":last", apelidos: &[":ultima"], uso: "", ajuda: "cmd.last", meta: Meta::Ultima },
+    Comando { nome: ":retry", apelidos: &[], uso: "", ajuda: "cmd.retry", meta: Meta::Repetir },
+    Comando { nome: ":explain", apelidos: &[":explica"], uso: "", ajuda: "cmd.explain", meta: Meta::Explicar },
+    Comando { nome: ":macro", apelidos: &[], uso: "[nome [args...]]", ajuda: "cmd.macro", meta: Meta::Macro },
+    Comando { nome: ":save-macro", apelidos: &[], uso: "<nome> [n]", ajuda: "cmd.save-macro", meta: Meta::SalvarMacro },
+    Comando { nome: ":undo", apelidos: &[], uso: "[lista|id]", ajuda: "cmd.undo", meta: Meta::Desfazer },
+    Comando { nome: "sair", apelidos: &["exit", ":quit", ":q"], uso: "", ajuda: "cmd.sair", meta: Meta::Sair },
+];
+
+// --- CONTRATO ---
+
+// O que o REPL sabe fazer sozinho
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Meta {
+    Ajuda,
+    Historico,
+    Jobs,
+    PararTudo,
+    Set,
+    Backend,
+    Escopo,
+    Engagement,
+    DryRun,
+    Ultima,
+    Repetir,
+    Explicar,
+    Macro,
+    SalvarMacro,
+    Desfazer,
+    Sair,
+}
+
+pub struct Comando {
+    pub nome: &'static str,
+    pub apelidos: &'static [&'static str],
+    pub uso: &'static str, // Os argumentos (vazio = não tem)
+    pub ajuda: &'static str,
+    pub meta: Meta,
+}
+
+// --- FUNÇÕES PÚBLICAS ---
+
+// É comando do REPL? None = não é (vai pro Oráculo).
+// Some(Ok((meta, argumentos))) ou Some(Err) pra ':coisa' que não existe.
+pub fn interpretar(entrada: &str) -> Option<Result<(Meta, &str), String>> {
+    let entrada = entrada.trim();
+    let (nome, resto) = entrada.split_once(char::is_whitespace).unwrap_or((entrada, ""));
+    let nome = nome.to_lowercase();
+
+    let achado = COMANDOS
+        .iter()
+        .find(|c| c.nome == nome || c.apelidos.contains(&nome.as_str()));
+    match achado {
+        Some(comando) => Some(Ok((comando.meta, resto.trim()))),
+        None if nome.starts_with(':') => Some(Err(msg!("comandos.nao_existe", nome))),
+        None => None, // Pedido normal ("escaneia 10.0.0.1")
+    }
+}
+
+// O ':help' (alinhado, saído da tabela)
+pub fn imprimir_ajuda() {
+    let assinaturas: Vec<String> = COMANDOS
+        .iter()
+        .map(|c| if c.uso.is_empty() { c.nome.to_string() } else { format!("{} {}", c.nome, c.uso) })
+        .collect();
+    let largura = assinaturas.iter().map(|a| a.chars().count()).max().unwrap_or(0);
+
+    println!("{}", msg!("comandos.ajuda_titulo"));
+    for (comando, assinatura) in COMANDOS.iter().zip(&assinaturas) {
+        let apelidos = if comando.apelidos.is_empty() {
+            String::new()
+        } else {
+            msg!("comandos.apelidos", comando.apelidos.join(", "))
+        };
+        println!("{:largura$}  {}{}", assinatura, mensagens::texto(comando.ajuda), apelidos, largura = largura);
+    }
+    println!("{}", msg!("comandos.ajuda_rodape"));
+}
+
+// O uso de um comando ("Uso: :set [chave=valor]"), pra quando errarem o argumento
+pub fn uso(meta: Meta) -> String {
+    COMANDOS
+        .iter()
+        .find(|c| c.meta == meta)
+        .map(|c| msg!("comandos.uso", format!("{} {}", c.nome, c.uso).trim_end()))
+        .unwrap_or_default()
+}
+
+// Os nomes pro Tab
+pub fn nomes() -> Vec<String> {
+    COMANDOS.iter().map(|c| c.nome.to_string()).collect()
+}
+
+// ':history [n]'
+pub fn mostrar_historico(historico: &[String], argumentos: &str) {
+    let quantos = if argumentos.is_empty() { Ok(HISTORICO_PADRAO) } else { argumentos.parse::<usize>() };
+    let Ok(quantos) = quantos else {
+        println!("{}", uso(Meta::Historico));
+        return;
+    };
+    let inicio = historico.len().saturating_sub(quantos);
+    for (i, linha) in historico.iter().enumerate().skip(inicio) {
+        println!("{:>5}  {}", i + 1, linha);
+    }
+}
+
+// ':jobs' (os mesmos que o ':stop-all' derrubaria)
+pub fn mostrar_jobs() {
+    let jobs = parada::em_andamento();
+    if jobs.is_empty() {
+        println!("{}", msg!("comandos.sem_jobs"));
+        return;
+    }
+    for job in jobs {
+        let dono = if job.dono == std::process::id() { msg!("comandos.job_daqui") } else { msg!("comandos.job_de", job.dono) };
+        println!("{}", msg!("comandos.job", job.pgid, job.rotulo, job.inicio, dono));
+    }
+}
+
+// ':backend' mostra; ':backend <binário> [modelo]' troca (só nessa sessão)
+pub fn backend(argumentos: &str) {
+    let mut partes = argumentos.split_whitespace();
+    if let Some(binario) = partes.next() {
+        let troca = config::definir("oraculo.binario", binario, ":backend").and_then(|_| match partes.next() {
+            Some(modelo) => config::definir("oraculo.modelo", modelo, ":backend"),
+            None => Ok(()),
+        });
+        if let Err(e) = troca {
+            eprintln!("{}", msg!("geral.erro", e));
+            return;
+        }
+    }
+    let oraculo = config::atual().oraculo;
+    let modelo = if oraculo.modelo.is_empty() { msg!("comandos.modelo_padrao") } else { oraculo.modelo.clone() };
+    println!("{}", msg!("comandos.backend", oraculo.binario, modelo, oraculo.timeout_segundos));
+}
+
+// ':scope' mostra; 'add'/'rm' mexem (e já salvam); 'clear' zera
+pub fn editar_escopo(argumentos: &str, escopo: &mut Escopo) {
+    let mut partes = argumentos.split_whitespace();
+    let mudou = match partes.next() {
+        None => false,
+        Some("add") => {
+            for alvo in partes {
+                if !escopo.alvos.iter().any(|a| a == alvo) {
+                    escopo.alvos.push(alvo.to_string());
+                }
+            }
+            true
+        }
+        Some("rm") => {
+            let tirar: Vec<&str> = partes.collect();
+            escopo.alvos.retain(|a| !tirar.contains(&a.as_str()));
+            true
+        }
+        Some("clear") => {
+            escopo.alvos.clear();
+            true
+        }
+        Some(_) => {
+            println!("{}", uso(Meta::Escopo));
+            return;
+        }
+    };
+    if mudou {
+        if let Err(e) = escopo.salvar() {
+            eprintln!("{}", msg!("comandos.erro_salvar_escopo", e));
+        }
+    }
+
+    if escopo.alvos.is_empty() {
+        println!("{}", msg!("comandos.escopo_vazio"));
+    } else {
+        println!("{}", msg!("comandos.escopo", Escopo::arquivo()));
+        for alvo in &escopo.alvos {
+            println!("  - {}", alvo);
+        }
+    }
+}
+
+// ':engagement': onde a gente tá e o que tem configurado aqui
+pub fn mostrar_engagement(escopo: &Escopo) {
+    let pasta = env::current_dir().map(|p| p.display().to_string()).unwrap_or_else(|_| "?".to_string());
+    println!("{}", msg!("comandos.engagement_titulo"));
+    println!("{}", msg!("comandos.pasta", pasta));
+    for (arquivo, oque) in ARQUIVOS_ENGAGEMENT {
+        let marca = if Path::new(arquivo).exists() { "✓" } else { "·" };
+        println!("  {} {:24} {}", marca, arquivo, mensagens::texto(oque));
+    }
+    let config = config::atual();
+    println!("{}", msg!("comandos.log_saidas", config.caminhos.log, config.caminhos.saida));
+    println!("{}", msg!("comandos.escopo_jobs", escopo.alvos.len(), parada::em_andamento().len()));
+    println!("{}", msg!("comandos.engagement_dica"));
+    println!("{}", msg!("comandos.engagement_rodape"));
+}
+
+// ':last': o finalzinho do que o último comando cuspiu
+pub fn mostrar_ultima_saida() {
+    let Some(saida) = executor::ultima_saida() else {
+        println!("{}", msg!("comandos.nada_rodou"));
+        return;
+    };
+    println!("{}", msg!("comandos.ultima_titulo", saida.rotulo, saida.desfecho));
+    if !saida.texto.trim().is_empty() {
+        println!("{}", saida.texto.trim_end());
+    } else if !saida.guardada {
+        println!("{}", msg!("comandos.foi_direto"));
+    } else if saida.arquivo.is_none() {
+        println!("{}", msg!("comandos.nao_cuspiu"));
+    }
+    if let Some(arquivo) = &saida.arquivo {
+        println!("{}", msg!("comandos.saida_completa", arquivo));
+    }
+    println!("{}", msg!("geral.separador"));
+}
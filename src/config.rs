@@ -0,0 +1,441 @@
+// --- MÓDULO CONFIG ---
+// Tudo era constante: o binário do Oráculo, o timeout, o log, a wordlist...
+// Agora vem em camadas, a de baixo perde pra de cima:
+//
+//   padrão < ~/.config/fenrir/config.toml < ./.fenrir.toml < ambiente < linha de comando
+//
+// Exemplo ('./.fenrir.toml'):
+//
+//   [oraculo]
+//   timeout_segundos = 120
+//
+//   [ferramentas]
+//   wordlist_gobuster = "/usr/share/seclists/Discovery/Web-Content/common.txt"
+//
+// 'fenrir config show' mostra o valor que vale e DE ONDE ele veio.
+// (Regras da política e limites continuam nos arquivos deles.)
+//
+// O './.fenrir.toml' vem junto com o repositório que você clonou: ele NÃO
+// escolhe o binário do Oráculo nem afrouxa a confirmação/sandbox
+// (ver 'SO_DO_USUARIO'). Essas só na config do usuário, no ambiente ou na CLI.
+// (O 'allow' da '.fenrir/politica.toml' também não vale sozinho: ver 'allow_do_projeto'.)
+
+use crate::mensagens::{self, Idioma};
+use crate::msg;
+use crate::politica::Acao;
+use crate::risco::Nivel;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, RwLock};
+use toml::Value;
+
+// --- CONSTANTES (só da Config) ---
+const ARQUIVO_USUARIO: &str = ".config/fenrir/config.toml"; // (relativo ao $HOME)
+const ARQUIVO_PROJETO: &str = ".fenrir.toml";
+const ORIGEM_PADRAO: &str = "padrão";
+
+// Chaves que o './.fenrir.toml' não pode mexer (repo malicioso trocando o
+// 'gemini' por um script, ou ligando o "aprova tudo sozinho")
+const SO_DO_USUARIO: &[&str] = &[
+    "oraculo.binario",
+    "confirmacao.acao_padrao",
+    "confirmacao.frase_padrao",
+    "confirmacao.nao_interativo",
+    "confirmacao.risco_maximo",
+    "confirmacao.allow_do_projeto",
+    "confirmacao.sandbox",
+    "confirmacao.sandbox_rede",
+    "confirmacao.sandbox_cwd",
+];
+
+// Toda chave que existe: (nome, variável de ambiente, pra que serve)
+// (O "pra que serve" é a chave do texto no catálogo das mensagens)
+const CHAVES: &[(&str, Option<&str>, &str)] = &[
+    ("oraculo.binario", Some("FENRIR_ORACULO"), "config.ajuda.oraculo.binario"),
+    ("oraculo.modelo", Some("FENRIR_MODELO"), "config.ajuda.oraculo.modelo"),
+    ("oraculo.timeout_segundos", Some("FENRIR_ORACULO_TIMEOUT"), "config.ajuda.oraculo.timeout_segundos"),
+    ("caminhos.log", Some("FENRIR_LOG"), "config.ajuda.caminhos.log"),
+    ("caminhos.saida", Some("FENRIR_SAIDA"), "config.ajuda.caminhos.saida"),
+    ("ferramentas.wordlist_gobuster", Some("FENRIR_WORDLIST"), "config.ajuda.ferramentas.wordlist_gobuster"),
+    ("ferramentas.paralelo_fila", Some("FENRIR_PARALELO"), "config.ajuda.ferramentas.paralelo_fila"),
+    ("confirmacao.acao_padrao", None, "config.ajuda.confirmacao.acao_padrao"),
+    ("confirmacao.frase_padrao", None, "config.ajuda.confirmacao.frase_padrao"),
+    ("confirmacao.dry_run", Some("FENRIR_DRY_RUN"), "config.ajuda.confirmacao.dry_run"),
+    ("confirmacao.nao_interativo", Some("FENRIR_NAO_INTERATIVO"), "config.ajuda.confirmacao.nao_interativo"),
+    ("confirmacao.risco_maximo", Some("FENRIR_RISCO_MAXIMO"), "config.ajuda.confirmacao.risco_maximo"),
+    ("confirmacao.allow_do_projeto", None, "config.ajuda.confirmacao.allow_do_projeto"),
+    ("confirmacao.sandbox", Some("FENRIR_SANDBOX"), "config.ajuda.confirmacao.sandbox"),
+    ("confirmacao.sandbox_rede", Some("FENRIR_SANDBOX_REDE"), "config.ajuda.confirmacao.sandbox_rede"),
+    ("confirmacao.sandbox_cwd", Some("FENRIR_SANDBOX_CWD"), "config.ajuda.confirmacao.sandbox_cwd"),
+    ("interface.cor", Some("FENRIR_COR"), "config.ajuda.interface.cor"),
+    ("interface.spinner", Some("FENRIR_SPINNER"), "config.ajuda.interface.spinner"),
+    ("interface.idioma", Some("FENRIR_IDIOMA"), "config.ajuda.interface.idioma"),
+];
+
+// --- CONTRATO ---
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Config {
+    pub oraculo: ConfigOraculo,
+    pub caminhos: ConfigCaminhos,
+    pub ferramentas: ConfigFerramentas,
+    pub confirmacao: ConfigConfirmacao,
+    pub interface: ConfigInterface,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigOraculo {
+    pub binario: String,
+    pub modelo: String,
+    pub timeout_segundos: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigCaminhos {
+    pub log: String,
+    pub saida: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigFerramentas {
+    pub wordlist_gobuster: String,
+    pub paralelo_fila: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigConfirmacao {
+    pub acao_padrao: Acao,
+    pub frase_padrao: String,
+    pub dry_run: bool,
+    pub nao_interativo: bool,
+    pub risco_maximo: Nivel,
+    pub allow_do_projeto: bool, // 'allow' da política do engagement vale (senão vira 'confirm')
+    pub sandbox: bool,
+    pub sandbox_rede: bool,
+    pub sandbox_cwd: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigInterface {
+    pub cor: bool,
+    pub spinner: bool,
+    pub idioma: Idioma,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            oraculo: ConfigOraculo {
+                binario: "gemini".to_string(),
+                modelo: String::new(),
+                timeout_segundos: 60,
+            },
+            caminhos: ConfigCaminhos {
+                log: "fenrir_tasks.log".to_string(),
+                saida: "fenrir_logs".to_string(),
+            },
+            ferramentas: ConfigFerramentas {
+                wordlist_gobuster: "/usr/share/wordlists/dirbuster/directory-list-2.3-medium.txt".to_string(),
+                paralelo_fila: 4,
+            },
+            confirmacao: ConfigConfirmacao {
+                acao_padrao: Acao::Confirm,
+                frase_padrao: "EU ASSUMO".to_string(),
+                dry_run: false,
+                nao_interativo: false,
+                risco_maximo: Nivel::Baixo,
+                allow_do_projeto: false,
+                sandbox: false,
+                sandbox_rede: false,
+                sandbox_cwd: false,
+            },
+            interface: ConfigInterface {
+                cor: true,
+                spinner: true,
+                idioma: Idioma::PtBr,
+            },
+        }
+    }
+}
+
+// Um valor e de onde ele veio
+#[derive(Debug, Clone)]
+struct Valor {
+    valor: Value,
+    origem: String,
+}
+
+// A config em camadas (valores planos 'secao.chave') + a versão tipada
+#[derive(Debug)]
+struct Camadas {
+    valores: BTreeMap<String, Valor>,
+    efetiva: Config,
+}
+
+// --- FUNÇÕES PÚBLICAS ---
+
+// Monta a config (uma vez, no começo). 'da_linha' = o que veio da linha de
+// comando: (chave, valor, origem), ex: ("confirmacao.dry_run", "true", "--dry-run").
+pub fn carregar(da_linha: &[(String, String, String)]) {
+    let mut camadas = Camadas::padrao();
+
+    for caminho in arquivos_de_config() {
+        let Ok(conteudo) = fs::read_to_string(&caminho) else {
+            continue; // Não existe? Sem problema.
+        };
+        match conteudo.parse::<toml::Table>() {
+            Ok(tabela) => {
+                let origem = caminho.display().to_string();
+                let projeto = caminho == Path::new(ARQUIVO_PROJETO);
+                for aviso in camadas.aplicar_arquivo(tabela, &origem, projeto) {
+                    eprintln!("{}", aviso);
+                }
+            }
+            Err(e) => eprintln!("{}", msg!("config.zoada", caminho.display(), e)),
+        }
+    }
+
+    for (chave, variavel, _) in CHAVES {
+        let Some(variavel) = variavel else { continue };
+        if let Ok(texto) = env::var(variavel) {
+            if let Err(e) = camadas.definir_texto(chave, &texto, &format!("${}", variavel)) {
+                eprintln!("{}", msg!("geral.aviso", e));
+            }
+        }
+    }
+
+    for (chave, texto, origem) in da_linha {
+        if let Err(e) = camadas.definir_texto(chave, texto, origem) {
+            eprintln!("{}", msg!("geral.aviso", e));
+        }
+    }
+
+    mensagens::usar(camadas.efetiva.interface.idioma);
+    *global().write().unwrap_or_else(|e| e.into_inner()) = camadas;
+}
+
+// A config que vale agora (cópia: barata, e ninguém segura a trava)
+pub fn atual() -> Config {
+    global().read().unwrap_or_else(|e| e.into_inner()).efetiva.clone()
+}
+
+// Muda UMA chave em tempo de execução (o ':set' do REPL). Vale até o Fenrir sair.
+pub fn definir(chave: &str, texto: &str, origem: &str) -> Result<(), String> {
+    let mut camadas = global().write().unwrap_or_else(|e| e.into_inner());
+    camadas.definir_texto(chave, texto, origem)?;
+    mensagens::usar(camadas.efetiva.interface.idioma); // ':set interface.idioma=en' vale na hora
+    Ok(())
+}
+
+// 'fenrir config show': cada chave, o valor e de onde veio
+pub fn mostrar() {
+    let camadas = global().read().unwrap_or_else(|e| e.into_inner());
+    println!("{}", msg!("config.titulo"));
+    let largura = CHAVES.iter().map(|(c, _, _)| c.len()).max().unwrap_or(0);
+    for (chave, variavel, ajuda) in CHAVES {
+        let Some(v) = camadas.valores.get(*chave) else { continue };
+        let origem = if v.origem == ORIGEM_PADRAO { msg!("config.origem_padrao") } else { v.origem.clone() };
+        println!("{:largura$} = {:<30} ({})", chave, v.valor.to_string(), origem, largura = largura);
+        let env = variavel.map(|e| format!(" [{}]", e)).unwrap_or_default();
+        println!("{:largura$}   # {}{}", "", mensagens::texto(ajuda), env, largura = largura);
+    }
+    println!("{}", msg!("config.arquivos"));
+    for caminho in arquivos_de_config() {
+        let situacao = if caminho.is_file() { msg!("config.carregado") } else { msg!("config.nao_existe") };
+        println!("{} ({})", caminho.display(), situacao);
+    }
+    println!("{}", msg!("geral.separador"));
+}
+
+// --- FUNÇÕES PRIVADAS ---
+
+fn global() -> &'static RwLock<Camadas> {
+    static CONFIG: OnceLock<RwLock<Camadas>> = OnceLock::new();
+    CONFIG.get_or_init(|| RwLock::new(Camadas::padrao()))
+}
+
+impl Camadas {
+    fn padrao() -> Camadas {
+        let efetiva = Config::default();
+        let mut planos = Vec::new();
+        achatar("", &Value::try_from(&efetiva).expect("config padrão vira TOML"), &mut planos);
+        let valores = planos
+            .into_iter()
+            .map(|(chave, valor)| (chave, Valor { valor, origem: ORIGEM_PADRAO.to_string() }))
+            .collect();
+        Camadas { valores, efetiva }
+    }
+
+    // Um arquivo TOML inteiro. Do projeto? As 'SO_DO_USUARIO' ficam de fora.
+    // Devolve os avisos (quem chama mostra).
+    fn aplicar_arquivo(&mut self, tabela: toml::Table, origem: &str, projeto: bool) -> Vec<String> {
+        let mut planos = Vec::new();
+        achatar("", &Value::Table(tabela), &mut planos);
+        let mut avisos = Vec::new();
+        for (chave, valor) in planos {
+            if projeto && SO_DO_USUARIO.contains(&chave.as_str()) {
+                avisos.push(msg!("config.so_do_usuario", origem, chave));
+                continue;
+            }
+            if let Err(e) = self.definir(&chave, valor, origem) {
+                avisos.push(msg!("config.aviso_em", e, origem));
+            }
+        }
+        avisos
+    }
+
+    // Valor já tipado (veio de arquivo TOML): tem que bater com o tipo do padrão
+    fn definir(&mut self, chave: &str, valor: Value, origem: &str) -> Result<(), String> {
+        let atual = self
+            .valores
+            .get(chave)
+            .ok_or_else(|| msg!("config.chave_nao_existe", chave))?;
+        if atual.valor.type_str() != valor.type_str() {
+            return Err(msg!("config.tipo_errado", chave, atual.valor.type_str(), valor.type_str()));
+        }
+
+        let anterior = self.valores.insert(chave.to_string(), Valor { valor, origem: origem.to_string() });
+        match self.montar() {
+            Ok(efetiva) => {
+                self.efetiva = efetiva;
+                Ok(())
+            }
+            Err(e) => {
+                // Não encaixou (ex: acao_padrao = "talvez"): desfaz
+                if let Some(anterior) = anterior {
+                    self.valores.insert(chave.to_string(), anterior);
+                }
+                Err(msg!("config.invalido", chave, e))
+            }
+        }
+    }
+
+    // Valor em texto (ambiente, linha de comando, ':set'): converte pro tipo do padrão
+    fn definir_texto(&mut self, chave: &str, texto: &str, origem: &str) -> Result<(), String> {
+        let tipo = self
+            .valores
+            .get(chave)
+            .map(|v| v.valor.type_str())
+            .ok_or_else(|| msg!("config.chave_nao_existe", chave))?;
+        let valor = match tipo {
+            "boolean" => Value::Boolean(ler_booleano(texto).ok_or_else(|| msg!("config.quer_booleano", chave, texto))?),
+            "integer" => Value::Integer(texto.trim().parse().map_err(|_| msg!("config.quer_numero", chave, texto))?),
+            _ => Value::String(texto.to_string()),
+        };
+        self.definir(chave, valor, origem)
+    }
+
+    // Os valores planos de volta pra struct tipada
+    fn montar(&self) -> Result<Config, String> {
+        let mut raiz = toml::Table::new();
+        for (chave, v) in &self.valores {
+            let (secao, nome) = chave.split_once('.').unwrap_or(("", chave));
+            let tabela = raiz
+                .entry(secao.to_string())
+                .or_insert_with(|| Value::Table(toml::Table::new()));
+            if let Value::Table(t) = tabela {
+                t.insert(nome.to_string(), v.valor.clone());
+            }
+        }
+        Value::Table(raiz).try_into().map_err(|e: toml::de::Error| e.message().to_string())
+    }
+}
+
+// { oraculo = { binario = "x" } } => [("oraculo.binario", "x")]
+fn achatar(prefixo: &str, valor: &Value, saida: &mut Vec<(String, Value)>) {
+    match valor {
+        Value::Table(tabela) => {
+            for (nome, filho) in tabela {
+                let chave = if prefixo.is_empty() { nome.clone() } else { format!("{}.{}", prefixo, nome) };
+                achatar(&chave, filho, saida);
+            }
+        }
+        folha => saida.push((prefixo.to_string(), folha.clone())),
+    }
+}
+
+fn ler_booleano(texto: &str) -> Option<bool> {
+    match texto.trim().to_lowercase().as_str() {
+        "1" | "true" | "sim" | "on" | "s" => Some(true),
+        "0" | "false" | "nao" | "não" | "off" | "n" | "" => Some(false),
+        _ => None,
+    }
+}
+
+fn arquivos_de_config() -> Vec<PathBuf> {
+    let mut arquivos = Vec::new();
+    if let Some(home) = env::var_os("HOME") {
+        arquivos.push(PathBuf::from(home).join(ARQUIVO_USUARIO));
+    }
+    arquivos.push(PathBuf::from(ARQUIVO_PROJETO));
+    arquivos
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn booleano_em_varios_sotaques() {
+        for sim in ["1", "true", "TRUE", "sim", " on ", "s"] {
+            assert_eq!(ler_booleano(sim), Some(true), "{}", sim);
+        }
+        for nao in ["0", "false", "nao", "não", "off", "N", ""] {
+            assert_eq!(ler_booleano(nao), Some(false), "{}", nao);
+        }
+        assert_eq!(ler_booleano("talvez"), None);
+        assert_eq!(ler_booleano("yes"), None);
+    }
+
+    #[test]
+    fn projeto_nao_afrouxa_a_seguranca() {
+        let arquivo = r#"
+            [oraculo]
+            binario = "./pega-tudo.sh"
+            timeout_segundos = 120
+            [confirmacao]
+            acao_padrao = "allow"
+            nao_interativo = true
+            risco_maximo = "alto"
+            allow_do_projeto = true
+            sandbox = false
+            dry_run = true
+        "#;
+        let mut camadas = Camadas::padrao();
+        let usuario = "[confirmacao]\nsandbox = true\n";
+        assert!(camadas.aplicar_arquivo(usuario.parse().unwrap(), "~/.config/fenrir/config.toml", false).is_empty());
+        let avisos = camadas.aplicar_arquivo(arquivo.parse().unwrap(), ".fenrir.toml", true);
+        assert_eq!(avisos.len(), 6, "{:?}", avisos);
+        let efetiva = &camadas.efetiva;
+        assert_eq!(efetiva.oraculo.binario, "gemini");
+        assert_eq!(efetiva.confirmacao.acao_padrao, Acao::Confirm);
+        assert!(!efetiva.confirmacao.nao_interativo);
+        assert_eq!(efetiva.confirmacao.risco_maximo, Nivel::Baixo);
+        assert!(!efetiva.confirmacao.allow_do_projeto);
+        assert!(efetiva.confirmacao.sandbox, "o projeto desligou a sandbox do usuário");
+        // O resto passa normal
+        assert_eq!(efetiva.oraculo.timeout_segundos, 120);
+        assert!(efetiva.confirmacao.dry_run);
+
+        // Na config do usuário pode
+        let avisos = camadas.aplicar_arquivo(arquivo.parse().unwrap(), "~/.config/fenrir/config.toml", false);
+        assert!(avisos.is_empty(), "{:?}", avisos);
+        assert!(camadas.efetiva.confirmacao.nao_interativo);
+        assert_eq!(camadas.efetiva.oraculo.binario, "./pega-tudo.sh");
+    }
+
+    #[test]
+    fn texto_converte_pro_tipo_do_padrao() {
+        let mut camadas = Camadas::padrao();
+        camadas.definir_texto("confirmacao.sandbox", "sim", "--set").unwrap();
+        camadas.definir_texto("oraculo.timeout_segundos", " 90 ", "--set").unwrap();
+        assert!(camadas.efetiva.confirmacao.sandbox);
+        assert_eq!(camadas.efetiva.oraculo.timeout_segundos, 90);
+        assert!(camadas.definir_texto("oraculo.timeout_segundos", "muito", "--set").is_err());
+        assert!(camadas.definir_texto("confirmacao.acao_padrao", "talvez", "--set").is_err());
+        assert_eq!(camadas.efetiva.confirmacao.acao_padrao, Acao::Confirm);
+    }
+}
@@ -0,0 +1,352 @@
+// --- MÓDULO DESFAZER (UNDO) ---
+// Comando mexeu nos arquivos e deu merda? Antes não tinha volta.
+// Agora, antes de rodar uma tarefa que o analisador de risco marca como
+// "mexe em arquivo do projeto", a gente tira uma foto dos caminhos num
+// diário por tarefa ('.fenrir/undo/<id>/'). O 'fenrir undo' restaura e
+// avisa o que mudou DEPOIS da tarefa (pra você não perder trabalho novo).
+
+use crate::executor;
+use crate::msg;
+use crate::oraculo::FenrirTask;
+use crate::risco::Analise;
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// --- CONSTANTES (só do Desfazer) ---
+const PASTA_DIARIOS: &str = ".fenrir/undo";
+const ARQUIVO_DIARIO: &str = "diario.json";
+const MAX_BYTES_DIARIO: u64 = 200 * 1024 * 1024; // Foto maior que isso = sem undo
+const MAX_ARQUIVOS_DIARIO: usize = 10_000;
+
+// --- CONTRATO ---
+
+// Como o arquivo estava num momento (pra saber se mudou)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct Estado {
+    tamanho: u64,
+    hash: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Entrada {
+    caminho: PathBuf,      // Absoluto
+    antes: Option<Estado>, // None = não existia antes da tarefa
+    copia: Option<String>, // Nome da cópia dentro do diário
+    #[serde(default)]
+    depois: Option<Estado>, // Como a tarefa deixou (None = apagou / não criou)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Diario {
+    pub id: String, // O mesmo id da tarefa
+    pub tarefa: String,
+    pub descricao: String,
+    pub criado: String,
+    entradas: Vec<Entrada>,
+    #[serde(default)]
+    pastas_novas: Vec<PathBuf>, // Não existiam antes (o 'mkdir' da tarefa); o undo tira se ficarem vazias
+    #[serde(default)]
+    pub selado: bool, // A tarefa terminou e o "depois" foi anotado
+    #[serde(default)]
+    pub desfeito: bool,
+}
+
+// --- FUNÇÕES PÚBLICAS ---
+
+// Se a análise diz que a tarefa mexe em arquivo do projeto, tira a foto.
+// Falhou a foto? Avisa (a tarefa roda igual, só não tem undo).
+pub fn preparar(task: &FenrirTask, analise: &Analise) -> Option<Diario> {
+    if !analise.modifica_arquivos() {
+        return None;
+    }
+    let descricao = task.command_to_run.clone().unwrap_or_else(|| task.ia_explanation.clone());
+    match abrir(&task.id, &task.task_type, &descricao, &analise.arquivos) {
+        Ok(diario) => Some(diario),
+        Err(e) => {
+            eprintln!("{}", msg!("desfazer.sem_undo", e));
+            None
+        }
+    }
+}
+
+// Tira a foto dos 'caminhos' (pastas entram com tudo dentro) e grava o diário
+pub fn abrir(id: &str, tarefa: &str, descricao: &str, caminhos: &[PathBuf]) -> Result<Diario, String> {
+    let pasta = pasta_do_diario(id);
+    let pasta_copias = pasta.join("arquivos");
+    fs::create_dir_all(&pasta_copias).map_err(|e| msg!("geral.falha_criar", pasta_copias.display(), e))?;
+
+    let mut arquivos = Vec::new();
+    for caminho in caminhos {
+        listar_arquivos(caminho, &mut arquivos);
+    }
+    if arquivos.len() > MAX_ARQUIVOS_DIARIO {
+        let _ = fs::remove_dir_all(&pasta);
+        return Err(msg!("desfazer.arquivos_demais", arquivos.len(), MAX_ARQUIVOS_DIARIO));
+    }
+    let total: u64 = arquivos.iter().filter_map(|a| fs::metadata(a).ok()).map(|m| m.len()).sum();
+    if total > MAX_BYTES_DIARIO {
+        let _ = fs::remove_dir_all(&pasta);
+        return Err(msg!("desfazer.grande_demais", total / 1_048_576, MAX_BYTES_DIARIO / 1_048_576));
+    }
+
+    // Caminho que ainda não existe (e as pastas acima dele que também não):
+    // se a tarefa criar pasta ali, o undo sabe que foi ela
+    let mut pastas_novas: Vec<PathBuf> = Vec::new();
+    for caminho in &arquivos {
+        for pasta in caminho.ancestors().take_while(|p| !p.as_os_str().is_empty() && !p.exists()) {
+            if !pastas_novas.iter().any(|p| p == pasta) {
+                pastas_novas.push(pasta.to_path_buf());
+            }
+        }
+    }
+
+    let mut entradas = Vec::new();
+    for (n, caminho) in arquivos.into_iter().enumerate() {
+        let antes = estado(&caminho);
+        let copia = match antes {
+            Some(_) => {
+                let nome = n.to_string();
+                fs::copy(&caminho, pasta_copias.join(&nome))
+                    .map_err(|e| msg!("desfazer.falha_copiar", caminho.display(), e))?;
+                Some(nome)
+            }
+            None => None,
+        };
+        entradas.push(Entrada {
+            caminho,
+            antes,
+            copia,
+            depois: None,
+        });
+    }
+
+    let diario = Diario {
+        id: id.to_string(),
+        tarefa: tarefa.to_string(),
+        descricao: descricao.to_string(),
+        criado: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        entradas,
+        pastas_novas,
+        selado: false,
+        desfeito: false,
+    };
+    diario.salvar()?;
+    println!("{}", msg!("desfazer.foto", diario.entradas.len(), id));
+    Ok(diario)
+}
+
+impl Diario {
+    // A tarefa terminou: anota como ela deixou cada arquivo
+    pub fn selar(mut self) {
+        for entrada in &mut self.entradas {
+            entrada.depois = estado(&entrada.caminho);
+        }
+        self.selado = true;
+        if let Err(e) = self.salvar() {
+            eprintln!("{}", msg!("desfazer.nao_fechou", e));
+        }
+    }
+
+    // A tarefa falhou antes de mexer em qualquer coisa: joga o diário fora.
+    // (Escrita pela metade mudou algum arquivo? Aí sela, que o undo ainda serve.)
+    pub fn descartar(self) {
+        if self.entradas.iter().any(|e| estado(&e.caminho) != e.antes) {
+            self.selar();
+            return;
+        }
+        let _ = fs::remove_dir_all(pasta_do_diario(&self.id));
+    }
+
+    fn salvar(&self) -> Result<(), String> {
+        let caminho = pasta_do_diario(&self.id).join(ARQUIVO_DIARIO);
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(&caminho, json).map_err(|e| msg!("geral.falha_gravar", caminho.display(), e))
+    }
+}
+
+// 'fenrir undo' / ':undo'. Sem id, desfaz a última tarefa ainda não desfeita.
+// Com "lista", mostra os diários.
+pub async fn rodar(argumento: Option<&str>) {
+    match argumento.map(str::trim) {
+        Some("lista") | Some("list") => listar(),
+        Some("") | None => match diarios().into_iter().rev().find(|d| !d.desfeito) {
+            Some(diario) => desfazer(diario).await,
+            None => println!("{}", msg!("desfazer.nada")),
+        },
+        Some(id) => match carregar(id) {
+            Ok(diario) => desfazer(diario).await,
+            Err(e) => eprintln!("{}", msg!("geral.erro", e)),
+        },
+    }
+}
+
+// --- FUNÇÕES PRIVADAS ---
+
+fn listar() {
+    let todos = diarios();
+    if todos.is_empty() {
+        println!("{}", msg!("desfazer.nenhum_diario"));
+        return;
+    }
+    for d in todos {
+        let situacao = if d.desfeito { msg!("desfazer.desfeito") } else { String::new() };
+        println!("{}  {}  [{}] {}{}", d.id, d.criado, d.tarefa, d.descricao, situacao);
+    }
+}
+
+async fn desfazer(mut diario: Diario) {
+    if diario.desfeito {
+        println!("{}", msg!("desfazer.ja_desfeita", diario.id));
+        return;
+    }
+    println!("{}", msg!("desfazer.desfazendo", diario.id, diario.tarefa, diario.descricao));
+
+    // O que mudou DEPOIS da tarefa? (trabalho seu que o undo ia atropelar)
+    let mudados: Vec<&Entrada> = diario
+        .entradas
+        .iter()
+        .filter(|e| diario.selado && estado(&e.caminho) != e.depois)
+        .collect();
+    let mut pular_mudados = false;
+    if !mudados.is_empty() {
+        println!("{}", msg!("desfazer.mudaram"));
+        for e in &mudados {
+            println!("  - {}", e.caminho.display());
+        }
+        pular_mudados = !executor::ask_for_confirmation(&msg!("desfazer.restaura_tambem")).await;
+    }
+
+    let pasta_copias = pasta_do_diario(&diario.id).join("arquivos");
+    let mut restaurados = Vec::new();
+    let mut pulados = Vec::new();
+    let mut erros = Vec::new();
+
+    for entrada in &diario.entradas {
+        let mudou = diario.selado && estado(&entrada.caminho) != entrada.depois;
+        if mudou && pular_mudados {
+            pulados.push(entrada.caminho.display().to_string());
+            continue;
+        }
+        if !mudou && entrada.antes == entrada.depois && diario.selado {
+            continue; // A tarefa nem encostou nele
+        }
+
+        let resultado = match &entrada.copia {
+            // Existia: volta a cópia
+            Some(copia) => entrada
+                .caminho
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| fs::copy(pasta_copias.join(copia), &entrada.caminho).map(|_| ())),
+            // Não existia: a tarefa criou, então some com ele
+            None if entrada.caminho.is_file() => fs::remove_file(&entrada.caminho),
+            None => Ok(()),
+        };
+        match resultado {
+            Ok(_) => restaurados.push(entrada.caminho.display().to_string()),
+            Err(e) => erros.push(format!("{}: {}", entrada.caminho.display(), e)),
+        }
+    }
+
+    // Pastas que a tarefa criou: da mais funda pra mais rasa, e só se ficaram
+    // vazias (tem coisa dentro que não é da tarefa? fica, e a gente avisa)
+    let mut pastas: Vec<&PathBuf> = diario.pastas_novas.iter().filter(|p| p.is_dir()).collect();
+    pastas.sort_by_key(|p| std::cmp::Reverse(p.components().count()));
+    let mut pastas_ficaram = Vec::new();
+    for pasta in pastas {
+        match fs::remove_dir(pasta) {
+            Ok(_) => restaurados.push(pasta.display().to_string()),
+            Err(_) => pastas_ficaram.push(pasta.display().to_string()),
+        }
+    }
+
+    println!("{}", msg!("desfazer.restaurados", restaurados.len()));
+    for r in &restaurados {
+        println!("  ✓ {}", r);
+    }
+    for p in &pulados {
+        println!("{}", msg!("desfazer.pulado", p));
+    }
+    for p in &pastas_ficaram {
+        println!("{}", msg!("desfazer.pasta_ficou", p));
+    }
+    for e in &erros {
+        eprintln!("  ✗ {}", e);
+    }
+
+    diario.desfeito = erros.is_empty();
+    if let Err(e) = diario.salvar() {
+        eprintln!("{}", msg!("geral.aviso", e));
+    }
+    let evento = json!({ "id": diario.id, "restaurados": restaurados, "pulados": pulados, "erros": erros });
+    if let Err(e) = executor::log_evento("UNDO", &evento) {
+        eprintln!("{}", msg!("desfazer.erro_log", e));
+    }
+}
+
+fn pasta_do_diario(id: &str) -> PathBuf {
+    Path::new(PASTA_DIARIOS).join(id)
+}
+
+// O id vira pasta: 'fenrir undo ../../x' não pode sair de '.fenrir/undo/'.
+// Só letra, número, '-' e '_' (o id da tarefa é "20261019-072651-284").
+fn id_valido(id: &str) -> bool {
+    !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn carregar(id: &str) -> Result<Diario, String> {
+    if !id_valido(id) {
+        return Err(msg!("desfazer.id_invalido", id));
+    }
+    let caminho = pasta_do_diario(id).join(ARQUIVO_DIARIO);
+    let conteudo = fs::read_to_string(&caminho).map_err(|_| msg!("desfazer.nao_tem", id))?;
+    serde_json::from_str(&conteudo).map_err(|e| msg!("desfazer.zoado", caminho.display(), e))
+}
+
+// Todos os diários, do mais velho pro mais novo (o id é a data)
+fn diarios() -> Vec<Diario> {
+    let Ok(entradas) = fs::read_dir(PASTA_DIARIOS) else {
+        return Vec::new();
+    };
+    let mut ids: Vec<String> = entradas
+        .filter_map(Result::ok)
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect();
+    ids.sort();
+    ids.iter().filter_map(|id| carregar(id).ok()).collect()
+}
+
+// Pasta entra com tudo dentro; arquivo (existindo ou não) entra ele mesmo
+fn listar_arquivos(caminho: &Path, saida: &mut Vec<PathBuf>) {
+    if saida.len() > MAX_ARQUIVOS_DIARIO {
+        return;
+    }
+    if caminho.is_dir() {
+        if let Ok(entradas) = fs::read_dir(caminho) {
+            for entrada in entradas.filter_map(Result::ok) {
+                listar_arquivos(&entrada.path(), saida);
+            }
+        }
+    } else if !saida.iter().any(|c| c == caminho) {
+        saida.push(caminho.to_path_buf());
+    }
+}
+
+fn estado(caminho: &Path) -> Option<Estado> {
+    if !caminho.is_file() {
+        return None;
+    }
+    let bytes = fs::read(caminho).ok()?;
+    Some(Estado {
+        tamanho: bytes.len() as u64,
+        hash: fnv1a(&bytes),
+    })
+}
+
+// Hash estável entre versões do Rust (o DefaultHasher não garante isso)
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, b| (hash ^ *b as u64).wrapping_mul(0x100000001b3))
+}
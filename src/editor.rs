@@ -0,0 +1,223 @@
+// --- MÓDULO EDITOR ---
+// Abrir arquivo montando string pro 'sh -c' era pedir pra um path
+// com aspas (ou '$()') virar execução de código. Agora é argv puro.
+// Cada editor conhecido tem o seu jeito de pular pra linha:coluna.
+
+use crate::msg;
+use std::env;
+use std::path::Path;
+use tokio::process::Command;
+
+// --- CONTRATO ---
+
+// Como cada editor recebe "abre ISSO na linha X, coluna Y"
+#[derive(Debug, Clone, Copy)]
+enum Posicao {
+    MaisLinha,        // vim/nvim: +42 arquivo
+    MaisLinhaVirgula, // nano: +42,7 arquivo
+    MaisLinhaDois,    // emacs: +42:7 arquivo
+    Goto,             // code: --goto arquivo:42:7
+    DoisPontos,       // helix: arquivo:42:7
+    JetBrains,        // rustrover: --line 42 --column 7 arquivo
+}
+
+struct EditorConhecido {
+    nomes: &'static [&'static str], // Como o Oráculo (ou o $EDITOR) pode chamar
+    binario: &'static str,
+    posicao: Posicao,
+    terminal: bool, // Roda no terminal? Então o Fenrir ESPERA ele fechar.
+}
+
+// A tabela dos conhecidos
+const EDITORES: &[EditorConhecido] = &[
+    EditorConhecido { nomes: &["vim", "vi"], binario: "vim", posicao: Posicao::MaisLinha, terminal: true },
+    EditorConhecido { nomes: &["nvim", "neovim"], binario: "nvim", posicao: Posicao::MaisLinha, terminal: true },
+    EditorConhecido { nomes: &["nano"], binario: "nano", posicao: Posicao::MaisLinhaVirgula, terminal: true },
+    EditorConhecido { nomes: &["emacs"], binario: "emacs", posicao: Posicao::MaisLinhaDois, terminal: true },
+    EditorConhecido { nomes: &["hx", "helix"], binario: "hx", posicao: Posicao::DoisPontos, terminal: true },
+    EditorConhecido { nomes: &["code", "vscode", "vs code"], binario: "code", posicao: Posicao::Goto, terminal: false },
+    EditorConhecido { nomes: &["rustrover"], binario: "rustrover", posicao: Posicao::JetBrains, terminal: false },
+];
+
+// O editor pronto pra rodar
+pub struct Abertura {
+    pub cmd: Command,
+    pub esperar: bool, // Editor de terminal: segura o REPL até ele fechar
+}
+
+// Arquivo + (talvez) linha e coluna
+#[derive(Debug, PartialEq)]
+pub struct Alvo {
+    pub caminho: String,
+    pub linha: Option<u32>,
+    pub coluna: Option<u32>,
+}
+
+// --- FUNÇÕES PÚBLICAS ---
+
+// Separa "src/main.rs:42:7" em caminho, linha e coluna.
+// (Se o arquivo existe COM os dois-pontos no nome, respeita o nome.
+// "Existe" é a partir da 'cwd' da sessão, não da pasta onde o Fenrir abriu.)
+pub fn separar_alvo(texto: &str, cwd: &Path) -> Alvo {
+    let sem_posicao = Alvo {
+        caminho: texto.to_string(),
+        linha: None,
+        coluna: None,
+    };
+    if cwd.join(texto).exists() {
+        return sem_posicao;
+    }
+
+    let numero = |s: &str| s.parse::<u32>().ok();
+
+    // "arquivo:linha:coluna"
+    let partes: Vec<&str> = texto.rsplitn(3, ':').collect();
+    if let [coluna, linha, caminho] = partes.as_slice() {
+        if let (Some(l), Some(c)) = (numero(linha), numero(coluna)) {
+            if !caminho.is_empty() {
+                return Alvo {
+                    caminho: caminho.to_string(),
+                    linha: Some(l),
+                    coluna: Some(c),
+                };
+            }
+        }
+    }
+
+    // "arquivo:linha"
+    match texto.rsplit_once(':') {
+        Some((caminho, linha)) if !caminho.is_empty() && numero(linha).is_some() => Alvo {
+            caminho: caminho.to_string(),
+            linha: numero(linha),
+            coluna: None,
+        },
+        _ => sem_posicao,
+    }
+}
+
+// Monta o argv do editor (que vai rodar na 'cwd'). Sem 'app'? Vai de $VISUAL, depois $EDITOR.
+pub fn montar(app: Option<&str>, alvo: &Alvo, cwd: &Path) -> Result<Abertura, String> {
+    let escolhido = match app {
+        Some(a) if !a.trim().is_empty() => a.trim().to_string(),
+        _ => env::var("VISUAL")
+            .or_else(|_| env::var("EDITOR"))
+            .map_err(|_| msg!("editor.sem_editor"))?,
+    };
+
+    // $EDITOR pode vir com args (ex: "code -w"), então quebra no estilo shell
+    let mut tokens = shlex::split(&escolhido).unwrap_or_else(|| vec![escolhido.clone()]);
+    if tokens.is_empty() {
+        return Err(msg!("editor.vazio"));
+    }
+    let programa = tokens.remove(0);
+
+    // Primeiro pelo nome inteiro ("vs code"), depois pelo binário ("/usr/bin/nvim")
+    let inteiro = escolhido.to_lowercase();
+    if let Some(editor) = EDITORES.iter().find(|e| e.nomes.contains(&inteiro.as_str())) {
+        return Ok(montar_conhecido(editor, editor.binario, Vec::new(), alvo, cwd));
+    }
+
+    let nome = Path::new(&programa)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    match EDITORES.iter().find(|e| e.nomes.contains(&nome.as_str())) {
+        Some(editor) => Ok(montar_conhecido(editor, &programa, tokens, alvo, cwd)),
+        None => Ok(montar_desconhecido(&escolhido, &programa, tokens, alvo, cwd)),
+    }
+}
+
+// --- FUNÇÕES PRIVADAS ---
+
+fn montar_conhecido(editor: &EditorConhecido, programa: &str, extras: Vec<String>, alvo: &Alvo, cwd: &Path) -> Abertura {
+    // Veio um apelido ("vs code", "neovim")? Usa o binário da tabela.
+    // Caminho explícito ("/opt/nvim/bin/nvim") a gente respeita.
+    let binario = if programa.contains('/') { programa } else { editor.binario };
+
+    // RustRover no macOS sem o launcher no PATH: vai pelo 'open -a'
+    // (o app NÃO abre na nossa pasta, então o caminho vai absoluto)
+    if matches!(editor.posicao, Posicao::JetBrains) && cfg!(target_os = "macos") && !binario_existe(binario) {
+        let de_fora = Alvo { caminho: absoluto(cwd, &alvo.caminho), linha: alvo.linha, coluna: alvo.coluna };
+        let mut cmd = Command::new("open");
+        cmd.args(["-na", "RustRover", "--args"]);
+        argumentos_posicao(&mut cmd, editor.posicao, &de_fora);
+        return Abertura { cmd, esperar: false };
+    }
+
+    let mut cmd = Command::new(binario);
+    cmd.args(extras);
+    argumentos_posicao(&mut cmd, editor.posicao, alvo);
+    Abertura {
+        cmd,
+        esperar: editor.terminal,
+    }
+}
+
+fn argumentos_posicao(cmd: &mut Command, posicao: Posicao, alvo: &Alvo) {
+    let caminho = caminho_seguro(&alvo.caminho);
+    let caminho = caminho.as_str();
+    match (posicao, alvo.linha, alvo.coluna) {
+        (_, None, _) => {
+            cmd.arg(caminho);
+        }
+        (Posicao::MaisLinha, Some(l), _) => {
+            cmd.arg(format!("+{}", l)).arg(caminho);
+        }
+        (Posicao::MaisLinhaVirgula, Some(l), c) => {
+            cmd.arg(format!("+{},{}", l, c.unwrap_or(1))).arg(caminho);
+        }
+        (Posicao::MaisLinhaDois, Some(l), c) => {
+            cmd.arg(format!("+{}:{}", l, c.unwrap_or(1))).arg(caminho);
+        }
+        (Posicao::Goto, Some(l), c) => {
+            cmd.arg("--goto").arg(format!("{}:{}:{}", caminho, l, c.unwrap_or(1)));
+        }
+        (Posicao::DoisPontos, Some(l), c) => {
+            cmd.arg(format!("{}:{}:{}", caminho, l, c.unwrap_or(1)));
+        }
+        (Posicao::JetBrains, Some(l), c) => {
+            cmd.arg("--line").arg(l.to_string());
+            cmd.arg("--column").arg(c.unwrap_or(1).to_string());
+            cmd.arg(caminho);
+        }
+    }
+}
+
+// Editor que a gente não conhece: no macOS vai de 'open -a',
+// no resto roda o binário com o arquivo (sem pular pra linha).
+fn montar_desconhecido(escolhido: &str, programa: &str, extras: Vec<String>, alvo: &Alvo, cwd: &Path) -> Abertura {
+    if cfg!(target_os = "macos") && !binario_existe(programa) {
+        let mut cmd = Command::new("open");
+        cmd.arg("-a").arg(escolhido).arg(absoluto(cwd, &alvo.caminho));
+        return Abertura { cmd, esperar: false };
+    }
+
+    let mut cmd = Command::new(programa);
+    cmd.args(extras).arg(caminho_seguro(&alvo.caminho));
+    Abertura { cmd, esperar: false }
+}
+
+// Arquivo começando com '-' vira opção do editor ('-c:!rm ...' no vim).
+// Prefixa com './' e pronto.
+fn caminho_seguro(caminho: &str) -> String {
+    if caminho.starts_with('-') {
+        format!("./{}", caminho)
+    } else {
+        caminho.to_string()
+    }
+}
+
+// Pro 'open -a' (que roda o app fora da nossa pasta)
+fn absoluto(cwd: &Path, caminho: &str) -> String {
+    cwd.join(caminho).display().to_string()
+}
+
+fn binario_existe(programa: &str) -> bool {
+    if programa.contains('/') {
+        return Path::new(programa).is_file();
+    }
+    env::var_os("PATH")
+        .map(|paths| env::split_paths(&paths).any(|dir| dir.join(programa).is_file()))
+        .unwrap_or(false)
+}
@@ -0,0 +1,117 @@
+// --- MÓDULO ESCOPO ---
+// O contrato diz "10.10.0.0/24 e *.cliente.com.br". O Oráculo não leu o contrato.
+// O escopo do engagement fica em '.fenrir/escopo.toml' e toda tarefa com
+// alvo fora dele para e pergunta antes (mesmo se a política liberou).
+//
+//   alvos = ["10.10.0.0/24", "cliente.com.br", "*.homolog.cliente.com.br"]
+//
+// Domínio cobre os subdomínios ('cliente.com.br' cobre 'app.cliente.com.br').
+// Escopo vazio = nada definido, ninguém é barrado.
+
+use crate::msg;
+use crate::oraculo::FenrirTask;
+use crate::politica;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::net::Ipv4Addr;
+use std::path::Path;
+
+// --- CONSTANTES (só do Escopo) ---
+const ARQUIVO_ESCOPO: &str = ".fenrir/escopo.toml";
+
+// --- CONTRATO ---
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Escopo {
+    #[serde(default)]
+    pub alvos: Vec<String>,
+}
+
+// --- FUNÇÕES PÚBLICAS ---
+
+impl Escopo {
+    // Lê o escopo do engagement (pasta atual). Quebrado = aviso e escopo vazio.
+    pub fn carregar() -> Escopo {
+        let Ok(conteudo) = fs::read_to_string(ARQUIVO_ESCOPO) else {
+            return Escopo::default();
+        };
+        toml::from_str(&conteudo).unwrap_or_else(|e| {
+            eprintln!("{}", msg!("escopo.zoado", ARQUIVO_ESCOPO, e));
+            Escopo::default()
+        })
+    }
+
+    pub fn salvar(&self) -> Result<(), String> {
+        if let Some(pasta) = Path::new(ARQUIVO_ESCOPO).parent() {
+            fs::create_dir_all(pasta).map_err(|e| e.to_string())?;
+        }
+        let texto = toml::to_string(self).map_err(|e| e.to_string())?;
+        fs::write(ARQUIVO_ESCOPO, texto).map_err(|e| msg!("geral.falha_gravar", ARQUIVO_ESCOPO, e))
+    }
+
+    pub fn arquivo() -> &'static str {
+        ARQUIVO_ESCOPO
+    }
+
+    // Os alvos da tarefa que NÃO estão no escopo (escopo vazio = nenhum)
+    pub fn fora(&self, task: &FenrirTask) -> Vec<String> {
+        if self.alvos.is_empty() {
+            return Vec::new();
+        }
+        politica::pedido_da_tarefa(task)
+            .alvos
+            .into_iter()
+            .filter(|alvo| !self.cobre(alvo))
+            .collect()
+    }
+
+    pub fn cobre(&self, alvo: &str) -> bool {
+        let host = host_do_alvo(alvo);
+        self.alvos.iter().any(|entrada| entrada_cobre(entrada.trim(), &host))
+    }
+}
+
+// "https://user:x@App.Cliente.com:8443/login" => "app.cliente.com"
+// (A macro usa também: '{url:host}')
+pub fn host_do_alvo(alvo: &str) -> String {
+    let sem_esquema = alvo.split_once("://").map(|(_, resto)| resto).unwrap_or(alvo);
+    let sem_caminho = sem_esquema.split(['?', '#']).next().unwrap_or_default();
+    // CIDR fica inteiro ('10.0.0.0/24'), URL perde o caminho
+    let sem_caminho = if ler_rede(sem_caminho).is_some() { sem_caminho } else { sem_caminho.split('/').next().unwrap_or_default() };
+    let sem_usuario = sem_caminho.rsplit('@').next().unwrap_or_default();
+    let sem_porta = match sem_usuario.rsplit_once(':') {
+        Some((host, porta)) if porta.chars().all(|c| c.is_ascii_digit()) => host,
+        _ => sem_usuario,
+    };
+    sem_porta.trim_end_matches('.').to_lowercase()
+}
+
+// --- FUNÇÕES PRIVADAS ---
+
+fn entrada_cobre(entrada: &str, host: &str) -> bool {
+    let entrada = entrada.to_lowercase();
+    if let (Some(rede), Some(alvo)) = (ler_rede(&entrada), ler_rede(host)) {
+        // Alvo (IP ou rede) tem que caber inteiro na rede da entrada
+        return alvo.1 >= rede.1 && mascarar(alvo.0, rede.1) == mascarar(rede.0, rede.1);
+    }
+    if entrada.contains('*') || entrada.contains('?') {
+        return politica::casa_padrao(&entrada, host);
+    }
+    host == entrada || host.ends_with(&format!(".{}", entrada))
+}
+
+// "10.0.0.0/24" => (10.0.0.0, 24); IP sozinho = /32
+fn ler_rede(texto: &str) -> Option<(u32, u8)> {
+    let (ip, prefixo) = texto.split_once('/').unwrap_or((texto, "32"));
+    let ip: Ipv4Addr = ip.parse().ok()?;
+    let prefixo: u8 = prefixo.parse().ok().filter(|p| *p <= 32)?;
+    Some((u32::from(ip), prefixo))
+}
+
+// Só os bits da rede ('/0' = tudo zero)
+fn mascarar(ip: u32, prefixo: u8) -> u32 {
+    match prefixo {
+        0 => 0,
+        p => ip & (u32::MAX << (32 - p as u32)),
+    }
+}
@@ -0,0 +1,108 @@
+// --- MÓDULO EVENTOS (O '--json') ---
+// Pra chamar o Fenrir de script: com '--json', o stdout vira SÓ linhas JSON
+// (uma por evento: proposta, política, desfecho, achado...) e todo o texto
+// pra gente (português, spinner, saída dos comandos) vai pro stderr.
+//
+// O truque: guarda uma cópia do stdout de verdade pros eventos e aponta o
+// fd 1 pro stderr. Aí nenhum 'println!' espalhado por aí suja o JSON.
+//
+// E o código de saída conta como foi (vale com ou sem '--json'):
+//   0 = rodou e deu certo   1 = falhou   2 = recusado (política, escopo ou 'n')
+
+use crate::msg;
+use crate::segredos;
+use chrono::Local;
+use serde_json::{json, Value};
+use std::fs::File;
+use std::io::Write;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+// --- CONSTANTES (só dos Eventos) ---
+static SAIDA_JSON: OnceLock<Mutex<File>> = OnceLock::new();
+static PIOR: AtomicU8 = AtomicU8::new(Desfecho::Sucesso as u8);
+static PIOR_DO_PASSO: AtomicU8 = AtomicU8::new(Desfecho::Sucesso as u8); // Zerado a cada 'novo_passo'
+
+// --- CONTRATO ---
+
+// Do melhor pro pior (o pior da execução vira o código de saída)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum Desfecho {
+    Sucesso = 0,
+    Recusado = 1,
+    Falha = 2,
+}
+
+// --- FUNÇÕES PÚBLICAS ---
+
+// Liga o modo JSON (uma vez, no começo, antes de qualquer saída)
+pub fn ligar() -> Result<(), String> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::io::FromRawFd;
+        // SAFETY: dup/dup2 só mexem na tabela de fds; o fd novo vira dono do File
+        let copia = unsafe { libc::dup(libc::STDOUT_FILENO) };
+        if copia < 0 || unsafe { libc::dup2(libc::STDERR_FILENO, libc::STDOUT_FILENO) } < 0 {
+            return Err(msg!("eventos.sem_separar", std::io::Error::last_os_error()));
+        }
+        let _ = SAIDA_JSON.set(Mutex::new(unsafe { File::from_raw_fd(copia) }));
+        Ok(())
+    }
+    #[cfg(not(unix))]
+    {
+        Err(msg!("eventos.so_unix"))
+    }
+}
+
+// Uma linha JSON no stdout: {"evento": "...", "quando": "...", ...dados}
+// (Segredo vira marcador, igual no log.) Sem '--json', não faz nada.
+pub fn emitir(evento: &str, dados: &Value) {
+    let Some(saida) = SAIDA_JSON.get() else {
+        return;
+    };
+    let mut linha = json!({
+        "evento": evento,
+        "quando": Local::now().format("%Y-%m-%dT%H:%M:%S%.3f%:z").to_string(),
+    });
+    if let (Some(linha), Some(dados)) = (linha.as_object_mut(), dados.as_object()) {
+        linha.extend(dados.iter().map(|(k, v)| (k.clone(), v.clone())));
+    } else if let Some(linha) = linha.as_object_mut() {
+        linha.insert("dados".to_string(), dados.clone());
+    }
+
+    let texto = segredos::mascarar(&linha.to_string());
+    let mut saida = saida.lock().unwrap_or_else(|e| e.into_inner());
+    let _ = writeln!(saida, "{}", texto).and_then(|_| saida.flush());
+}
+
+// Anota como foi um passo (fica o pior)
+pub fn marcar(desfecho: Desfecho) {
+    PIOR.fetch_max(desfecho as u8, Ordering::Relaxed);
+    PIOR_DO_PASSO.fetch_max(desfecho as u8, Ordering::Relaxed);
+}
+
+// Começa a contar um passo do zero (a macro quer saber como foi CADA um)
+pub fn novo_passo() {
+    PIOR_DO_PASSO.store(Desfecho::Sucesso as u8, Ordering::Relaxed);
+}
+
+// O pior desde o último 'novo_passo'
+pub fn desfecho_do_passo() -> Desfecho {
+    match PIOR_DO_PASSO.load(Ordering::Relaxed) {
+        0 => Desfecho::Sucesso,
+        1 => Desfecho::Recusado,
+        _ => Desfecho::Falha,
+    }
+}
+
+// O código de saída do Fenrir (e o evento 'fim', no '--json')
+pub fn encerrar() -> i32 {
+    let (rotulo, codigo) = match PIOR.load(Ordering::Relaxed) {
+        0 => ("sucesso", 0),
+        1 => ("recusado", 2),
+        _ => ("falha", 1),
+    };
+    emitir("fim", &json!({ "desfecho": rotulo, "codigo": codigo }));
+    codigo
+}
@@ -2,242 +2,571 @@
 // As "Mãos" do Fenrir.
 // Agora ele recebe 'task_args' (JSON) e se vira.
 
+use crate::config;
+use crate::editor;
+use crate::eventos::{self, Desfecho};
+use crate::limites::{self, Limites};
+use crate::msg;
+use crate::resolvedor;
+use crate::risco::Nivel;
 use crate::oraculo::FenrirTask; // Precisa saber o que é uma Task
+use crate::politica::{self, Acao, Politica};
+use crate::sandbox::{self, ConfigSandbox};
+use crate::segredos;
+use crate::terminal;
 use chrono::Local;
-use serde_json::Value; // Importa o 'Value' (JSON genérico)
-use std::fs::OpenOptions;
-use std::io::{self, Write};
+use serde_json::{json, Value}; // Importa o 'Value' (JSON genérico)
+use std::env;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, IsTerminal, Read, Write};
+use std::path::Path;
+use std::process::Stdio;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use tokio::process::Command;
 use tokio::task;
 
 // --- CONSTANTES (só do Executor) ---
-const LOG_FILE: &str = "fenrir_tasks.log";
+const MAX_SAIDA_GUARDADA: usize = 64 * 1024; // Só o finalzinho (pro ':last')
+
+// --- CONTRATO ---
+
+// A saída do último comando que rodou (o ':last' mostra)
+#[derive(Debug, Clone, Default)]
+pub struct Saida {
+    pub rotulo: String,
+    pub desfecho: String,
+    pub texto: String,           // O final do que ele cuspiu (stdout + stderr)
+    pub arquivo: Option<String>, // Onde ficou a saída inteira (XML do nmap, transcrição do PTY)
+    pub guardada: bool,          // false = foi direto pra tela, sem passar por nós
+}
+
+static ULTIMA_SAIDA: Mutex<Option<Saida>> = Mutex::new(None);
 
 // --- FUNÇÕES PÚBLICAS ---
 
+// Anota a saída do comando que acabou de rodar (a anterior vai embora)
+pub fn guardar_saida(saida: Saida) {
+    *ULTIMA_SAIDA.lock().unwrap_or_else(|e| e.into_inner()) = Some(saida);
+}
+
+// A ferramenta sabe onde gravou o arquivo dela: pendura na última saída
+// (se a última saída for mesmo dela: se nem subiu, não tem o que pendurar)
+pub fn apontar_arquivo(ferramenta: &str, arquivo: Option<String>) {
+    if let Some(saida) = ULTIMA_SAIDA.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+        if saida.rotulo.split_whitespace().next() == Some(ferramenta) {
+            saida.arquivo = arquivo;
+        }
+    }
+}
+
+pub fn ultima_saida() -> Option<Saida> {
+    ULTIMA_SAIDA.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
 // Salva a tarefa no 'fenrir_tasks.log'
-// (Não muda, logar JSON é bom)
-pub fn log_task(task: &FenrirTask) -> io::Result<()> {
+// (Logar JSON é bom. No dry-run a entrada sai marcada.)
+pub fn log_task(task: &FenrirTask, dry_run: bool) -> io::Result<()> {
+    let marca = if dry_run { Some("DRY-RUN") } else { None };
+    escrever_log(
+        marca,
+        &serde_json::to_string_pretty(task).unwrap_or("Erro ao serializar tarefa".to_string()),
+    )
+}
+
+// Salva um evento qualquer (dry-run, decisão, desfecho...) no mesmo log
+// (No '--json' o mesmo evento sai no stdout: "DRY-RUN" vira {"evento": "dry-run", ...})
+pub fn log_evento(marca: &str, detalhe: &Value) -> io::Result<()> {
+    eventos::emitir(&marca.to_lowercase(), detalhe);
+    escrever_log(
+        Some(marca),
+        &serde_json::to_string_pretty(detalhe).unwrap_or("Erro ao serializar evento".to_string()),
+    )
+}
+
+fn escrever_log(marca: Option<&str>, corpo: &str) -> io::Result<()> {
     let mut file = OpenOptions::new()
         .append(true)
         .create(true)
-        .open(LOG_FILE)?;
+        .open(config::atual().caminhos.log)?;
 
     let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
-    let log_entry = format!(
-        "\n--- [ {} ] ---\n{}\n",
-        timestamp,
-        serde_json::to_string_pretty(task).unwrap_or("Erro ao serializar tarefa".to_string())
-    );
+    let cabecalho = match marca {
+        Some(m) => format!("[ {} ] [{}]", timestamp, m),
+        None => format!("[ {} ]", timestamp),
+    };
+    // Segredo (cookie, token, senha...) não entra no log: vira marcador
+    let log_entry = format!("\n--- {} ---\n{}\n", cabecalho, segredos::mascarar(corpo));
 
     file.write_all(log_entry.as_bytes())
 }
 
+// O "spawn de mentirinha": mostra o argv/env/cwd EXATOS que iam rodar
+// e registra no log. Nada é executado.
+pub fn mostrar_dry_run(cmd: &Command, artefato: Option<&str>) {
+    let std_cmd = cmd.as_std();
+
+    let mut argv = vec![std_cmd.get_program().to_string_lossy().to_string()];
+    argv.extend(std_cmd.get_args().map(|a| a.to_string_lossy().to_string()));
+
+    let cwd = match std_cmd.get_current_dir() {
+        Some(dir) => dir.display().to_string(),
+        None => env::current_dir()
+            .map(|d| d.display().to_string())
+            .unwrap_or_else(|_| "?".to_string()),
+    };
+
+    // Só o que muda em relação ao ambiente do Fenrir (o resto é herdado)
+    let env_extra: Vec<String> = std_cmd
+        .get_envs()
+        .map(|(k, v)| match v {
+            Some(v) => format!("{}={}", k.to_string_lossy(), v.to_string_lossy()),
+            None => format!("-{}", k.to_string_lossy()), // removida
+        })
+        .collect();
+
+    println!("{}", msg!("executor.dry_run_titulo"));
+    println!("argv: {:?}", argv);
+    println!("cwd:  {}", cwd);
+    if env_extra.is_empty() {
+        println!("{}", msg!("executor.dry_run_env"));
+    } else {
+        println!("{}", msg!("executor.dry_run_env_mais", format!("{:?}", env_extra)));
+    }
+    if let Some(a) = artefato {
+        println!("{}", msg!("executor.dry_run_saida", a));
+    }
+    println!("{}", msg!("executor.separador_longo"));
+
+    let evento = json!({ "argv": argv, "cwd": cwd, "env": env_extra, "artefato": artefato });
+    if let Err(e) = log_evento("DRY-RUN", &evento) {
+        eprintln!("{}", msg!("executor.erro_log_dry_run", e));
+    }
+}
+
 // Pergunta 's' ou 'n' (O Freio de Mão)
 // (Não muda)
 pub async fn ask_for_confirmation(acao_proposta: &str) -> bool {
-    print!("{}", acao_proposta);
+    eh_sim(&perguntar(acao_proposta).await)
+}
+
+// Mostra o 'prompt' e lê UMA linha do terminal (já sem espaços nas pontas).
+// Não-interativo: ninguém responde, vale resposta vazia (= não).
+// stdin que não é terminal (pipe, CI) NUNCA responde: a gente pergunta no
+// '/dev/tty' e, se nem ele existe, fica o não (nada de travar esperando).
+pub async fn perguntar(prompt: &str) -> String {
+    print!("{}", prompt);
     io::stdout().flush().unwrap();
 
+    if config::atual().confirmacao.nao_interativo {
+        println!("{}", msg!("executor.ninguem_responde"));
+        return String::new();
+    }
+
     let result = task::spawn_blocking(|| {
         let mut input = String::new();
-        io::stdin().read_line(&mut input).unwrap_or(0);
-        input.trim().to_lowercase()
+        if io::stdin().is_terminal() {
+            io::stdin().read_line(&mut input).unwrap_or(0);
+        } else {
+            match File::open("/dev/tty") {
+                Ok(tty) => {
+                    BufReader::new(tty).read_line(&mut input).unwrap_or(0);
+                }
+                Err(_) => eprintln!("{}", msg!("executor.sem_terminal")),
+            }
+        }
+        input.trim().to_string()
     })
     .await;
 
-    match result {
-        Ok(input) => input == "s" || input == "sim",
-        Err(_) => false,
+    result.unwrap_or_default()
+}
+
+// Pergunta de um passo de tarefa que a política JÁ aprovou (aplicar o diff,
+// promover da sandbox...). No não-interativo quem responde é 'automatico'
+// (e vai pro log como aprovação automática).
+pub async fn confirmar_passo(pergunta: &str, automatico: bool) -> bool {
+    if !config::atual().confirmacao.nao_interativo {
+        return ask_for_confirmation(pergunta).await;
+    }
+    println!("{}", if automatico { msg!("executor.passo_automatico", pergunta) } else { msg!("executor.passo_recusado", pergunta) });
+    if let Err(e) = log_evento("AUTO", &json!({ "pergunta": pergunta, "aprovado": automatico })) {
+        eprintln!("{}", msg!("executor.erro_log_auto", e));
+    }
+    automatico
+}
+
+// Não-interativo: o risco cabe no 'confirmacao.risco_maximo'?
+pub fn risco_liberado(risco: Nivel) -> bool {
+    risco <= config::atual().confirmacao.risco_maximo
+}
+
+// "s", "sim", "y" ou "yes" (vale em qualquer idioma)
+fn eh_sim(resposta: &str) -> bool {
+    matches!(resposta.trim().to_lowercase().as_str(), "s" | "sim" | "y" | "yes")
+}
+
+// Lê uma frase exata (pro 'require-phrase' da política)
+async fn pedir_frase(frase: &str) -> bool {
+    perguntar(&msg!("executor.digite_frase", frase)).await == frase
+}
+
+// Alvo fora do escopo do engagement pergunta ANTES da política (mesmo se ela
+// liberar direto). Sem alvo de fora, passa calado. A resposta vai pro log.
+pub async fn passar_pelo_escopo(task: &FenrirTask, fora: &[String]) -> bool {
+    if fora.is_empty() {
+        return true;
+    }
+    let segue = ask_for_confirmation(&msg!("executor.pergunta_escopo")).await;
+    let evento = json!({ "task_type": task.task_type, "fora": fora, "aprovado": segue });
+    if let Err(e) = log_evento("ESCOPO", &evento) {
+        eprintln!("{}", msg!("executor.erro_log_escopo", e));
     }
+    if !segue {
+        println!("{}", msg!("executor.cancelado_escopo"));
+    }
+    segue
+}
+
+// A Política vem ANTES do Freio de Mão.
+// Deny barra, allow libera direto, confirm pergunta s/n,
+// require-phrase exige a frase. A decisão vai pro log.
+// Aprovado? Devolve o que veio depois do "s" (ex: "s timeout=600" => "timeout=600"),
+// que são os ajustes de limite. Barrado = None.
+// Não-interativo (CI): no lugar do s/n quem decide é o 'risco' da tarefa
+// (até o 'confirmacao.risco_maximo' passa, acima é recusado, mesmo com 'allow') e a frase do
+// require-phrase nunca é digitada. Aprovação assim vai marcada no log.
+pub async fn passar_pela_politica(task: &FenrirTask, politica: &Politica, risco: Nivel) -> Option<String> {
+    let decisao = politica.avaliar(&politica::pedido_da_tarefa(task));
+    let confirmacao = config::atual().confirmacao;
+    let mut ajustes = String::new();
+    let mut automatico = false;
+
+    let aprovado = match decisao.acao {
+        Acao::Deny => {
+            println!("{}", msg!("executor.barrado", decisao.origem, decisao.motivo));
+            false
+        }
+        // O teto do não-interativo vale até pro 'allow': regra larga ('allow nmap*')
+        // não pode aprovar sozinha um 'nmap --script ...' que o risco marca acima
+        Acao::Allow if confirmacao.nao_interativo && !risco_liberado(risco) => {
+            automatico = true;
+            println!("{}", msg!("executor.liberado_acima", decisao.origem, risco.rotulo(), confirmacao.risco_maximo.rotulo()));
+            false
+        }
+        Acao::Allow => {
+            println!("{}", msg!("executor.liberado", decisao.origem, decisao.motivo));
+            true
+        }
+        Acao::Confirm if confirmacao.nao_interativo => {
+            automatico = true;
+            if risco_liberado(risco) {
+                println!("{}", msg!("executor.auto_aprovado", risco.rotulo(), confirmacao.risco_maximo.rotulo()));
+                true
+            } else {
+                println!("{}", msg!("executor.auto_recusado", risco.rotulo(), confirmacao.risco_maximo.rotulo()));
+                false
+            }
+        }
+        Acao::RequirePhrase if confirmacao.nao_interativo => {
+            automatico = true;
+            println!("{}", msg!("executor.auto_frase", decisao.motivo));
+            false
+        }
+        Acao::Confirm => {
+            let resposta = perguntar(&msg!("executor.executa")).await;
+            let (sim, resto) = resposta.split_once(' ').unwrap_or((resposta.as_str(), ""));
+            ajustes = resto.trim().to_string();
+            eh_sim(sim)
+        }
+        Acao::RequirePhrase => {
+            println!("{}", msg!("executor.confirmacao_forte", decisao.origem, decisao.motivo));
+            pedir_frase(decisao.frase.as_deref().unwrap_or_default()).await
+        }
+    };
+
+    let evento = json!({
+        "task_type": task.task_type,
+        "decisao": decisao,
+        "aprovado": aprovado,
+        "ajustes": ajustes,
+        "automatico": automatico,
+        "risco": risco,
+        "risco_maximo": automatico.then_some(confirmacao.risco_maximo),
+    });
+    if let Err(e) = log_evento("POLITICA", &evento) {
+        eprintln!("{}", msg!("executor.erro_log_politica", e));
+    }
+
+    aprovado.then_some(ajustes)
 }
 
 // Executa um comando no shell
-// AGORA ELA RECEBE O JSON DE ARGS
-pub fn handle_execute_command(args: Option<Value>) {
+// AGORA ELA RECEBE O JSON DE ARGS (e respeita o dry-run)
+// (Roda na pasta da sessão: 'cwd', e ESPERA terminar: o undo precisa do "depois")
+// Os 'limites' (tempo, CPU, memória, saída) valem pro grupo de processos inteiro.
+pub async fn handle_execute_command(args: Option<Value>, cwd: &Path, limites: &Limites, dry_run: bool) {
     // A gente vai no JSON, acha a chave "cmd", e pega o texto.
     let comando = match args.as_ref().and_then(|a| a.get("cmd")).and_then(|v| v.as_str()) {
         Some(cmd_str) => cmd_str,
         None => {
-            eprintln!("Erro: Oráculo mandou 'command' mas não mandou o JSON de 'cmd'!");
+            eprintln!("{}", msg!("executor.sem_cmd"));
+            eventos::marcar(Desfecho::Falha);
             return;
         }
     };
 
-    println!("Rodando: '{}'...", comando);
-    let output = if cfg!(target_os = "windows") {
-        Command::new("cmd")
-            .arg("/C")
-            .arg(comando)
-            .spawn()
+    let mut cmd = if cfg!(target_os = "windows") {
+        let mut c = Command::new("cmd");
+        c.arg("/C").arg(comando);
+        c
     } else {
-        Command::new("sh")
-            .arg("-c")
-            .arg(comando)
-            .spawn()
+        let mut c = Command::new("sh");
+        c.arg("-c").arg(comando);
+        c
     };
-    
-    match output {
-        Ok(_) => println!("Comando enviado pro terminal."),
-        Err(e) => eprintln!("Oxe! Deu erro ao TENTAR rodar o comando: {}", e),
+    cmd.current_dir(cwd);
+
+    // Programa interativo (msfconsole, ssh, mysql...)? Vai pro PTY.
+    // O Oráculo também pode pedir: ARGS {"interativo": true}
+    let interativo = args.as_ref().and_then(|a| a.get("interativo")).and_then(|v| v.as_bool())
+        .unwrap_or_else(|| terminal::eh_interativo(comando));
+
+    if dry_run {
+        if interativo {
+            println!("{}", msg!("executor.pty_dry_run"));
+        }
+        mostrar_dry_run(&cmd, None);
+        return;
     }
-}
 
-// Abre um arquivo no editor
-// AGORA ELA RECEBE O JSON DE ARGS
-pub fn handle_open_editor(args: Option<Value>) {
-    let args_map = match args.as_ref().and_then(|a| a.as_object()) {
-        Some(map) => map,
-        None => {
-            eprintln!("Erro: Oráculo mandou 'open_editor' mas não mandou os ARGS!");
+    if interativo {
+        handle_execute_command_pty(comando, cwd, limites).await;
+        return;
+    }
+
+    println!("{}", msg!("executor.rodando", comando));
+    limites::preparar(&mut cmd, limites);
+    // Na tela de verdade, stdout/stderr vão por um PTY só de saída: o comando
+    // continua vendo um terminal (cor, barra, linha a linha) e a gente copia
+    // pra tela E guarda o final pro ':last'. Sem tela (pipe, CI) ou sem PTY,
+    // a saída é herdada e vai direto (o ':last' fica só com o desfecho).
+    let espelho = terminal::saida_em_pty(&mut cmd);
+    // Terminal na mão do comando só se ele precisa do teclado ('sudo' pedindo
+    // senha). Aí o Ctrl-C vai direto pra ele e a parada é pelo 'fenrir stop'.
+    // O resto roda atrás, sem stdin: o Ctrl-C chega aqui e o duplo derruba tudo.
+    let teclado = terminal::quer_teclado(comando);
+    if !teclado {
+        cmd.stdin(Stdio::null());
+    }
+    let mut filho = match cmd.spawn() {
+        Ok(f) => f,
+        Err(e) => {
+            eprintln!("{}", msg!("executor.erro_spawn", e));
+            eventos::marcar(Desfecho::Falha);
             return;
         }
     };
+    drop(cmd); // Ele segura uma ponta do PTY: sem soltar, a leitura nunca acaba
 
-    // Pega "app" e "path" do JSON
-    let app = args_map.get("app").and_then(|v| v.as_str());
-    let path = args_map.get("path").and_then(|v| v.as_str());
-
-    match (app, path) {
-        (Some(app_str), Some(path_str)) => {
-            println!("Tentando abrir '{}' no '{}'...", path_str, app_str);
-            
-            let cmd_para_rodar = if cfg!(target_os = "macos") && app_str == "rustrover" {
-                format!("open -a RustRover \"{}\"", path_str)
-            } else if cfg!(target_os = "macos") {
-                format!("open -a \"{}\" \"{}\"", app_str, path_str)
-            } else {
-                format!("{} \"{}\"", app_str, path_str)
-            };
+    let guardada = espelho.is_some();
+    let guardado = Arc::new(Mutex::new(Vec::new()));
+    let lidos = Arc::new(AtomicU64::new(0)); // Pro limite de saída
+    let leitura = espelho.map(|mestre| {
+        let (guardado, lidos) = (Arc::clone(&guardado), Arc::clone(&lidos));
+        task::spawn_blocking(move || espelhar(mestre, &guardado, &lidos))
+    });
 
-            println!("(Usando o comando: '{}')", cmd_para_rodar);
-            // Re-usa o 'handle_execute_command' (só que com JSON fake)
-            let cmd_json = serde_json::json!({ "cmd": cmd_para_rodar });
-            handle_execute_command(Some(cmd_json));
-        }
-        _ => {
-            eprintln!("Erro: Oráculo mandou 'open_editor' mas faltou 'app' ou 'path' nos ARGS!");
-        }
+    // Grupo próprio; o terminal só é dele se ele precisa (ver acima)
+    let _terminal = filho.id().filter(|_| teclado).map(limites::primeiro_plano);
+    let resultado = limites::vigiar(&mut filho, limites, comando, guardada.then_some(&*lidos)).await;
+    if let Some(leitura) = leitura {
+        let _ = leitura.await;
     }
-}// --- MÓDULO EXECUTOR ---
-// As "Mãos" do Fenrir.
-// Agora ele recebe 'task_args' (JSON) e se vira.
-
-use crate::oraculo::FenrirTask; // Precisa saber o que é uma Task
-use chrono::Local;
-use serde_json::Value; // Importa o 'Value' (JSON genérico)
-use std::fs::OpenOptions;
-use std::io::{self, Write};
-use tokio::process::Command;
-use tokio::task;
-
-// --- CONSTANTES (só do Executor) ---
-const LOG_FILE: &str = "fenrir_tasks.log";
-
-// --- FUNÇÕES PÚBLICAS ---
 
-// Salva a tarefa no 'fenrir_tasks.log'
-// (Não muda, logar JSON é bom)
-pub fn log_task(task: &FenrirTask) -> io::Result<()> {
-    let mut file = OpenOptions::new()
-        .append(true)
-        .create(true)
-        .open(LOG_FILE)?;
+    let desfecho = match &resultado {
+        Ok(fim) if fim.sucesso() => {
+            println!("{}", msg!("executor.terminou"));
+            fim.to_string()
+        }
+        Ok(fim) => {
+            eprintln!("{}", msg!("executor.saiu_com_erro", fim));
+            fim.to_string()
+        }
+        Err(e) => {
+            eprintln!("{}", msg!("executor.perdi_de_vista", e));
+            eventos::marcar(Desfecho::Falha);
+            e.to_string()
+        }
+    };
+    // (O PTY devolve '\r\n'; pro ':last' fica o '\n' de sempre)
+    let texto = String::from_utf8_lossy(&guardado.lock().unwrap_or_else(|e| e.into_inner())).replace("\r\n", "\n");
+    guardar_saida(Saida { rotulo: comando.to_string(), desfecho, texto, arquivo: None, guardada });
+}
 
-    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
-    let log_entry = format!(
-        "\n--- [ {} ] ---\n{}\n",
-        timestamp,
-        serde_json::to_string_pretty(task).unwrap_or("Erro ao serializar tarefa".to_string())
-    );
+// Repassa o que sai do PTY pra nossa tela e guarda o final
+// (Bloqueante: acaba quando o comando, e quem herdou a saída dele, fecha o PTY)
+fn espelhar(mut mestre: File, guardado: &Mutex<Vec<u8>>, lidos: &AtomicU64) {
+    let mut buffer = [0u8; 4096];
+    let mut stdout = io::stdout();
+    loop {
+        let n = match mestre.read(&mut buffer) {
+            Ok(0) | Err(_) => break, // EIO = o último escravo fechou
+            Ok(n) => n,
+        };
+        lidos.fetch_add(n as u64, Ordering::Relaxed);
+        let _ = stdout.write_all(&buffer[..n]).and_then(|_| stdout.flush());
 
-    file.write_all(log_entry.as_bytes())
+        let mut guardado = guardado.lock().unwrap_or_else(|e| e.into_inner());
+        guardado.extend_from_slice(&buffer[..n]);
+        if guardado.len() > MAX_SAIDA_GUARDADA {
+            let sobra = guardado.len() - MAX_SAIDA_GUARDADA;
+            guardado.drain(..sobra);
+        }
+    }
 }
 
-// Pergunta 's' ou 'n' (O Freio de Mão)
-// (Não muda)
-pub async fn ask_for_confirmation(acao_proposta: &str) -> bool {
-    print!("{}", acao_proposta);
-    io::stdout().flush().unwrap();
+// Roda um comando interativo no PTY (o Fenrir some até ele sair)
+async fn handle_execute_command_pty(comando: &str, cwd: &Path, limites: &Limites) {
+    let rotulo = comando.split_whitespace().next().unwrap_or("sessao").rsplit('/').next().unwrap_or("sessao").to_string();
+    let (comando_dono, cwd_dono, limites_dono) = (comando.to_string(), cwd.to_path_buf(), *limites);
 
-    let result = task::spawn_blocking(|| {
-        let mut input = String::new();
-        io::stdin().read_line(&mut input).unwrap_or(0);
-        input.trim().to_lowercase()
-    })
-    .await;
-
-    match result {
-        Ok(input) => input == "s" || input == "sim",
-        Err(_) => false,
+    let resultado = task::spawn_blocking(move || terminal::rodar(&comando_dono, &cwd_dono, &rotulo, &limites_dono)).await;
+    match resultado {
+        Ok(Ok(sessao)) => {
+            guardar_saida(Saida {
+                rotulo: comando.to_string(),
+                desfecho: sessao.status.clone(),
+                texto: String::new(),
+                arquivo: Some(sessao.transcricao.display().to_string()),
+                guardada: true,
+            });
+            let evento = json!({
+                "cmd": comando,
+                "status": sessao.status,
+                "sucesso": sessao.sucesso,
+                "transcricao": sessao.transcricao.display().to_string(),
+            });
+            if let Err(e) = log_evento("PTY", &evento) {
+                eprintln!("{}", msg!("executor.erro_log_sessao", e));
+            }
+        }
+        Ok(Err(e)) => {
+            eprintln!("{}", msg!("geral.oxe", e));
+            eventos::marcar(Desfecho::Falha);
+        }
+        Err(e) => {
+            eprintln!("{}", msg!("executor.pty_capotou", e));
+            eventos::marcar(Desfecho::Falha);
+        }
     }
 }
 
-// Executa um comando no shell
-// AGORA ELA RECEBE O JSON DE ARGS
-pub fn handle_execute_command(args: Option<Value>) {
-    // A gente vai no JSON, acha a chave "cmd", e pega o texto.
+// Testa um comando na SANDBOX (e espera ele terminar)
+// Devolve 'true' se rodou limpo lá dentro. No dry-run só mostra o embrulho.
+pub async fn handle_execute_command_sandbox(args: Option<Value>, cwd: &Path, config: &ConfigSandbox, limites: &Limites, dry_run: bool) -> bool {
     let comando = match args.as_ref().and_then(|a| a.get("cmd")).and_then(|v| v.as_str()) {
         Some(cmd_str) => cmd_str,
         None => {
-            eprintln!("Erro: Oráculo mandou 'command' mas não mandou o JSON de 'cmd'!");
-            return;
+            eprintln!("{}", msg!("executor.sem_cmd"));
+            eventos::marcar(Desfecho::Falha);
+            return false;
         }
     };
 
-    println!("Rodando: '{}'...", comando);
-    let output = if cfg!(target_os = "windows") {
-        Command::new("cmd")
-            .arg("/C")
-            .arg(comando)
-            .spawn()
-    } else {
-        Command::new("sh")
-            .arg("-c")
-            .arg(comando)
-            .spawn()
-    };
-    
-    match output {
-        Ok(_) => println!("Comando enviado pro terminal."),
-        Err(e) => eprintln!("Oxe! Deu erro ao TENTAR rodar o comando: {}", e),
+    if dry_run {
+        match sandbox::montar(comando, cwd, config) {
+            Ok(isolado) => mostrar_dry_run(&isolado.cmd, Some(&isolado.scratch.display().to_string())),
+            Err(e) => {
+                eprintln!("{}", msg!("geral.oxe", e));
+                eventos::marcar(Desfecho::Falha);
+            }
+        }
+        return false;
+    }
+
+    println!("{}", msg!("executor.testando_sandbox", comando));
+    match sandbox::testar(comando, cwd, config, limites).await {
+        Ok(sucesso) => sucesso,
+        Err(e) => {
+            eprintln!("{}", msg!("geral.oxe", e));
+            eventos::marcar(Desfecho::Falha);
+            false
+        }
     }
 }
 
 // Abre um arquivo no editor
-// AGORA ELA RECEBE O JSON DE ARGS
-pub fn handle_open_editor(args: Option<Value>) {
+// Argv puro (nada de 'sh -c'), com linha:coluna e fallback pro $VISUAL/$EDITOR.
+// (Não abriu, por qualquer motivo? Conta como falha pro código de saída.)
+pub async fn handle_open_editor(args: Option<Value>, cwd: &Path, dry_run: bool) {
     let args_map = match args.as_ref().and_then(|a| a.as_object()) {
         Some(map) => map,
         None => {
-            eprintln!("Erro: Oráculo mandou 'open_editor' mas não mandou os ARGS!");
+            eprintln!("{}", msg!("executor.editor_sem_args"));
+            eventos::marcar(Desfecho::Falha);
             return;
         }
     };
 
-    // Pega "app" e "path" do JSON
+    // Pega "app" (opcional) e "path" do JSON
     let app = args_map.get("app").and_then(|v| v.as_str());
-    let path = args_map.get("path").and_then(|v| v.as_str());
-
-    match (app, path) {
-        (Some(app_str), Some(path_str)) => {
-            println!("Tentando abrir '{}' no '{}'...", path_str, app_str);
-            
-            let cmd_para_rodar = if cfg!(target_os = "macos") && app_str == "rustrover" {
-                format!("open -a RustRover \"{}\"", path_str)
-            } else if cfg!(target_os = "macos") {
-                format!("open -a \"{}\" \"{}\"", app_str, path_str)
-            } else {
-                format!("{} \"{}\"", app_str, path_str)
-            };
+    let Some(path) = args_map.get("path").and_then(|v| v.as_str()) else {
+        eprintln!("{}", msg!("executor.editor_sem_path"));
+        eventos::marcar(Desfecho::Falha);
+        return;
+    };
 
-            println!("(Usando o comando: '{}')", cmd_para_rodar);
-            // Re-usa o 'handle_execute_command' (só que com JSON fake)
-            let cmd_json = serde_json::json!({ "cmd": cmd_para_rodar });
-            handle_execute_command(Some(cmd_json));
+    // "main.rs" sem estar no 'src/'? O resolvedor caça na árvore do projeto.
+    let mut alvo = editor::separar_alvo(path, cwd);
+    match resolvedor::resolver(&alvo.caminho, cwd).await {
+        Some(caminho) => alvo.caminho = caminho,
+        None => {
+            eventos::marcar(Desfecho::Falha);
+            return;
         }
-        _ => {
-            eprintln!("Erro: Oráculo mandou 'open_editor' mas faltou 'app' ou 'path' nos ARGS!");
+    }
+
+    let mut abertura = match editor::montar(app, &alvo, cwd) {
+        Ok(a) => a,
+        Err(e) => {
+            eprintln!("{}", msg!("geral.erro", e));
+            eventos::marcar(Desfecho::Falha);
+            return;
         }
+    };
+    abertura.cmd.current_dir(cwd);
+
+    if dry_run {
+        mostrar_dry_run(&abertura.cmd, None);
+        return;
     }
-}
\ No newline at end of file
+
+    println!(
+        "{}",
+        msg!("executor.abrindo_editor", alvo.caminho, abertura.cmd.as_std().get_program().to_string_lossy())
+    );
+
+    if abertura.esperar {
+        // Editor de terminal: ele é dono do TTY até fechar
+        match abertura.cmd.status().await {
+            Ok(status) if status.success() => println!("{}", msg!("executor.editor_fechado")),
+            Ok(status) => {
+                eprintln!("{}", msg!("executor.editor_erro", status));
+                eventos::marcar(Desfecho::Falha);
+            }
+            Err(e) => {
+                eprintln!("{}", msg!("executor.editor_falhou", e));
+                eventos::marcar(Desfecho::Falha);
+            }
+        }
+    } else {
+        match abertura.cmd.spawn() {
+            Ok(_) => println!("{}", msg!("executor.editor_aberto")),
+            Err(e) => {
+                eprintln!("{}", msg!("executor.editor_falhou", e));
+                eventos::marcar(Desfecho::Falha);
+            }
+        }
+    }
+}
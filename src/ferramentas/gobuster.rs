@@ -1,22 +1,25 @@
 // --- MÓDULO GOBUSTER (HARDCODED) ---
 // Pra achar diretório que nem um "Semi Deus"
 
+use super::{progresso, ComandoPronto};
+use crate::config;
+use crate::limites::Limites;
+use crate::msg;
+use crate::tela::{self, Tabela};
+use regex::Regex;
 use serde_json::Value;
 use std::fs;
 use std::path::Path;
+use std::sync::OnceLock;
 use tokio::process::Command;
 
-// A IA vai chamar 'TAREFA: gobuster'
-pub fn run(args: Option<Value>) {
+// Monta o comando do Gobuster (sem rodar)
+pub fn montar(args: Option<Value>) -> Result<ComandoPronto, String> {
     let args_map = match args.as_ref().and_then(|a| a.as_object()) {
         Some(map) => map,
-        None => {
-            eprintln!("Erro: Oráculo mandou 'gobuster' mas não mandou os ARGS!");
-            return;
-        }
+        None => return Err(msg!("ferramentas.sem_args", "gobuster")),
     };
 
-    println!("Rodando Gobuster (Hardcoded)...");
     let mut cmd = Command::new("gobuster");
 
     // --- MUDANÇA "SÊNIOR": A gente SEMPRE usa o modo 'dir' por padrão
@@ -29,24 +32,19 @@ pub fn run(args: Option<Value>) {
             cmd.arg(u);
             u // Salva pra gente usar no log
         }
-        None => {
-            eprintln!("Erro: Oráculo mandou 'gobuster' mas faltou o 'url'!");
-            return;
-        }
+        None => return Err(msg!("ferramentas.faltou", "gobuster", "url")),
     };
 
     // 2. Pega a 'wordlist' (opcional, com um DEFAULT "pique sênior")
+    let padrao = config::atual().ferramentas.wordlist_gobuster; // DEFAULT (muda na config)
     let wordlist = args_map
         .get("wordlist")
         .and_then(|v| v.as_str())
-        .unwrap_or("/usr/share/wordlists/dirbuster/directory-list-2.3-medium.txt"); // DEFAULT
+        .unwrap_or(&padrao);
 
     // Checa se a wordlist existe ANTES de rodar
     if !Path::new(wordlist).exists() {
-        eprintln!("Erro: Wordlist '{}' não encontrada, seu corno!", wordlist);
-        eprintln!("A IA sugeriu essa, mas talvez você precise de outra?");
-        eprintln!("(Ex: /usr/share/wordlists/rockyou.txt, /usr/share/seclists/...)");
-        return;
+        return Err(msg!("ferramentas.sem_wordlist", wordlist));
     }
     cmd.arg("-w");
     cmd.arg(wordlist);
@@ -66,22 +64,72 @@ pub fn run(args: Option<Value>) {
         .replace("http://", "")
         .replace("https://", "")
         .replace("/", "_");
-        
-    let output_dir = format!("fenrir_logs/{}", safe_target_name);
-    let output_file = format!("{}/gobuster_scan.log", output_dir);
 
-    if let Err(e) = fs::create_dir_all(&output_dir) {
-        eprintln!("Aviso: Falha ao criar diretório de log '{}': {}", output_dir, e);
-    }
+    let output_dir = format!("{}/{}", config::atual().caminhos.saida, safe_target_name);
+    let output_file = format!("{}/gobuster_scan.log", output_dir);
     cmd.arg("-o");
     cmd.arg(&output_file);
 
+    Ok(ComandoPronto {
+        cmd,
+        saida: Some(output_file),
+        pastas: vec![output_dir],
+    })
+}
 
-    // 5. Roda
-    let spawn_result = cmd.spawn();
+// A IA vai chamar 'TAREFA: gobuster'
+// Agora ela recebe o comando já montado e ESPERA o scan,
+// com barra de progresso de verdade.
+pub async fn run(mut pronto: ComandoPronto, limites: &Limites) -> Option<String> {
+    // 5. Roda (lendo o "Progress: X / Y" do próprio Gobuster)
+    println!("{}", msg!("ferramentas.rodando", "Gobuster"));
+    let pb = progresso::nova_barra("gobuster");
+    let resultado = progresso::acompanhar(&mut pronto.cmd, "gobuster", &pb, true, limites).await;
+    pb.finish();
+    pronto.mascarar_saida();
 
-    match spawn_result {
-        Ok(_) => println!("Scan Gobuster enviado pro terminal. (Saída em: {})", output_file),
-        Err(e) => eprintln!("Oxe! Deu erro ao TENTAR rodar o 'gobuster': {}", e),
+    let desfecho = resultado.as_ref().ok().map(|fim| fim.status.to_string());
+    match resultado {
+        Ok(fim) if fim.status.sucesso() => {
+            println!(
+                "{}",
+                msg!("ferramentas.concluido", "Gobuster", fim.achados, pronto.saida.as_deref().unwrap_or("N/A"))
+            );
+            if let Some(tabela) = pronto.caminho_saida().and_then(|c| ler_resultado(&c)) {
+                tela::tabela(&tabela);
+            }
+        }
+        Ok(fim) => eprintln!(
+            "{}",
+            msg!("ferramentas.saiu_com_erro", "gobuster", fim.status, fim.ultima_linha.unwrap_or_default())
+        ),
+        Err(e) => eprintln!("{}", msg!("ferramentas.erro_rodar", "gobuster", e)),
     }
-}
\ No newline at end of file
+    desfecho
+}
+
+// Lê o '-o' do gobuster ("/admin (Status: 301) [Size: 178] [--> /admin/]") e monta a tabela
+pub fn ler_resultado(arquivo: &Path) -> Option<Tabela> {
+    let texto = fs::read_to_string(arquivo).ok()?;
+    let linhas: Vec<Vec<String>> = texto
+        .lines()
+        .filter_map(|l| regex_linha().captures(l.trim()))
+        .map(|c| {
+            let campo = |i: usize| c.get(i).map(|m| m.as_str().trim().to_string()).unwrap_or_default();
+            vec![campo(1), campo(2), campo(3), campo(4)]
+        })
+        .collect();
+    (!linhas.is_empty()).then(|| Tabela {
+        titulo: msg!("tela.gobuster_titulo"),
+        cabecalho: vec![msg!("tela.col_caminho"), msg!("tela.col_status"), msg!("tela.col_tamanho"), msg!("tela.col_destino")],
+        linhas,
+    })
+}
+
+fn regex_linha() -> &'static Regex {
+    static LINHA: OnceLock<Regex> = OnceLock::new();
+    LINHA.get_or_init(|| {
+        Regex::new(r"^(\S+)\s+\(Status:\s*(\d+)\)(?:\s*\[Size:\s*(\d+)\])?(?:\s*\[-->\s*([^\]]+)\])?")
+            .expect("regex do gobuster")
+    })
+}
@@ -1,8 +1,128 @@
-[Immersive content redacted for brevity.]
 pub mod nmap;
 pub mod sqlmap;
-pub mod reporter; // A "ARMA" DO TECH LEAD
+// pub mod reporter; // A "ARMA" DO TECH LEAD (ainda não chegou)
 pub mod gobuster; // A NOVA ARMA
+pub mod progresso; // Barra de progresso lendo o stream das ferramentas
 // pub mod metasploit; // (Exemplo futuro)
-[Immersive content redacted for brevity.]
-mod nmap;
\ No newline at end of file
+
+use crate::eventos::{self, Desfecho};
+use crate::executor;
+use crate::limites::Limites;
+use crate::msg;
+use crate::segredos;
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tokio::process::Command;
+
+// O comando já montado (argv "hardcoded", a IA só preencheu)
+// + onde a ferramenta vai cuspir a saída.
+// 'montar' só PLANEJA as pastas; quem cria é o 'criar_pastas', logo antes de rodar.
+pub struct ComandoPronto {
+    pub cmd: Command,
+    pub saida: Option<String>,
+    pub pastas: Vec<String>,
+}
+
+impl ComandoPronto {
+    // Roda a ferramenta a partir da pasta 'cwd' (a da sessão).
+    // As pastas de saída são relativas, então vão junto pra lá.
+    pub fn na_pasta(&mut self, cwd: &Path) {
+        self.cmd.current_dir(cwd);
+        for pasta in &mut self.pastas {
+            *pasta = cwd.join(&*pasta).display().to_string();
+        }
+    }
+
+    // Onde a saída fica de verdade ('saida' é relativa à pasta em que ele roda)
+    pub fn caminho_saida(&self) -> Option<PathBuf> {
+        let saida = Path::new(self.saida.as_deref()?);
+        Some(match self.cmd.as_std().get_current_dir() {
+            Some(cwd) => cwd.join(saida),
+            None => saida.to_path_buf(),
+        })
+    }
+
+    // A ferramenta grava o próprio comando na saída (o 'args' do XML do nmap,
+    // a URL com senha no log do gobuster): mascara os segredos lá também.
+    // (Saída que é pasta, tipo a do sqlmap, fica como a ferramenta deixou.)
+    pub fn mascarar_saida(&self) {
+        let Some(caminho) = self.caminho_saida().filter(|c| c.is_file()) else {
+            return;
+        };
+        if let Err(e) = segredos::mascarar_arquivo(&caminho) {
+            eprintln!("{}", msg!("geral.aviso", e));
+        }
+    }
+
+    // Cria as pastas de output planejadas (falhar aqui é só aviso)
+    pub fn criar_pastas(&self) {
+        for pasta in &self.pastas {
+            if let Err(e) = fs::create_dir_all(pasta) {
+                eprintln!("{}", msg!("ferramentas.falha_pasta", pasta, e));
+            }
+        }
+    }
+}
+
+// Monta o comando de qualquer ferramenta pelo nome, SEM rodar.
+// (Quem roda é o 'run' de cada uma, ou a fila)
+pub fn montar(ferramenta: &str, args: Option<Value>) -> Result<ComandoPronto, String> {
+    match ferramenta {
+        "nmap" => nmap::montar(args),
+        "gobuster" => gobuster::montar(args),
+        "sqlmap" => sqlmap::montar(args),
+        outra => Err(msg!("ferramentas.nao_existe", outra)),
+    }
+}
+
+// Roda uma ferramenta pelo nome (o "TAREFA: nmap" do Oráculo cai aqui).
+// Roda na pasta da sessão ('cwd'). No dry-run monta tudo, mostra o argv e NÃO roda nada.
+pub async fn rodar(ferramenta: &str, args: Option<Value>, cwd: &Path, limites: &Limites, dry_run: bool) {
+    let mut pronto = match montar(ferramenta, args) {
+        Ok(p) => p,
+        Err(e) => {
+            eprintln!("{}", msg!("geral.erro", e));
+            eventos::marcar(Desfecho::Falha);
+            return;
+        }
+    };
+    pronto.na_pasta(cwd);
+
+    if dry_run {
+        executor::mostrar_dry_run(&pronto.cmd, pronto.saida.as_deref());
+        return;
+    }
+
+    pronto.criar_pastas();
+    let arquivo = pronto.saida.clone();
+    // O desfecho DESSA rodada (None = nem chegou a rodar)
+    let desfecho = match ferramenta {
+        "nmap" => nmap::run(pronto, limites).await,
+        "gobuster" => gobuster::run(pronto, limites).await,
+        "sqlmap" => sqlmap::run(pronto, limites).await,
+        _ => None, // 'montar' já barrou
+    };
+    // Pro '--json': como acabou e onde tá a saída (os achados já foram, um por um).
+    // Não vem do ':last', que pode ser da tarefa anterior se essa nem subiu.
+    let evento = match desfecho {
+        Some(desfecho) => {
+            executor::apontar_arquivo(ferramenta, arquivo.clone());
+            json!({ "ferramenta": ferramenta, "desfecho": desfecho, "arquivo": arquivo })
+        }
+        None => {
+            eventos::marcar(Desfecho::Falha);
+            json!({ "ferramenta": ferramenta, "desfecho": msg!("ferramentas.nem_rodou"), "arquivo": null })
+        }
+    };
+    eventos::emitir("resultado", &evento);
+}
+
+// Qual chave dos ARGS é o "alvo" de cada ferramenta
+pub fn chave_alvo(ferramenta: &str) -> Option<&'static str> {
+    match ferramenta {
+        "nmap" => Some("target"),
+        "gobuster" | "sqlmap" => Some("url"),
+        _ => None,
+    }
+}
@@ -1,72 +1,148 @@
 // --- MÓDULO NMAP (AGORA "HARDCODED") ---
 // A IA só preenche, a gente FAZ.
 
+use super::{progresso, ComandoPronto};
+use crate::config;
+use crate::limites::Limites;
+use crate::msg;
+use crate::tela::{self, Tabela};
+use regex::Regex;
 use serde_json::Value;
-use std::fs; // Pra gente poder criar a pasta de output
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
 use tokio::process::Command;
 
-// A função 'run' é o nosso "backend carai"
-// ... (código existente) ...
-pub fn run(args: Option<Value>) {
+// Monta o comando do Nmap (sem rodar)
+pub fn montar(args: Option<Value>) -> Result<ComandoPronto, String> {
     let args_map = match args.as_ref().and_then(|a| a.as_object()) {
-// ... (código existente) ...
+        Some(map) => map,
+        None => return Err(msg!("ferramentas.sem_args", "nmap")),
     };
 
     // 1. Pega o 'target' (obrigatório)
-// ... (código existente) ...
-        None => {
-            eprintln!("Erro: Oráculo mandou 'nmap' mas faltou o 'target'!");
-            return;
-        }
+    let target = match args_map.get("target").and_then(|v| v.as_str()) {
+        Some(t) => t,
+        None => return Err(msg!("ferramentas.faltou", "nmap", "target")),
     };
-    
+
     // --- MUDANÇA "SÊNIOR" ---
     // A gente vai FORÇAR o output em XML pra usar no relatório.
-    let output_dir = format!("fenrir_logs/{}", target);
+    let output_dir = format!("{}/{}", config::atual().caminhos.saida, target);
     let output_xml = format!("{}/nmap_scan.xml", output_dir);
-    
-    // Cria o diretório de log pro alvo, se não existir
-    if let Err(e) = fs::create_dir_all(&output_dir) {
-        eprintln!("Aviso: Falha ao criar diretório de log '{}': {}", output_dir, e);
-        // Não retorna, tenta rodar mesmo assim
-    }
+    // (O diretório de log pro alvo só é criado na hora de rodar: 'criar_pastas')
     // --- FIM DA MUDANÇA ---
 
-
     // 2. Pega as 'flags' (opcional)
-// ... (código existente) ...
+    let flags = match args_map.get("flags").and_then(|v| v.as_array()) {
+        Some(lista) => lista
             .iter()
             .map(|v| v.as_str().unwrap_or("")) // Converte cada flag
             .filter(|s| !s.is_empty()) // Remove flags vazias
             .collect::<Vec<&str>>(),
         None => vec![], // Sem flags
     };
-// ... (código existente) ...
+
+    // 3. Monta o comando (seguro)
     // A IA não injeta nada aqui.
-    println!("Rodando Nmap (Hardcoded)...");
     let mut cmd = Command::new("nmap"); // O COMANDO "HARDCODED"
-    
+
     // Adiciona as flags (seguras)
     for flag in flags {
         cmd.arg(flag);
     }
-    
+
     // --- MUDANÇA "SÊNIOR" ---
     // Adiciona nossas flags "hardcoded" de output
     cmd.arg("-oX"); // Output em XML
     cmd.arg(&output_xml); // O caminho do arquivo
+    cmd.arg("--stats-every"); // Pra barra de progresso ter o que ler
+    cmd.arg("5s");
     // --- FIM DA MUDANÇA ---
-    
+
     // Adiciona o target (seguro)
-// ... (código existente) ...
     cmd.arg(target);
 
-    // 4. Roda
-// ... (código existente) ...
-    let spawn_result = cmd.spawn();
-    
-    match spawn_result {
-        Ok(_) => println!("Scan Nmap enviado pro terminal. (Saída em: {})", output_xml),
-        Err(e) => eprintln!("Oxe! Deu erro ao TENTAR rodar o 'nmap': {}", e),
+    Ok(ComandoPronto {
+        cmd,
+        saida: Some(output_xml),
+        pastas: vec![output_dir],
+    })
+}
+
+// A função 'run' é o nosso "backend carai"
+// Agora ela recebe o comando já montado e ESPERA o scan,
+// com barra de progresso de verdade.
+pub async fn run(mut pronto: ComandoPronto, limites: &Limites) -> Option<String> {
+    // 4. Roda (acompanhando o '--stats-every')
+    println!("{}", msg!("ferramentas.rodando", "Nmap"));
+    let pb = progresso::nova_barra("nmap");
+    let resultado = progresso::acompanhar(&mut pronto.cmd, "nmap", &pb, true, limites).await;
+    pb.finish();
+    pronto.mascarar_saida();
+
+    let desfecho = resultado.as_ref().ok().map(|fim| fim.status.to_string());
+    match resultado {
+        Ok(fim) if fim.status.sucesso() => {
+            println!(
+                "{}",
+                msg!("ferramentas.concluido", "Nmap", fim.achados, pronto.saida.as_deref().unwrap_or("N/A"))
+            );
+            if let Some(tabela) = pronto.caminho_saida().and_then(|c| ler_resultado(&c)) {
+                tela::tabela(&tabela);
+            }
+        }
+        Ok(fim) => eprintln!(
+            "{}",
+            msg!("ferramentas.saiu_com_erro", "nmap", fim.status, fim.ultima_linha.unwrap_or_default())
+        ),
+        Err(e) => eprintln!("{}", msg!("ferramentas.erro_rodar", "nmap", e)),
     }
-}
\ No newline at end of file
+    desfecho
+}
+
+// Lê o XML ('-oX') e monta a tabela das portas: host, porta, estado, serviço e versão
+pub fn ler_resultado(arquivo: &Path) -> Option<Tabela> {
+    let xml = fs::read_to_string(arquivo).ok()?;
+    let mut linhas = Vec::new();
+    for host in xml.split("<host ").skip(1) {
+        let endereco = atributo(host, "address", "addr").unwrap_or_default();
+        for porta in regex_porta().captures_iter(host) {
+            let corpo = &porta[3];
+            let versao = ["product", "version", "extrainfo"]
+                .iter()
+                .filter_map(|a| atributo(corpo, "service", a))
+                .collect::<Vec<_>>()
+                .join(" ");
+            linhas.push(vec![
+                endereco.clone(),
+                format!("{}/{}", &porta[2], &porta[1]),
+                atributo(corpo, "state", "state").unwrap_or_default(),
+                atributo(corpo, "service", "name").unwrap_or_default(),
+                versao,
+            ]);
+        }
+    }
+    (!linhas.is_empty()).then(|| Tabela {
+        titulo: msg!("tela.nmap_titulo"),
+        cabecalho: vec![msg!("tela.col_host"), msg!("tela.col_porta"), msg!("tela.col_estado"), msg!("tela.col_servico"), msg!("tela.col_versao")],
+        linhas,
+    })
+}
+
+// O valor de 'atributo' na primeira tag '<tag ...>' do trecho (já sem '&amp;' e cia.)
+fn atributo(trecho: &str, tag: &str, atributo: &str) -> Option<String> {
+    let inicio = trecho.find(&format!("<{} ", tag))?;
+    let tag = &trecho[inicio..inicio + trecho[inicio..].find('>')?];
+    let chave = format!(" {}=\"", atributo);
+    let valor = &tag[tag.find(&chave)? + chave.len()..];
+    let valor = &valor[..valor.find('"')?];
+    Some(valor.replace("&quot;", "\"").replace("&lt;", "<").replace("&gt;", ">").replace("&apos;", "'").replace("&amp;", "&"))
+}
+
+fn regex_porta() -> &'static Regex {
+    static PORTA: OnceLock<Regex> = OnceLock::new();
+    PORTA.get_or_init(|| {
+        Regex::new(r#"(?s)<port protocol="([^"]+)" portid="([^"]+)">(.*?)</port>"#).expect("regex das portas")
+    })
+}
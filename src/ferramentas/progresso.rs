@@ -0,0 +1,316 @@
+// --- MÓDULO PROGRESSO ---
+// Scan longo com terminal mudo é angústia pura.
+// O Nmap ('--stats-every') e o Gobuster já contam quanto falta,
+// a gente só escuta o stream e desenha uma barra de verdade.
+
+use crate::eventos::{self, Desfecho};
+use crate::executor::{self, Saida};
+use crate::limites::{self, Fim, Limites};
+use crate::msg;
+use indicatif::{ProgressBar, ProgressStyle};
+use serde_json::json;
+use std::collections::VecDeque;
+use std::io;
+use std::process::Stdio;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::process::Command;
+use tokio::sync::mpsc;
+
+// Escala da barra: 100% = 10000 (pra não perder as casas decimais)
+const ESCALA: u64 = 10_000;
+const CAUDA_LINHAS: usize = 200; // Quanto da saída fica guardado pro ':last'
+
+// --- CONTRATO ---
+#[derive(Debug, PartialEq)]
+pub enum Evento {
+    Andamento { percentual: f64, eta: Option<String> },
+    Achado(String),
+}
+
+// O que sobrou depois que a ferramenta terminou
+pub struct Acompanhamento {
+    pub status: Fim, // Saída normal ou "killed: ..." (estourou um limite)
+    pub achados: u64,
+    pub ultima_linha: Option<String>, // Útil pra explicar uma falha
+}
+
+// --- PARSERS ---
+
+// Nmap com '--stats-every' cospe coisas tipo:
+// "SYN Stealth Scan Timing: About 45.50% done; ETC: 12:34 (0:00:12 remaining)"
+// E com '-v': "Discovered open port 80/tcp on 10.0.0.1"
+pub fn ler_linha_nmap(linha: &str) -> Option<Evento> {
+    let linha = linha.trim();
+
+    if let Some(resto) = linha.strip_prefix("Discovered ") {
+        return Some(Evento::Achado(resto.to_string()));
+    }
+
+    let (_, depois) = linha.split_once("About ")?;
+    let (pct_str, resto) = depois.split_once("% done")?;
+    let percentual = pct_str.trim().parse::<f64>().ok()?;
+
+    let eta = resto
+        .split_once('(')
+        .and_then(|(_, r)| r.split_once(" remaining)"))
+        .map(|(tempo, _)| tempo.trim().to_string());
+
+    Some(Evento::Andamento { percentual, eta })
+}
+
+// Gobuster (modo dir) cospe:
+// "Progress: 1234 / 220560 (0.56%)"
+// "/admin                (Status: 301) [Size: 0] [--> /admin/]"
+pub fn ler_linha_gobuster(linha: &str) -> Option<Evento> {
+    let linha = linha.trim();
+
+    if let Some(resto) = linha.strip_prefix("Progress:") {
+        let (_, pct) = resto.split_once('(')?;
+        let (pct_str, _) = pct.split_once('%')?;
+        let percentual = pct_str.trim().parse::<f64>().ok()?;
+        return Some(Evento::Andamento { percentual, eta: None });
+    }
+
+    if linha.contains("(Status:") {
+        return Some(Evento::Achado(linha.split_whitespace().collect::<Vec<_>>().join(" ")));
+    }
+
+    None
+}
+
+fn parser_da(ferramenta: &str) -> Option<fn(&str) -> Option<Evento>> {
+    match ferramenta {
+        "nmap" => Some(ler_linha_nmap),
+        "gobuster" => Some(ler_linha_gobuster),
+        _ => None,
+    }
+}
+
+// --- BARRA ---
+
+// Uma barra nova, já no estilo do Fenrir
+pub fn nova_barra(nome: &str) -> ProgressBar {
+    let pb = ProgressBar::new(ESCALA);
+    estilizar(&pb, nome);
+    pb
+}
+
+// Deixa uma barra (nova ou vinda de um MultiProgress) no estilo certo
+pub fn estilizar(pb: &ProgressBar, nome: &str) {
+    pb.set_length(ESCALA);
+    pb.set_style(
+        ProgressStyle::default_bar()
+            .template("  {prefix:.bold} [{bar:25.green/white}] {percent:>3}% {msg}")
+            .unwrap()
+            .progress_chars("#>-"),
+    );
+    pb.set_prefix(nome.to_string());
+}
+
+// --- EXECUÇÃO ACOMPANHADA ---
+
+// Roda o 'cmd' lendo stdout E stderr, e vai alimentando a barra.
+// Achados (porta aberta, diretório) são impressos acima da barra
+// quando 'mostrar_achados' tá ligado.
+pub async fn acompanhar(
+    cmd: &mut Command,
+    ferramenta: &str,
+    pb: &ProgressBar,
+    mostrar_achados: bool,
+    limites: &Limites,
+) -> io::Result<Acompanhamento> {
+    limites::preparar(cmd, limites);
+    let rotulo = limites::rotulo(cmd);
+    let mut child = cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .inspect_err(|_| eventos::marcar(Desfecho::Falha))?;
+
+    let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+    let bytes_lidos = Arc::new(AtomicU64::new(0)); // Pro limite de saída
+    if let Some(stdout) = child.stdout.take() {
+        tokio::spawn(repassar_linhas(stdout, tx.clone(), Arc::clone(&bytes_lidos)));
+    }
+    if let Some(stderr) = child.stderr.take() {
+        tokio::spawn(repassar_linhas(stderr, tx, Arc::clone(&bytes_lidos)));
+    }
+
+    let parser = parser_da(ferramenta);
+    let mut achados: u64 = 0;
+    let mut ultima_linha: Option<String> = None;
+    let mut cauda: VecDeque<String> = VecDeque::new();
+
+    // Lê o stream E vigia os limites ao mesmo tempo
+    // (estourou? o grupo morre, os pipes fecham e o loop acaba sozinho)
+    let vigia = limites::vigiar(&mut child, limites, &rotulo, Some(&bytes_lidos));
+    let leitura = async {
+        while let Some(linha) = rx.recv().await {
+            if !linha.trim().is_empty() {
+                ultima_linha = Some(linha.trim().to_string());
+            }
+
+            let evento = match parser {
+                Some(parse) => parse(&linha),
+                None => None,
+            };
+            // Linha de andamento não interessa depois (o Gobuster cospe milhares)
+            if !matches!(evento, Some(Evento::Andamento { .. })) {
+                if cauda.len() == CAUDA_LINHAS {
+                    cauda.pop_front();
+                }
+                cauda.push_back(linha.clone());
+            }
+
+            match evento {
+                Some(Evento::Andamento { percentual, eta }) => {
+                    pb.set_position(((percentual / 100.0) * ESCALA as f64) as u64);
+                    pb.set_message(formatar_mensagem(achados, eta.as_deref()));
+                }
+                Some(Evento::Achado(achado)) => {
+                    achados += 1;
+                    eventos::emitir("achado", &json!({ "ferramenta": ferramenta, "achado": achado }));
+                    if mostrar_achados {
+                        pb.println(format!("  [+] {}", achado));
+                    }
+                    pb.set_message(formatar_mensagem(achados, None));
+                }
+                None if parser.is_none() && !linha.trim().is_empty() => {
+                    // Ferramenta sem parser: pelo menos mostra a última linha
+                    pb.set_message(linha.trim().chars().take(60).collect::<String>());
+                }
+                None => {}
+            }
+        }
+    };
+
+    let (status, _) = tokio::join!(vigia, leitura);
+    let status = status?;
+    executor::guardar_saida(Saida {
+        rotulo,
+        desfecho: status.to_string(),
+        texto: Vec::from(cauda).join("\n"),
+        arquivo: None,
+        guardada: true,
+    });
+    if status.sucesso() {
+        pb.set_position(ESCALA);
+    }
+    Ok(Acompanhamento {
+        status,
+        achados,
+        ultima_linha,
+    })
+}
+
+fn formatar_mensagem(achados: u64, eta: Option<&str>) -> String {
+    match eta {
+        Some(eta) => msg!("progresso.achados_eta", achados, eta),
+        None => msg!("progresso.achados", achados),
+    }
+}
+
+// Lê o stream em pedaços e quebra em '\n' E '\r'
+// (o Gobuster reescreve a linha de progresso com '\r')
+async fn repassar_linhas<R: AsyncRead + Unpin>(mut leitor: R, tx: mpsc::UnboundedSender<String>, contador: Arc<AtomicU64>) {
+    let mut buffer = [0u8; 4096];
+    let mut pendente: Vec<u8> = Vec::new();
+
+    loop {
+        let lidos = match leitor.read(&mut buffer).await {
+            Ok(0) | Err(_) => break,
+            Ok(n) => n,
+        };
+        contador.fetch_add(lidos as u64, Ordering::Relaxed);
+
+        for &byte in &buffer[..lidos] {
+            if byte == b'\n' || byte == b'\r' {
+                if !pendente.is_empty() {
+                    let _ = tx.send(String::from_utf8_lossy(&pendente).to_string());
+                    pendente.clear();
+                }
+            } else {
+                pendente.push(byte);
+            }
+        }
+    }
+
+    if !pendente.is_empty() {
+        let _ = tx.send(String::from_utf8_lossy(&pendente).to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nmap_andamento_com_eta() {
+        let linha = "SYN Stealth Scan Timing: About 45.50% done; ETC: 12:34 (0:00:12 remaining)";
+        assert_eq!(
+            ler_linha_nmap(linha),
+            Some(Evento::Andamento { percentual: 45.5, eta: Some("0:00:12".to_string()) })
+        );
+    }
+
+    #[test]
+    fn nmap_andamento_do_service_scan() {
+        let linha = "Service scan Timing: About 66.67% done; ETC: 14:03 (0:00:05 remaining)\n";
+        assert_eq!(
+            ler_linha_nmap(linha),
+            Some(Evento::Andamento { percentual: 66.67, eta: Some("0:00:05".to_string()) })
+        );
+    }
+
+    #[test]
+    fn nmap_porta_descoberta() {
+        assert_eq!(
+            ler_linha_nmap("Discovered open port 22/tcp on 192.168.56.101"),
+            Some(Evento::Achado("open port 22/tcp on 192.168.56.101".to_string()))
+        );
+    }
+
+    #[test]
+    fn nmap_ignora_o_resto() {
+        for linha in [
+            "Starting Nmap 7.94SVN ( https://nmap.org ) at 2024-05-02 14:02 -03",
+            "Stats: 0:00:05 elapsed; 0 hosts completed (1 up), 1 undergoing SYN Stealth Scan",
+            "Nmap scan report for 10.0.0.1",
+            "22/tcp open  ssh     OpenSSH 8.9p1 Ubuntu 3ubuntu0.6",
+            "",
+        ] {
+            assert_eq!(ler_linha_nmap(linha), None, "{linha}");
+        }
+    }
+
+    #[test]
+    fn gobuster_andamento() {
+        assert_eq!(
+            ler_linha_gobuster("\rProgress: 1234 / 220560 (0.56%)"),
+            Some(Evento::Andamento { percentual: 0.56, eta: None })
+        );
+    }
+
+    #[test]
+    fn gobuster_achado_normaliza_espacos() {
+        assert_eq!(
+            ler_linha_gobuster("/admin                (Status: 301) [Size: 169] [--> http://10.0.0.1/admin/]"),
+            Some(Evento::Achado("/admin (Status: 301) [Size: 169] [--> http://10.0.0.1/admin/]".to_string()))
+        );
+    }
+
+    #[test]
+    fn gobuster_ignora_o_resto() {
+        for linha in [
+            "===============================================================",
+            "[+] Url:                     http://10.0.0.1",
+            "Starting gobuster in directory enumeration mode",
+            "Progress: sem porcentagem",
+        ] {
+            assert_eq!(ler_linha_gobuster(linha), None, "{linha}");
+        }
+    }
+}
@@ -1,27 +1,32 @@
 // --- MÓDULO SQLMAP (O PADRÃO) ---
 // A gente deixa o "esqueleto" pronto pro futuro.
 
+use super::ComandoPronto;
+use crate::config;
+use crate::executor::{self, Saida};
+use crate::limites::{self, Limites};
+use crate::msg;
+use crate::tela::{self, Tabela};
 use serde_json::Value;
-use std::fs; // Pra gente poder criar a pasta de output
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use std::process::Stdio;
 use tokio::process::Command;
 
-// A IA vai chamar 'TAREFA: sqlmap'
-// ... (código existente) ...
-pub fn run(args: Option<Value>) {
+// Monta o comando do SQLMap (sem rodar)
+pub fn montar(args: Option<Value>) -> Result<ComandoPronto, String> {
     let args_map = match args.as_ref().and_then(|a| a.as_object()) {
-// ... (código existente) ...
-            return;
-        }
+        Some(map) => map,
+        None => return Err(msg!("ferramentas.sem_args", "sqlmap")),
     };
 
-    println!("Rodando SQLMap (Hardcoded)...");
-// ... (código existente) ...
     let mut cmd = Command::new("sqlmap");
-    
+
     // --- MUDANÇA "SÊNIOR" ---
     // A gente FORÇA o modo "batch" pra ele não ficar perguntando
     cmd.arg("--batch");
-    
+
     // Pega o 'url' (quase obrigatório)
     let target_url = match args_map.get("url").and_then(|v| v.as_str()) {
         Some(url) => {
@@ -30,51 +35,109 @@ pub fn run(args: Option<Value>) {
             url // Salva o 'url' pra gente usar no nome da pasta
         }
         None => {
-             // Se não tiver 'url', talvez tenha 'flags' (tipo --version)
-             "" 
+            // Se não tiver 'url', talvez tenha 'flags' (tipo --version)
+            ""
         }
     };
-    
+
     // Se a gente tem um 'url', a gente define a pasta de output
+    let mut saida = None;
+    let mut pastas = Vec::new();
     if !target_url.is_empty() {
         // Limpa o 'url' pra virar nome de pasta
         let safe_target_name = target_url
             .replace("http://", "")
             .replace("https://", "")
             .replace("/", "_");
-            
-        let output_dir = format!("fenrir_logs/{}/sqlmap", safe_target_name);
-        
-        // Cria o diretório
-        if let Err(e) = fs::create_dir_all(&output_dir) {
-            eprintln!("Aviso: Falha ao criar diretório de log '{}': {}", output_dir, e);
-        } else {
-             cmd.arg("--output-dir");
-             cmd.arg(&output_dir);
-             println!("(Saída do Sqlmap será salva em: {})", output_dir);
-        }
+
+        let output_dir = format!("{}/{}/sqlmap", config::atual().caminhos.saida, safe_target_name);
+
+        // O diretório só é criado na hora de rodar ('criar_pastas')
+        cmd.arg("--output-dir");
+        cmd.arg(&output_dir);
+        pastas.push(output_dir.clone());
+        saida = Some(output_dir);
     }
     // --- FIM DA MUDANÇA ---
-    
-    
+
     // Pega as 'flags' (ex: --version, --dbs, --tables, --dump)
-// ... (código existente) ...
     if let Some(flags) = args_map.get("flags").and_then(|v| v.as_array()) {
-         for flag in flags {
+        for flag in flags {
             if let Some(flag_str) = flag.as_str() {
-// ... (código existente) ...
                 cmd.arg(flag_str);
             }
         }
     }
-    
+
     // ... (aqui a gente adicionaria mais lógicas 'hardcoded'
     // para --dbs, --tables, etc.) -> A IA já pode mandar em 'flags'!
-    
-    let spawn_result = cmd.spawn();
-// ... (código existente) ...
-    match spawn_result {
-        Ok(_) => println!("Comando SQLMap enviado pro terminal."),
-// ... (código existente) ...
+
+    Ok(ComandoPronto { cmd, saida, pastas })
+}
+
+// A IA vai chamar 'TAREFA: sqlmap'
+// (Recebe o comando já montado)
+// (Espera terminar, de olho nos limites: sqlmap travado é clássico)
+pub async fn run(mut pronto: ComandoPronto, limites: &Limites) -> Option<String> {
+    println!("{}", msg!("ferramentas.rodando", "SQLMap"));
+    if let Some(dir) = &pronto.saida {
+        println!("{}", msg!("ferramentas.sqlmap_saida", dir));
+    }
+
+    limites::preparar(&mut pronto.cmd, limites);
+    let rotulo = limites::rotulo(&pronto.cmd);
+    let mut filho = match pronto.cmd.stdin(Stdio::null()).spawn() {
+        Ok(f) => f,
+        Err(e) => {
+            eprintln!("{}", msg!("ferramentas.erro_rodar", "sqlmap", e));
+            return None;
+        }
+    };
+
+    let resultado = limites::vigiar(&mut filho, limites, &rotulo, None).await;
+    let desfecho = resultado.as_ref().ok().map(|fim| fim.to_string());
+    // A saída foi direto pra tela: pro ':last' fica o desfecho (e a pasta, pendurada depois)
+    if let Some(desfecho) = &desfecho {
+        executor::guardar_saida(Saida { rotulo, desfecho: desfecho.clone(), texto: String::new(), arquivo: None, guardada: false });
+    }
+    match resultado {
+        Ok(fim) if fim.sucesso() => {
+            println!("{}", msg!("ferramentas.sqlmap_fim"));
+            if let Some(tabela) = pronto.caminho_saida().and_then(|c| ler_resultado(&c)) {
+                tela::tabela(&tabela);
+            }
+        }
+        Ok(fim) => eprintln!("{}", msg!("ferramentas.sqlmap_erro", fim)),
+        Err(e) => eprintln!("{}", msg!("ferramentas.sqlmap_perdi", e)),
+    }
+    desfecho
+}
+
+// Lê o 'log' de cada alvo na pasta de saída ('<pasta>/<host>/log') e monta a tabela
+// dos pontos de injeção: "Parameter: id (GET)" seguido de "Type:" e "Title:"
+pub fn ler_resultado(pasta: &Path) -> Option<Tabela> {
+    let mut linhas: Vec<Vec<String>> = Vec::new();
+    for entrada in fs::read_dir(pasta).ok()?.flatten() {
+        let Ok(texto) = fs::read_to_string(entrada.path().join("log")) else {
+            continue;
+        };
+        let mut parametro = String::new();
+        for linha in texto.lines().map(str::trim) {
+            if let Some(p) = linha.strip_prefix("Parameter:") {
+                parametro = p.trim().to_string();
+            } else if let Some(tipo) = linha.strip_prefix("Type:") {
+                linhas.push(vec![parametro.clone(), tipo.trim().to_string(), String::new()]);
+            } else if let (Some(titulo), Some(ultima)) = (linha.strip_prefix("Title:"), linhas.last_mut()) {
+                ultima[2] = titulo.trim().to_string();
+            }
+        }
     }
-}
\ No newline at end of file
+    // O 'log' acumula as rodadas: o mesmo ponto aparece de novo
+    let mut vistas = HashSet::new();
+    linhas.retain(|l| vistas.insert(l.clone()));
+    (!linhas.is_empty()).then(|| Tabela {
+        titulo: msg!("tela.sqlmap_titulo"),
+        cabecalho: vec![msg!("tela.col_parametro"), msg!("tela.col_tecnica"), msg!("tela.col_titulo")],
+        linhas,
+    })
+}
@@ -0,0 +1,377 @@
+// --- MÓDULO FILA ---
+// Nmap em vinte hosts, um por vez e na mão? Coisa de estagiário.
+// A fila pega VÁRIOS alvos pra UMA ferramenta e roda tudo com
+// limite de paralelismo (e um respiro opcional entre um start e outro).
+
+use crate::executor;
+use crate::ferramentas::{self, progresso};
+use crate::config;
+use crate::limites::{Limites, TabelaLimites};
+use crate::msg;
+use crate::oraculo::FenrirTask;
+use crate::escopo::Escopo;
+use crate::politica::{self, Acao, Politica};
+use crate::risco;
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+// --- CONSTANTES (só da Fila) ---
+
+// --- CONTRATO ---
+pub struct ConfigFila {
+    pub max_paralelo: usize,
+    pub intervalo: Duration, // Tempo mínimo entre um start e o próximo
+    pub dry_run: bool,       // Só mostra o argv de cada alvo
+    pub limites: Limites,    // Valem pra CADA alvo
+}
+
+impl Default for ConfigFila {
+    fn default() -> Self {
+        ConfigFila {
+            max_paralelo: config::atual().ferramentas.paralelo_fila,
+            intervalo: Duration::ZERO,
+            dry_run: false,
+            limites: Limites::default(),
+        }
+    }
+}
+
+// Como terminou cada alvo
+pub enum Desfecho {
+    Sucesso,
+    Falhou(String),   // Rodou, mas saiu com erro
+    NemRodou(String), // Nem chegou a rodar (args ruins, binário sumido...)
+    Simulado,         // Dry-run: montou, mostrou, não rodou
+}
+
+pub struct ResultadoAlvo {
+    pub alvo: String,
+    pub desfecho: Desfecho,
+    pub duracao: Duration,
+    pub achados: u64,
+    pub saida: Option<String>,
+}
+
+// --- FUNÇÕES PÚBLICAS ---
+
+// Roda a 'ferramenta' em todos os 'alvos'.
+// 'args_base' são os ARGS comuns (flags, wordlist...); o alvo é enfiado
+// na chave certa de cada ferramenta ('target' pro nmap, 'url' pro gobuster...).
+// Cada alvo passa pelo MESMO funil de uma tarefa normal (escopo + política +
+// Freio de Mão), tudo ANTES de a primeira barra aparecer: pergunta no meio das
+// barras vira sopa. Quem não passou entra no resumo como "nem rodou".
+pub async fn rodar_fila(
+    ferramenta: &str,
+    alvos: Vec<String>,
+    args_base: Option<Value>,
+    config: &ConfigFila,
+    politica: &Politica,
+    escopo: &Escopo,
+) -> Result<Vec<ResultadoAlvo>, String> {
+    let chave = ferramentas::chave_alvo(ferramenta)
+        .ok_or_else(|| msg!("fila.nao_sabe", ferramenta))?;
+
+    let base = match args_base {
+        Some(Value::Object(map)) => map,
+        Some(_) => return Err(msg!("fila.args_objeto")),
+        None => Map::new(),
+    };
+
+    let mut resultados: Vec<(usize, ResultadoAlvo)> = Vec::new();
+    let mut liberados: Vec<(usize, String, Map<String, Value>, Limites)> = Vec::new();
+    let total = alvos.len();
+
+    for (indice, alvo) in alvos.into_iter().enumerate() {
+        let mut args = base.clone();
+        args.insert(chave.to_string(), Value::String(alvo.clone()));
+
+        println!("{}", msg!("fila.avaliando", indice + 1, total, alvo));
+        match liberar(ferramenta, &args, config, politica, escopo).await {
+            Ok(limites) => liberados.push((indice, alvo, args, limites)),
+            Err(motivo) => resultados.push((indice, nem_rodou(alvo, motivo))),
+        }
+    }
+    println!("{}", msg!("geral.separador"));
+
+    let multi = MultiProgress::new();
+    let geral = multi.add(ProgressBar::new(liberados.len() as u64));
+    geral.set_style(
+        ProgressStyle::default_bar()
+            .template("{prefix:.bold.yellow} [{bar:30.cyan/blue}] {pos}/{len} {msg}")
+            .unwrap()
+            .progress_chars("=> "),
+    );
+    geral.set_prefix(ferramenta.to_string());
+    geral.set_message(msg!("fila.na_fila"));
+
+    let semaforo = Arc::new(Semaphore::new(config.max_paralelo.max(1)));
+    let mut tarefas = JoinSet::new();
+
+    for (posicao, (indice, alvo, args, limites)) in liberados.into_iter().enumerate() {
+        // Segura a vaga ANTES do respiro: assim o intervalo vale entre starts.
+        let vaga = match semaforo.clone().acquire_owned().await {
+            Ok(vaga) => vaga,
+            Err(e) => {
+                geral.inc(1);
+                resultados.push((indice, nem_rodou(alvo, e.to_string())));
+                continue;
+            }
+        };
+        if posicao > 0 && !config.intervalo.is_zero() {
+            tokio::time::sleep(config.intervalo).await;
+        }
+
+        let mut pronto = match ferramentas::montar(ferramenta, Some(Value::Object(args))) {
+            Ok(p) => p,
+            Err(e) => {
+                geral.inc(1);
+                resultados.push((indice, nem_rodou(alvo, e)));
+                continue;
+            }
+        };
+
+        if config.dry_run {
+            executor::mostrar_dry_run(&pronto.cmd, pronto.saida.as_deref());
+            geral.inc(1);
+            resultados.push((
+                indice,
+                ResultadoAlvo {
+                    alvo,
+                    desfecho: Desfecho::Simulado,
+                    duracao: Duration::ZERO,
+                    achados: 0,
+                    saida: pronto.saida,
+                },
+            ));
+            continue;
+        }
+        pronto.criar_pastas();
+
+        // Uma barra por alvo, alimentada pelo stream da ferramenta
+        let barra = multi.insert_before(&geral, ProgressBar::new(0));
+        progresso::estilizar(&barra, &alvo);
+
+        let geral = geral.clone();
+        let ferramenta = ferramenta.to_string();
+        tarefas.spawn(async move {
+            let _vaga = vaga; // Solta a vaga quando a tarefa acabar
+            let inicio = Instant::now();
+
+            // Achados ficam calados aqui (vinte alvos gritando = caos), a saída vai pro arquivo.
+            let (desfecho, achados) = match progresso::acompanhar(&mut pronto.cmd, &ferramenta, &barra, false, &limites).await {
+                Ok(fim) if fim.status.sucesso() => (Desfecho::Sucesso, fim.achados),
+                Ok(fim) => (
+                    Desfecho::Falhou(format!("{} {}", fim.status, fim.ultima_linha.unwrap_or_default())),
+                    fim.achados,
+                ),
+                Err(e) => (Desfecho::NemRodou(e.to_string()), 0),
+            };
+            pronto.mascarar_saida();
+
+            let marca = match desfecho {
+                Desfecho::Sucesso => msg!("fila.ok"),
+                _ => msg!("fila.falhou"),
+            };
+            barra.finish_with_message(marca);
+            geral.inc(1);
+
+            (
+                indice,
+                ResultadoAlvo {
+                    alvo,
+                    desfecho,
+                    duracao: inicio.elapsed(),
+                    achados,
+                    saida: pronto.saida,
+                },
+            )
+        });
+    }
+
+    while let Some(res) = tarefas.join_next().await {
+        match res {
+            Ok(par) => resultados.push(par),
+            Err(e) => eprintln!("{}", msg!("fila.capotou", e)),
+        }
+    }
+    geral.finish_with_message(msg!("fila.concluida"));
+
+    // Devolve na MESMA ordem em que os alvos chegaram
+    resultados.sort_by_key(|(indice, _)| *indice);
+    Ok(resultados.into_iter().map(|(_, r)| r).collect())
+}
+
+// O resumo final, alvo por alvo
+pub fn imprimir_resumo(resultados: &[ResultadoAlvo]) {
+    let ok = resultados.iter().filter(|r| matches!(r.desfecho, Desfecho::Sucesso)).count();
+
+    println!("{}", msg!("fila.resumo", ok, resultados.len()));
+    for r in resultados {
+        let (marca, detalhe) = match &r.desfecho {
+            Desfecho::Sucesso => (msg!("fila.ok"), r.saida.clone().map(|s| msg!("fila.saida_em", s)).unwrap_or_default()),
+            Desfecho::Falhou(motivo) => (msg!("fila.falhou"), motivo.clone()),
+            Desfecho::NemRodou(motivo) => (msg!("fila.nem_rodou"), motivo.clone()),
+            Desfecho::Simulado => ("DRY-RUN".to_string(), msg!("fila.nada_executado")),
+        };
+        println!(
+            "{}",
+            msg!(
+                "fila.linha",
+                format!("{:^9}", marca),
+                r.alvo,
+                format!("{:.1}", r.duracao.as_secs_f64()),
+                r.achados,
+                detalhe
+            )
+        );
+    }
+    println!("{}", msg!("geral.separador"));
+}
+
+// Entrada pela linha de comando:
+// fenrir fila <ferramenta> <alvo>... [--alvos arquivo.txt] [--paralelo N]
+//             [--intervalo SEGUNDOS] [--flag FLAG]... [--arg chave=valor]...
+pub async fn rodar_pela_cli(args: &[String], dry_run: bool, politica: &Politica, escopo: &Escopo) {
+    let Some((ferramenta, resto)) = args.split_first() else {
+        eprintln!("{}", msg!("fila.uso"));
+        return;
+    };
+
+    let mut config = ConfigFila {
+        dry_run,
+        limites: TabelaLimites::carregar().para(ferramenta, Some(ferramenta)),
+        ..Default::default()
+    };
+    let mut alvos: Vec<String> = Vec::new();
+    let mut args_base = Map::new();
+    let mut flags: Vec<Value> = Vec::new();
+
+    let mut iter = resto.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--paralelo" => match iter.next().and_then(|v| v.parse::<usize>().ok()) {
+                Some(n) if n > 0 => config.max_paralelo = n,
+                _ => {
+                    eprintln!("{}", msg!("fila.paralelo"));
+                    return;
+                }
+            },
+            "--intervalo" => match iter.next().and_then(|v| v.parse::<f64>().ok()) {
+                Some(seg) if seg >= 0.0 => config.intervalo = Duration::from_secs_f64(seg),
+                _ => {
+                    eprintln!("{}", msg!("fila.intervalo"));
+                    return;
+                }
+            },
+            "--alvos" => match iter.next() {
+                Some(arquivo) => match fs::read_to_string(arquivo) {
+                    Ok(conteudo) => alvos.extend(
+                        conteudo
+                            .lines()
+                            .map(str::trim)
+                            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+                            .map(String::from),
+                    ),
+                    Err(e) => {
+                        eprintln!("{}", msg!("geral.nao_ler", arquivo, e));
+                        return;
+                    }
+                },
+                None => {
+                    eprintln!("{}", msg!("fila.alvos"));
+                    return;
+                }
+            },
+            "--flag" => match iter.next() {
+                Some(flag) => flags.push(Value::String(flag.clone())),
+                None => {
+                    eprintln!("{}", msg!("fila.flag"));
+                    return;
+                }
+            },
+            "--arg" => match iter.next().and_then(|kv| kv.split_once('=')) {
+                Some((chave, valor)) => {
+                    args_base.insert(chave.to_string(), Value::String(valor.to_string()));
+                }
+                None => {
+                    eprintln!("{}", msg!("fila.arg"));
+                    return;
+                }
+            },
+            alvo => alvos.push(alvo.to_string()),
+        }
+    }
+
+    if alvos.is_empty() {
+        eprintln!("{}", msg!("fila.sem_alvos"));
+        return;
+    }
+    if !flags.is_empty() {
+        args_base.insert("flags".to_string(), Value::Array(flags));
+    }
+
+    println!("{}", msg!("fila.inicio", ferramenta, alvos.len(), config.max_paralelo));
+
+    match rodar_fila(ferramenta, alvos, Some(Value::Object(args_base)), &config, politica, escopo).await {
+        Ok(resultados) => imprimir_resumo(&resultados),
+        Err(e) => eprintln!("{}", msg!("geral.erro", e)),
+    }
+}
+
+// --- FUNÇÕES PRIVADAS ---
+
+// O funil de UM alvo: escopo, política e Freio de Mão (igualzinho ao
+// 'executar_tarefa'). Passou? Devolve os limites dele (com os ajustes do "s ...").
+// No dry-run nada roda, então nada pergunta: só mostra o que a política decidiria.
+async fn liberar(
+    ferramenta: &str,
+    args: &Map<String, Value>,
+    config: &ConfigFila,
+    politica: &Politica,
+    escopo: &Escopo,
+) -> Result<Limites, String> {
+    let task = FenrirTask {
+        task_type: ferramenta.to_string(),
+        ia_explanation: msg!("fila.explicacao", ferramenta),
+        task_args: Some(Value::Object(args.clone())),
+        ..Default::default()
+    };
+
+    let fora = escopo.fora(&task);
+    if !fora.is_empty() {
+        println!("{}", msg!("main.fora_escopo", fora.join(", ")));
+    }
+    if !config.dry_run && !executor::passar_pelo_escopo(&task, &fora).await {
+        return Err(msg!("fila.fora_escopo"));
+    }
+
+    if config.dry_run {
+        let decisao = politica.avaliar(&politica::pedido_da_tarefa(&task));
+        println!("{}", msg!("fila.dry_run_politica", decisao.acao.rotulo(), decisao.origem, decisao.motivo));
+        if decisao.acao == Acao::Deny {
+            return Err(msg!("fila.recusado"));
+        }
+        return Ok(config.limites);
+    }
+
+    let risco = risco::analisar(&task, Path::new(".")).nivel;
+    let ajustes = executor::passar_pela_politica(&task, politica, risco)
+        .await
+        .ok_or_else(|| msg!("fila.recusado"))?;
+
+    let mut limites = config.limites;
+    if !ajustes.is_empty() {
+        limites.ajustar(&ajustes)?;
+    }
+    Ok(limites)
+}
+
+fn nem_rodou(alvo: String, motivo: String) -> ResultadoAlvo {
+    ResultadoAlvo { alvo, desfecho: Desfecho::NemRodou(motivo), duracao: Duration::ZERO, achados: 0, saida: None }
+}
@@ -0,0 +1,238 @@
+// --- MÓDULO LEITOR (A LINHA DO REPL) ---
+// O 'read_line' cru não sabia nem andar com a setinha: cuspia '^[[A'.
+// Agora o prompt do modo interativo é um editor de linha de verdade:
+// histórico que sobrevive entre sessões ('.fenrir/historico', com os
+// segredos já mascarados), Ctrl-R pra buscar nele, Tab completando comando
+// do REPL, ferramenta, alvo conhecido e caminho (a partir da pasta da
+// sessão), e várias linhas (termina com '\' ou deixa uma aspa aberta).
+// E o que você digita chega do jeitinho que digitou: nada de minúscula na marra.
+
+use crate::msg;
+use crate::segredos;
+use regex::Regex;
+use rustyline::completion::{Completer, Pair};
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::{ValidationContext, ValidationResult, Validator};
+use rustyline::{Config, Context, Editor, Helper};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// --- CONSTANTES (só do Leitor) ---
+const ARQUIVO_HISTORICO: &str = ".fenrir/historico";
+const MAX_HISTORICO: usize = 5_000;
+const FERRAMENTAS: &[&str] = &["nmap", "gobuster", "sqlmap"];
+
+// --- CONTRATO ---
+
+// O editor de linha já com o ajudante (completar + várias linhas)
+pub struct Leitor {
+    editor: Editor<Ajudante, DefaultHistory>,
+}
+
+// O que o Tab sabe completar
+struct Ajudante {
+    comandos: Vec<String>,   // Os do REPL (sair, :undo...)
+    alvos: BTreeSet<String>, // IPs, hosts e URLs que já apareceram
+    cwd: PathBuf,            // Caminho completa a partir da pasta da sessão
+}
+
+// --- FUNÇÕES PÚBLICAS ---
+
+impl Leitor {
+    // 'comandos' = o que o REPL entende sem o Oráculo (pro Tab)
+    pub fn novo(comandos: Vec<String>) -> Result<Leitor, String> {
+        let config = Config::builder().max_history_size(MAX_HISTORICO).map_err(|e| e.to_string())?.auto_add_history(false).build();
+        let mut editor = Editor::with_config(config).map_err(|e| msg!("leitor.sem_editor", e))?;
+        let _ = editor.load_history(ARQUIVO_HISTORICO); // Primeira vez não tem, normal
+
+        let mut alvos = BTreeSet::new();
+        for linha in editor.history().iter() {
+            alvos.extend(extrair_alvos(linha));
+        }
+        editor.set_helper(Some(Ajudante { comandos, alvos, cwd: PathBuf::from(".") }));
+        Ok(Leitor { editor })
+    }
+
+    // Lê uma entrada (pode ter várias linhas). None = acabou (Ctrl-D).
+    // Ctrl-C no prompt só limpa a linha (volta Some("")).
+    pub fn ler(&mut self, prompt: &str, cwd: &Path) -> Result<Option<String>, String> {
+        if let Some(ajudante) = self.editor.helper_mut() {
+            ajudante.cwd = cwd.to_path_buf();
+        }
+        match self.editor.readline(prompt) {
+            Ok(linha) => {
+                let linha = juntar_linhas(&linha);
+                if !linha.trim().is_empty() {
+                    self.guardar(&linha);
+                }
+                Ok(Some(linha))
+            }
+            Err(ReadlineError::Interrupted) => Ok(Some(String::new())),
+            Err(ReadlineError::Eof) => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    // As entradas do histórico (a mais velha primeiro)
+    pub fn historico(&self) -> Vec<String> {
+        self.editor.history().iter().cloned().collect()
+    }
+
+    // Entrada vai pro histórico (e pro arquivo) com os segredos mascarados.
+    // O marcador volta revelado do Oráculo, então reusar com a setinha funciona.
+    fn guardar(&mut self, linha: &str) {
+        let mascarada = segredos::mascarar(linha);
+        if let Some(ajudante) = self.editor.helper_mut() {
+            ajudante.alvos.extend(extrair_alvos(linha));
+        }
+        let _ = self.editor.add_history_entry(mascarada);
+        if let Some(pasta) = Path::new(ARQUIVO_HISTORICO).parent() {
+            let _ = fs::create_dir_all(pasta);
+        }
+        if let Err(e) = self.editor.save_history(ARQUIVO_HISTORICO) {
+            eprintln!("{}", msg!("leitor.historico", e));
+        }
+    }
+}
+
+// --- FUNÇÕES PRIVADAS ---
+
+impl Completer for Ajudante {
+    type Candidate = Pair;
+
+    fn complete(&self, linha: &str, pos: usize, _ctx: &Context<'_>) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let antes = &linha[..pos];
+        let inicio = antes.rfind(char::is_whitespace).map(|i| i + 1).unwrap_or(0);
+        let palavra = &antes[inicio..];
+        let primeira = antes[..inicio].trim().is_empty();
+
+        let mut candidatos: Vec<Pair> = Vec::new();
+        let mut oferecer = |texto: &str| {
+            if texto.starts_with(palavra) && !candidatos.iter().any(|c| c.replacement == texto) {
+                candidatos.push(Pair { display: texto.to_string(), replacement: texto.to_string() });
+            }
+        };
+
+        // Comando do REPL só faz sentido no começo
+        if primeira {
+            for comando in &self.comandos {
+                oferecer(comando);
+            }
+        }
+        if !palavra.starts_with(':') {
+            for ferramenta in FERRAMENTAS {
+                oferecer(ferramenta);
+            }
+            for alvo in &self.alvos {
+                oferecer(alvo);
+            }
+            for caminho in completar_caminho(&self.cwd, palavra) {
+                oferecer(&caminho);
+            }
+        }
+        Ok((inicio, candidatos))
+    }
+}
+
+impl Hinter for Ajudante {
+    type Hint = String;
+}
+
+impl Highlighter for Ajudante {}
+
+// Termina com '\' ou tem aspa aberta? Então ainda não acabou: Enter quebra a linha.
+impl Validator for Ajudante {
+    fn validate(&self, ctx: &mut ValidationContext) -> rustyline::Result<ValidationResult> {
+        let entrada = ctx.input();
+        if entrada.trim_end().ends_with('\\') || aspa_aberta(entrada) {
+            return Ok(ValidationResult::Incomplete);
+        }
+        Ok(ValidationResult::Valid(None))
+    }
+}
+
+impl Helper for Ajudante {}
+
+// "nmap \<Enter> -sV alvo" vira uma linha só (dentro de aspas, a quebra fica)
+fn juntar_linhas(entrada: &str) -> String {
+    entrada.replace("\\\r\n", " ").replace("\\\n", " ").trim().to_string()
+}
+
+// Apóstrofo no meio da palavra ("what's", "d'água") é texto, não abre aspa:
+// senão a pergunta em linguagem natural nunca deixava o Enter mandar.
+fn aspa_aberta(texto: &str) -> bool {
+    let mut aberta: Option<char> = None;
+    let mut escapado = false;
+    let mut anterior: Option<char> = None;
+    for c in texto.chars() {
+        match (aberta, c) {
+            _ if escapado => escapado = false,
+            (_, '\\') => escapado = true,
+            (None, '\'') if anterior.is_some_and(char::is_alphanumeric) => {}
+            (None, '"') | (None, '\'') => aberta = Some(c),
+            (Some(a), c) if a == c => aberta = None,
+            _ => {}
+        }
+        anterior = Some(c);
+    }
+    aberta.is_some()
+}
+
+// Caminhos que começam com 'palavra', a partir da pasta da sessão ('~' = $HOME)
+fn completar_caminho(cwd: &Path, palavra: &str) -> Vec<String> {
+    let (pasta, prefixo) = match palavra.rfind('/') {
+        Some(i) => (&palavra[..=i], &palavra[i + 1..]),
+        None => ("", palavra),
+    };
+    let base = match (pasta.strip_prefix('~'), std::env::var_os("HOME")) {
+        (Some(resto), Some(home)) => PathBuf::from(home).join(resto.trim_start_matches('/')),
+        _ if pasta.is_empty() => cwd.to_path_buf(),
+        _ => cwd.join(pasta),
+    };
+    let Ok(entradas) = fs::read_dir(&base) else {
+        return Vec::new();
+    };
+
+    let mut achados: Vec<String> = entradas
+        .filter_map(Result::ok)
+        .filter_map(|e| {
+            let nome = e.file_name().to_string_lossy().to_string();
+            // Arquivo escondido só se você começou com '.'
+            if !nome.starts_with(prefixo) || (nome.starts_with('.') && !prefixo.starts_with('.')) {
+                return None;
+            }
+            let barra = if e.path().is_dir() { "/" } else { "" };
+            Some(format!("{}{}{}", pasta, nome, barra))
+        })
+        .collect();
+    achados.sort();
+    achados
+}
+
+// IPs (com ou sem CIDR), URLs e hosts com cara de domínio
+fn extrair_alvos(texto: &str) -> Vec<String> {
+    static ALVO: std::sync::OnceLock<Regex> = std::sync::OnceLock::new();
+    let regex = ALVO.get_or_init(|| {
+        Regex::new(r"https?://[^\s'\x22]+|\b\d{1,3}(?:\.\d{1,3}){3}(?:/\d{1,2})?\b|\b[a-zA-Z0-9-]+(?:\.[a-zA-Z0-9-]+)*\.(?:com|net|org|br|io|local|lan|htb|thm|corp|internal)\b")
+            .expect("regex dos alvos")
+    });
+    regex.find_iter(texto).map(|m| m.as_str().to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apostrofo_na_palavra_nao_abre_aspa() {
+        assert!(!aspa_aberta("what's on port 80"));
+        assert!(!aspa_aberta("copo d'água e o 'nmap -sV' rodando"));
+        assert!(aspa_aberta("echo 'ainda não fechou"));
+        assert!(aspa_aberta("grep \"a b"));
+        assert!(!aspa_aberta("sh -c 'it''s' e \\'"));
+    }
+}
@@ -0,0 +1,581 @@
+// --- MÓDULO LIMITES ---
+// Um 'gobuster' com wordlist gigante ou um 'sqlmap' travado rodava pra sempre.
+// Agora cada tarefa (e cada ferramenta) pode ter teto de tempo, CPU, memória
+// e saída. Cada processo nasce no SEU grupo de processos: estourou, o grupo
+// inteiro (filhos da ferramenta inclusos) vai pro saco, e o log registra
+// "killed: timeout", "killed: memory"...
+//
+// Exemplo ('.fenrir/limites.toml' ou '~/.config/fenrir/limites.toml'):
+//
+//   [padrao]
+//   saida_mb = 500
+//
+//   [tarefa.execute_command]
+//   timeout_segundos = 1800
+//
+//   [ferramenta.gobuster]
+//   timeout_segundos = 3600
+//   memoria_mb = 2048
+
+use crate::eventos::{self, Desfecho};
+use crate::executor;
+use crate::msg;
+use crate::parada;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitStatus;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tokio::process::{Child, Command};
+
+// --- CONSTANTES (só dos Limites) ---
+const ARQUIVO_ENGAGEMENT: &str = ".fenrir/limites.toml";
+const ARQUIVO_USUARIO: &str = ".config/fenrir/limites.toml"; // (relativo ao $HOME)
+const INTERVALO_VIGIA: Duration = Duration::from_millis(500);
+const CARENCIA_SIGTERM: Duration = Duration::from_secs(3); // Tempo pra salvar antes do SIGKILL
+
+// Sem arquivo nenhum, as ferramentas que mais travam já vêm com teto
+const EMBUTIDOS: &[(&str, Limites)] = &[
+    ("gobuster", Limites { timeout_segundos: Some(3600), cpu_segundos: None, memoria_mb: None, saida_mb: None }),
+    ("sqlmap", Limites { timeout_segundos: Some(3600), cpu_segundos: None, memoria_mb: None, saida_mb: None }),
+];
+
+// --- CONTRATO ---
+
+// 'None' = sem teto
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+pub struct Limites {
+    pub timeout_segundos: Option<u64>,
+    pub cpu_segundos: Option<u64>,
+    pub memoria_mb: Option<u64>,
+    pub saida_mb: Option<u64>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+struct ArquivoLimites {
+    #[serde(default)]
+    padrao: Limites,
+    #[serde(default)]
+    tarefa: HashMap<String, Limites>,
+    #[serde(default)]
+    ferramenta: HashMap<String, Limites>,
+}
+
+// Os limites carregados, por camada
+#[derive(Debug, Default)]
+pub struct TabelaLimites {
+    padrao: Limites,
+    tarefa: HashMap<String, Limites>,
+    ferramenta: HashMap<String, Limites>,
+}
+
+// Por que o processo foi pro saco
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Motivo {
+    Timeout,
+    Memoria,
+    Cpu,
+    Saida,
+    Parada,     // Parada de emergência ('fenrir stop', Ctrl-C duplo)
+    Interrupcao, // Ctrl-C repassado pro comando
+}
+
+impl fmt::Display for Motivo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let texto = match self {
+            Motivo::Timeout => "killed: timeout",
+            Motivo::Memoria => "killed: memory",
+            Motivo::Cpu => "killed: cpu",
+            Motivo::Saida => "killed: output",
+            Motivo::Parada => "killed: stop",
+            Motivo::Interrupcao => "killed: interrupt",
+        };
+        write!(f, "{}", texto)
+    }
+}
+
+// Como o processo terminou
+#[derive(Debug)]
+pub enum Fim {
+    Terminou(ExitStatus),
+    Morto(Motivo),
+}
+
+impl Fim {
+    pub fn sucesso(&self) -> bool {
+        matches!(self, Fim::Terminou(status) if status.success())
+    }
+}
+
+impl fmt::Display for Fim {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fim::Terminou(status) => write!(f, "{}", status),
+            Fim::Morto(motivo) => write!(f, "{}", motivo),
+        }
+    }
+}
+
+// --- CARREGAMENTO ---
+
+impl TabelaLimites {
+    // Embutidos, depois o arquivo do usuário, depois o do engagement
+    // (cada camada só sobrescreve o que ela define)
+    pub fn carregar() -> TabelaLimites {
+        let mut tabela = TabelaLimites::default();
+        for (ferramenta, limites) in EMBUTIDOS {
+            tabela.ferramenta.insert(ferramenta.to_string(), *limites);
+        }
+
+        for caminho in arquivos_de_limites() {
+            let Ok(conteudo) = fs::read_to_string(&caminho) else {
+                continue;
+            };
+            match toml::from_str::<ArquivoLimites>(&conteudo) {
+                Ok(arquivo) => tabela.somar(arquivo),
+                Err(e) => eprintln!("{}", msg!("limites.zoados", caminho.display(), e)),
+            }
+        }
+        tabela
+    }
+
+    fn somar(&mut self, arquivo: ArquivoLimites) {
+        self.padrao = self.padrao.por_cima(&arquivo.padrao);
+        for (nome, limites) in arquivo.tarefa {
+            let atual = self.tarefa.entry(nome).or_default();
+            *atual = atual.por_cima(&limites);
+        }
+        for (nome, limites) in arquivo.ferramenta {
+            let atual = self.ferramenta.entry(nome).or_default();
+            *atual = atual.por_cima(&limites);
+        }
+    }
+
+    // Padrão < tipo de tarefa < ferramenta (o binário, ou a TAREFA 'nmap' etc.)
+    pub fn para(&self, task_type: &str, ferramenta: Option<&str>) -> Limites {
+        let mut limites = self.padrao;
+        if let Some(l) = self.tarefa.get(task_type) {
+            limites = limites.por_cima(l);
+        }
+        if let Some(l) = ferramenta.and_then(|f| self.ferramenta.get(f)) {
+            limites = limites.por_cima(l);
+        }
+        limites
+    }
+}
+
+fn arquivos_de_limites() -> Vec<PathBuf> {
+    let mut arquivos = Vec::new();
+    if let Some(home) = env::var_os("HOME") {
+        arquivos.push(PathBuf::from(home).join(ARQUIVO_USUARIO));
+    }
+    arquivos.push(PathBuf::from(ARQUIVO_ENGAGEMENT));
+    arquivos
+}
+
+impl Limites {
+    // 'outro' ganha onde ele define alguma coisa
+    fn por_cima(&self, outro: &Limites) -> Limites {
+        Limites {
+            timeout_segundos: outro.timeout_segundos.or(self.timeout_segundos),
+            cpu_segundos: outro.cpu_segundos.or(self.cpu_segundos),
+            memoria_mb: outro.memoria_mb.or(self.memoria_mb),
+            saida_mb: outro.saida_mb.or(self.saida_mb),
+        }
+    }
+
+    pub fn vazio(&self) -> bool {
+        *self == Limites::default()
+    }
+
+    // Ajustes digitados na confirmação: "timeout=600 memoria=2048 cpu=off"
+    // (Tudo ou nada: um par errado e nenhum dos outros entra)
+    pub fn ajustar(&mut self, texto: &str) -> Result<(), String> {
+        let mut novo = *self;
+        for par in texto.split_whitespace() {
+            let Some((chave, valor)) = par.split_once('=') else {
+                return Err(msg!("limites.sem_igual", par));
+            };
+            let valor = match valor {
+                "off" | "0" | "-" => None,
+                v => Some(v.parse::<u64>().map_err(|_| msg!("limites.nao_numero", v, par))?),
+            };
+            match chave {
+                "timeout" => novo.timeout_segundos = valor,
+                "cpu" => novo.cpu_segundos = valor,
+                "memoria" | "mem" => novo.memoria_mb = valor,
+                "saida" => novo.saida_mb = valor,
+                outra => return Err(msg!("limites.nao_existe", outra)),
+            }
+        }
+        *self = novo;
+        Ok(())
+    }
+
+    pub fn resumo(&self) -> String {
+        let mostrar = |v: Option<u64>, unidade: &str| v.map(|n| format!("{}{}", n, unidade)).unwrap_or_else(|| "-".to_string());
+        msg!(
+            "limites.resumo",
+            mostrar(self.timeout_segundos, "s"),
+            mostrar(self.cpu_segundos, "s"),
+            mostrar(self.memoria_mb, " MB"),
+            mostrar(self.saida_mb, " MB")
+        )
+    }
+}
+
+// --- APLICANDO ---
+
+// Grupo de processos próprio + rlimits (CPU e tamanho de arquivo).
+// Memória NÃO vai por rlimit: RLIMIT_AS quebra programa em Go (o gobuster).
+// Ela é vigiada pelo '/proc' no 'vigiar'.
+pub fn preparar(cmd: &mut Command, limites: &Limites) {
+    #[cfg(unix)]
+    {
+        let cpu = limites.cpu_segundos;
+        let arquivo = limites.saida_mb.map(|mb| mb * 1024 * 1024);
+        // SAFETY: entre o fork e o exec só chamamos setpgid/setrlimit (async-signal-safe)
+        unsafe {
+            cmd.pre_exec(move || {
+                libc::setpgid(0, 0);
+                if let Some(seg) = cpu {
+                    let lim = libc::rlimit { rlim_cur: seg as libc::rlim_t, rlim_max: (seg + 5) as libc::rlim_t };
+                    libc::setrlimit(libc::RLIMIT_CPU, &lim);
+                }
+                if let Some(bytes) = arquivo {
+                    let lim = libc::rlimit { rlim_cur: bytes as libc::rlim_t, rlim_max: bytes as libc::rlim_t };
+                    libc::setrlimit(libc::RLIMIT_FSIZE, &lim);
+                }
+                Ok(())
+            });
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = (cmd, limites);
+}
+
+// Espera o filho, de olho no relógio, na memória do grupo e na saída
+// ('saida_lida' = bytes que passaram pelos pipes, quando tem pipe).
+// Estourou? Mata o grupo inteiro. O desfecho vai pro log com o 'rotulo'.
+// Enquanto roda, o grupo fica anotado pra parada de emergência,
+// e o Ctrl-C do operador é repassado pro grupo (SIGINT).
+pub async fn vigiar(filho: &mut Child, limites: &Limites, rotulo: &str, saida_lida: Option<&AtomicU64>) -> std::io::Result<Fim> {
+    let inicio = Instant::now();
+    let pid = filho.id();
+    let rastreio = pid.map(|p| parada::rastrear(p, rotulo));
+    let mut cancelado = Box::pin(parada::passo_cancelado());
+    let mut interrompido = false;
+
+    let fim = loop {
+        tokio::select! {
+            status = filho.wait() => {
+                let status = status?;
+                if rastreio.as_ref().is_some_and(|r| r.foi_parado()) && !status.success() {
+                    break Fim::Morto(Motivo::Parada);
+                }
+                if interrompido && !status.success() {
+                    break Fim::Morto(Motivo::Interrupcao);
+                }
+                break classificar(status);
+            }
+            _ = &mut cancelado => {
+                eprintln!("{}", msg!("limites.ctrl_c", rotulo));
+                if let Some(p) = pid {
+                    sinalizar_grupo(p, Sinal::Interromper);
+                }
+                interrompido = true;
+                cancelado.set(parada::passo_cancelado());
+            }
+            _ = tokio::time::sleep(INTERVALO_VIGIA) => {
+                let estourou = if limites.timeout_segundos.is_some_and(|t| inicio.elapsed() >= Duration::from_secs(t)) {
+                    Some(Motivo::Timeout)
+                } else if limites.memoria_mb.is_some_and(|mb| pid.is_some_and(|p| memoria_do_grupo(p) > mb * 1024 * 1024)) {
+                    Some(Motivo::Memoria)
+                } else if limites.saida_mb.is_some_and(|mb| saida_lida.is_some_and(|s| s.load(Ordering::Relaxed) > mb * 1024 * 1024)) {
+                    Some(Motivo::Saida)
+                } else {
+                    None
+                };
+
+                if let Some(motivo) = estourou {
+                    eprintln!("{}", msg!("limites.estourou", rotulo, motivo));
+                    if let Some(p) = pid {
+                        matar_grupo(p, filho).await;
+                    }
+                    let _ = filho.wait().await;
+                    break Fim::Morto(motivo);
+                }
+            }
+        }
+    };
+
+    registrar(rotulo, &fim, fim.sucesso(), limites, inicio.elapsed());
+    Ok(fim)
+}
+
+// O argv em uma linha (pro log saber QUEM foi)
+pub fn rotulo(cmd: &Command) -> String {
+    let std_cmd = cmd.as_std();
+    std::iter::once(std_cmd.get_program())
+        .chain(std_cmd.get_args())
+        .map(|a| a.to_string_lossy().to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Desfecho no log (é aqui que aparece o "killed: timeout")
+// Não deu certo? Conta pro código de saída.
+pub fn registrar(rotulo: &str, fim: &impl fmt::Display, sucesso: bool, limites: &Limites, duracao: Duration) {
+    if !sucesso {
+        eventos::marcar(Desfecho::Falha);
+    }
+    let evento = json!({
+        "rotulo": rotulo,
+        "desfecho": fim.to_string(),
+        "sucesso": sucesso,
+        "duracao_segundos": duracao.as_secs(),
+        "limites": limites,
+    });
+    if let Err(e) = executor::log_evento("DESFECHO", &evento) {
+        eprintln!("{}", msg!("limites.erro_log", e));
+    }
+}
+
+// Soma o RSS de todos os processos do grupo (Linux: '/proc/<pid>/stat')
+pub fn memoria_do_grupo(pgid: u32) -> u64 {
+    processos_do_grupo(pgid).iter().map(|(_, rss)| rss).sum()
+}
+
+// (pid, RSS em bytes) de cada processo do grupo
+pub fn processos_do_grupo(pgid: u32) -> Vec<(u32, u64)> {
+    let pagina = tamanho_pagina();
+    todos_os_processos()
+        .filter_map(|pid| {
+            let campos = campos_do_stat(pid)?;
+            let grupo: u32 = campos.get(2)?.parse().ok()?;
+            let rss: u64 = campos.get(21)?.parse().ok()?;
+            (grupo == pgid).then_some((pid, rss * pagina))
+        })
+        .collect()
+}
+
+// O grupo ainda é de quem anotou? O 'dono' tem que estar vivo e algum processo
+// do grupo ser filho dele ou estar na mesma sessão (o PTY do terminal abre
+// sessão nova, aí vale o filho). Anotação velha de Fenrir que caiu + PID/PGID
+// reciclado = grupo de OUTRO, e esse a parada não pode matar.
+pub fn grupo_do_dono(pgid: u32, dono: u32) -> bool {
+    let Some(sessao_do_dono) = campos_do_stat(dono).and_then(|c| c.get(3).cloned()) else {
+        return false; // Dono morreu
+    };
+    todos_os_processos().any(|pid| {
+        campos_do_stat(pid).is_some_and(|c| {
+            c.get(2).is_some_and(|g| g.parse() == Ok(pgid))
+                && (c.get(1).is_some_and(|p| p.parse() == Ok(dono)) || c.get(3) == Some(&sessao_do_dono))
+        })
+    })
+}
+
+// Ainda tem alguém vivo no grupo? (sinal 0 = só pergunta)
+pub fn grupo_vivo(pgid: u32) -> bool {
+    #[cfg(unix)]
+    {
+        // SAFETY: kill() com sinal 0 não mata nada
+        unsafe { libc::kill(-(pgid as i32), 0) == 0 }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = pgid;
+        false
+    }
+}
+
+// SIGTERM no grupo, uma carência, e SIGKILL em quem sobrar
+pub async fn matar_grupo(pgid: u32, filho: &mut Child) {
+    if cfg!(unix) {
+        sinalizar_grupo(pgid, Sinal::Terminar);
+        let _ = tokio::time::timeout(CARENCIA_SIGTERM, filho.wait()).await;
+        sinalizar_grupo(pgid, Sinal::Matar);
+    } else {
+        let _ = filho.kill().await;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Sinal {
+    Interromper, // SIGINT (o Ctrl-C)
+    Terminar,    // SIGTERM
+    Matar,    // SIGKILL
+}
+
+// Manda o sinal pro grupo INTEIRO (a ferramenta e os filhos dela)
+pub fn sinalizar_grupo(pgid: u32, sinal: Sinal) {
+    #[cfg(unix)]
+    {
+        let numero = match sinal {
+            Sinal::Interromper => libc::SIGINT,
+            Sinal::Terminar => libc::SIGTERM,
+            Sinal::Matar => libc::SIGKILL,
+        };
+        // SAFETY: kill() com pid negativo = o grupo inteiro
+        unsafe {
+            libc::kill(-(pgid as i32), numero);
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = (pgid, sinal);
+}
+
+// --- TERMINAL EM PRIMEIRO PLANO ---
+// O comando tá no grupo dele, mas herdou o terminal: sem ser o grupo da
+// frente, um 'sudo' pedindo senha leva SIGTTIN e congela. Então a gente
+// passa o terminal pro grupo do comando e pega de volta no 'Drop'.
+
+pub struct PrimeiroPlano {
+    #[cfg(unix)]
+    anterior: Option<libc::pid_t>,
+}
+
+pub fn primeiro_plano(pgid: u32) -> PrimeiroPlano {
+    #[cfg(unix)]
+    {
+        // SAFETY: só chamadas de controle de job no fd 0; SIGTTOU ignorado
+        // durante o tcsetpgrp (senão quem tá atrás leva stop)
+        unsafe {
+            if libc::isatty(libc::STDIN_FILENO) == 0 {
+                return PrimeiroPlano { anterior: None };
+            }
+            let anterior = libc::tcgetpgrp(libc::STDIN_FILENO);
+            libc::setpgid(pgid as libc::pid_t, pgid as libc::pid_t); // (corrida com o pre_exec)
+            let velho = libc::signal(libc::SIGTTOU, libc::SIG_IGN);
+            libc::tcsetpgrp(libc::STDIN_FILENO, pgid as libc::pid_t);
+            libc::signal(libc::SIGTTOU, velho);
+            libc::kill(-(pgid as i32), libc::SIGCONT); // Se já tinha congelado, acorda
+            PrimeiroPlano { anterior: Some(anterior) }
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = pgid;
+        PrimeiroPlano {}
+    }
+}
+
+impl Drop for PrimeiroPlano {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        if let Some(anterior) = self.anterior {
+            // SAFETY: devolve o terminal pro grupo do Fenrir
+            unsafe {
+                let velho = libc::signal(libc::SIGTTOU, libc::SIG_IGN);
+                libc::tcsetpgrp(libc::STDIN_FILENO, anterior);
+                libc::signal(libc::SIGTTOU, velho);
+            }
+        }
+    }
+}
+
+// --- FUNÇÕES PRIVADAS ---
+
+// CPU e arquivo estourados chegam como sinal (SIGXCPU / SIGXFSZ)
+fn classificar(status: ExitStatus) -> Fim {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        match status.signal() {
+            Some(libc::SIGXCPU) => return Fim::Morto(Motivo::Cpu),
+            Some(libc::SIGXFSZ) => return Fim::Morto(Motivo::Saida),
+            _ => {}
+        }
+    }
+    Fim::Terminou(status)
+}
+
+// Os PIDs em '/proc' (Linux; fora dele, nenhum)
+fn todos_os_processos() -> impl Iterator<Item = u32> {
+    fs::read_dir("/proc")
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter_map(|e| e.file_name().to_str()?.parse::<u32>().ok())
+}
+
+// Os campos do '/proc/<pid>/stat' depois do nome: [0] estado, [1] pai, [2] grupo, [3] sessão...
+// (O nome do processo pode ter espaço e parêntese: corta no ÚLTIMO ')')
+fn campos_do_stat(pid: u32) -> Option<Vec<String>> {
+    let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    Some(stat.rsplit_once(')')?.1.split_whitespace().map(String::from).collect())
+}
+
+fn tamanho_pagina() -> u64 {
+    #[cfg(unix)]
+    {
+        // SAFETY: sysconf só lê
+        let tamanho = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+        if tamanho > 0 {
+            return tamanho as u64;
+        }
+    }
+    4096
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ajustar_troca_e_desliga() {
+        let mut limites = Limites { timeout_segundos: Some(60), cpu_segundos: Some(10), ..Default::default() };
+        limites.ajustar("timeout=600 mem=2048 cpu=off saida=-").unwrap();
+        assert_eq!(limites.timeout_segundos, Some(600));
+        assert_eq!(limites.memoria_mb, Some(2048));
+        assert_eq!(limites.cpu_segundos, None);
+        assert_eq!(limites.saida_mb, None);
+
+        limites.ajustar("memoria=0").unwrap();
+        assert_eq!(limites.memoria_mb, None);
+        limites.ajustar("   ").unwrap();
+        assert_eq!(limites.timeout_segundos, Some(600));
+    }
+
+    #[test]
+    fn ajustar_recusa_lixo_sem_mexer_em_nada() {
+        let original = Limites { timeout_segundos: Some(60), ..Default::default() };
+        for ruim in ["timeout=600 cpu", "timeout=600 cpu=muito", "timeout=600 disco=10", "timeout=-5"] {
+            let mut limites = original;
+            assert!(limites.ajustar(ruim).is_err(), "{}", ruim);
+            assert_eq!(limites, original, "{}", ruim);
+        }
+    }
+
+    #[test]
+    fn por_cima_so_troca_o_que_foi_definido() {
+        let base = Limites { timeout_segundos: Some(60), cpu_segundos: Some(10), ..Default::default() };
+        let topo = Limites { timeout_segundos: Some(5), memoria_mb: Some(512), ..Default::default() };
+        let junto = base.por_cima(&topo);
+        assert_eq!(junto.timeout_segundos, Some(5));
+        assert_eq!(junto.cpu_segundos, Some(10));
+        assert_eq!(junto.memoria_mb, Some(512));
+        assert!(!junto.vazio());
+        assert!(Limites::default().vazio());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn grupo_so_vale_pro_dono_vivo() {
+        use std::os::unix::process::CommandExt;
+        let mut filho = std::process::Command::new("sleep").arg("5").process_group(0).spawn().unwrap();
+        let mut morto = std::process::Command::new("true").spawn().unwrap();
+        morto.wait().unwrap();
+
+        assert!(grupo_do_dono(filho.id(), std::process::id()));
+        assert!(!grupo_do_dono(filho.id(), morto.id()));
+        filho.kill().unwrap();
+        filho.wait().unwrap();
+        assert!(!grupo_do_dono(filho.id(), std::process::id()));
+    }
+}
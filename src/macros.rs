@@ -0,0 +1,417 @@
+// --- MÓDULO MACROS ---
+// Recon web é SEMPRE a mesma coisa: nmap no host, gobuster na URL, olhar os headers.
+// Macro = uma receita com nome e parâmetros que vira uma fila de tarefas,
+// e CADA uma passa pelo caminho normal (risco, escopo, política, s/n).
+//
+// Moram em '~/.config/fenrir/macros.toml' (suas) e '.fenrir/macros.toml'
+// (do engagement, ganha no mesmo nome):
+//
+//   [recon-web]
+//   descricao = "Recon web básico"
+//   parametros = ["url"]
+//   passos = [
+//     { tarefa = "nmap", args = { target = "{url:host}", flags = ["-sV"] } },
+//     { tarefa = "gobuster", args = { url = "{url}" } },
+//     { tarefa = "execute_command", comando = "curl -sI {url}" },
+//     { pedido = "procura subdomínios de {url:host}" },  # esse vai pro Oráculo
+//   ]
+//
+// '{nome}' é o parâmetro; '{nome:host}' só o host dele. No 'comando' (que vai
+// pro shell) o valor entra com aspas de shell. Macro de um passo só
+// com 'pedido' é um apelido. O ':save-macro' monta uma dessas com o que rodou.
+
+use crate::escopo;
+use crate::msg;
+use crate::oraculo::{self, FenrirTask};
+use crate::variaveis::Variaveis;
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+// --- CONSTANTES (só das Macros) ---
+const ARQUIVO_ENGAGEMENT: &str = ".fenrir/macros.toml";
+const ARQUIVO_USUARIO: &str = ".config/fenrir/macros.toml"; // (relativo ao $HOME)
+
+// --- CONTRATO ---
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Macro {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub descricao: Option<String>,
+    #[serde(default)]
+    pub parametros: Vec<String>,
+    pub passos: Vec<Passo>,
+}
+
+// Um passo: ou um pedido pro Oráculo, ou a tarefa já pronta (sem Oráculo)
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Passo {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pedido: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tarefa: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explicacao: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comando: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arquivo: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub args: Option<Value>,
+}
+
+// O que a macro vira na hora de rodar
+pub enum Etapa {
+    Pedido(String),
+    Tarefa(FenrirTask),
+}
+
+// Todas as macros carregadas, cada uma com o arquivo de onde veio
+#[derive(Debug, Default)]
+pub struct Macros {
+    macros: BTreeMap<String, (Macro, String)>,
+}
+
+// --- FUNÇÕES PÚBLICAS ---
+
+impl Macros {
+    // Do usuário, depois do engagement (mesmo nome: o engagement ganha)
+    pub fn carregar() -> Macros {
+        let mut todas = Macros::default();
+        for caminho in arquivos_de_macros() {
+            let Ok(conteudo) = fs::read_to_string(&caminho) else {
+                continue;
+            };
+            match toml::from_str::<BTreeMap<String, Macro>>(&conteudo) {
+                Ok(arquivo) => {
+                    let origem = caminho.display().to_string();
+                    todas.macros.extend(arquivo.into_iter().map(|(nome, m)| (nome, (m, origem.clone()))));
+                }
+                Err(e) => eprintln!("{}", msg!("macros.zoadas", caminho.display(), e)),
+            }
+        }
+        todas
+    }
+
+    pub fn pegar(&self, nome: &str) -> Option<&Macro> {
+        self.macros.get(nome).map(|(m, _)| m)
+    }
+
+    pub fn listar(&self) {
+        if self.macros.is_empty() {
+            println!("{}", msg!("macros.nenhuma", ARQUIVO_ENGAGEMENT));
+            return;
+        }
+        println!("{}", msg!("macros.titulo"));
+        for (nome, (m, origem)) in &self.macros {
+            let parametros: String = m.parametros.iter().map(|p| format!(" {{{}}}", p)).collect();
+            let descricao = m.descricao.clone().unwrap_or_else(|| msg!("macros.sem_descricao"));
+            println!("{}", msg!("macros.linha", nome, parametros, descricao, m.passos.len(), origem));
+        }
+    }
+
+    // ':save-macro': grava no arquivo do engagement (e já vale nessa sessão)
+    pub fn salvar(&mut self, nome: &str, nova: Macro) -> Result<(), String> {
+        let caminho = Path::new(ARQUIVO_ENGAGEMENT);
+        let mut arquivo: BTreeMap<String, Macro> = match fs::read_to_string(caminho) {
+            Ok(conteudo) => toml::from_str(&conteudo).map_err(|e| msg!("macros.arquivo_zoado", caminho.display(), e))?,
+            Err(_) => BTreeMap::new(),
+        };
+        arquivo.insert(nome.to_string(), nova.clone());
+
+        if let Some(pasta) = caminho.parent() {
+            fs::create_dir_all(pasta).map_err(|e| e.to_string())?;
+        }
+        let texto = toml::to_string(&arquivo).map_err(|e| e.to_string())?;
+        fs::write(caminho, texto).map_err(|e| msg!("geral.falha_gravar", caminho.display(), e))?;
+        self.macros.insert(nome.to_string(), (nova, caminho.display().to_string()));
+        Ok(())
+    }
+}
+
+impl Macro {
+    // Os 'args' vão nos parâmetros, na ordem. Faltou? Vale a variável da sessão
+    // com o mesmo nome ('$TARGET' vira o '{TARGET}' de quem não passou nada).
+    pub fn expandir(&self, nome: &str, args: &[String], variaveis: &Variaveis) -> Result<Vec<Etapa>, String> {
+        let mut valores = BTreeMap::new();
+        for (i, parametro) in self.parametros.iter().enumerate() {
+            let valor = args
+                .get(i)
+                .cloned()
+                .or_else(|| variaveis.valores().get(parametro).cloned())
+                .ok_or_else(|| msg!("macros.precisa", nome, self.parametros.join(" ")))?;
+            valores.insert(parametro.clone(), valor);
+        }
+        if args.len() > self.parametros.len() {
+            eprintln!("{}", msg!("macros.sobrou", nome, self.parametros.len()));
+        }
+
+        let trocar = |texto: &str| preencher(texto, &valores, false);
+        let mut etapas = Vec::new();
+        for (i, passo) in self.passos.iter().enumerate() {
+            if let Some(pedido) = &passo.pedido {
+                etapas.push(Etapa::Pedido(trocar(pedido)));
+                continue;
+            }
+            let Some(tarefa) = &passo.tarefa else {
+                return Err(msg!("macros.passo_vazio", i + 1, nome));
+            };
+            let mut args = passo.args.clone();
+            if let Some(a) = &mut args {
+                preencher_json(a, &valores);
+            }
+            etapas.push(Etapa::Tarefa(FenrirTask {
+                id: oraculo::novo_id(),
+                task_type: tarefa.clone(),
+                ia_explanation: passo
+                    .explicacao
+                    .as_deref()
+                    .map(trocar)
+                    .unwrap_or_else(|| msg!("macros.passo", nome, i + 1, self.passos.len())),
+                command_to_run: passo.comando.as_deref().map(|c| preencher(c, &valores, true)),
+                target_path: passo.arquivo.as_deref().map(trocar),
+                application: passo.app.clone(),
+                task_args: args,
+                analysis: None,
+            }));
+        }
+        Ok(etapas)
+    }
+
+    // Monta a macro com as tarefas que rodaram. Valor de variável da sessão
+    // que aparecer como token inteiro vira parâmetro ('10.10.11.5' => '{TARGET}').
+    pub fn das_tarefas(tarefas: &[FenrirTask], variaveis: &Variaveis) -> Macro {
+        // Valor maior primeiro (a URL antes do IP que tá dentro dela)
+        let mut trocas: Vec<(&String, &String)> =
+            variaveis.valores().iter().filter(|(_, v)| !v.is_empty()).map(|(n, v)| (v, n)).collect();
+        trocas.sort_by_key(|(valor, _)| std::cmp::Reverse(valor.len()));
+        let mut usados: Vec<String> = Vec::new();
+
+        let passos = tarefas
+            .iter()
+            .map(|t| Passo {
+                pedido: None,
+                tarefa: Some(t.task_type.clone()),
+                explicacao: Some(parametrizar(&t.ia_explanation, &trocas, false, &mut usados)),
+                comando: t.command_to_run.as_deref().map(|c| parametrizar(c, &trocas, true, &mut usados)),
+                arquivo: t.target_path.as_deref().map(|a| parametrizar(a, &trocas, false, &mut usados)),
+                app: t.application.clone(),
+                args: t
+                    .task_args
+                    .as_ref()
+                    .map(|a| parametrizar_json(a, &mut |texto| parametrizar(texto, &trocas, false, &mut usados))),
+            })
+            .collect();
+        Macro { descricao: None, parametros: usados, passos }
+    }
+}
+
+// --- FUNÇÕES PRIVADAS ---
+
+fn arquivos_de_macros() -> Vec<PathBuf> {
+    let mut arquivos = Vec::new();
+    if let Some(home) = env::var_os("HOME") {
+        arquivos.push(PathBuf::from(home).join(ARQUIVO_USUARIO));
+    }
+    arquivos.push(PathBuf::from(ARQUIVO_ENGAGEMENT));
+    arquivos
+}
+
+// '{url}' e '{url:host}'. O '{{URL}}' (variável da sessão) passa reto.
+// 'citar' = vai pro shell: o valor entra com aspas ('x; rm -rf ~' é só texto).
+fn preencher(texto: &str, valores: &BTreeMap<String, String>, citar: bool) -> String {
+    static PARAMETRO: OnceLock<Regex> = OnceLock::new();
+    let regex = PARAMETRO.get_or_init(|| {
+        Regex::new(r"\{\{[^}]*\}\}|\{([A-Za-z_][A-Za-z0-9_-]*)(:host)?\}").expect("regex dos parâmetros")
+    });
+    regex
+        .replace_all(texto, |c: &Captures| {
+            let valor = match (c.get(1), valores.get(c.get(1).map(|m| m.as_str()).unwrap_or_default())) {
+                (Some(_), Some(valor)) if c.get(2).is_some() => escopo::host_do_alvo(valor),
+                (Some(_), Some(valor)) => valor.clone(),
+                _ => return c[0].to_string(),
+            };
+            match shlex::try_quote(&valor) {
+                Ok(citado) if citar => citado.to_string(),
+                Err(_) if citar => c[0].to_string(), // Byte nulo não vai pro shell
+                _ => valor,
+            }
+        })
+        .to_string()
+}
+
+fn preencher_json(valor: &mut Value, valores: &BTreeMap<String, String>) {
+    match valor {
+        Value::String(texto) => *texto = preencher(texto, valores, false),
+        Value::Array(lista) => lista.iter_mut().for_each(|v| preencher_json(v, valores)),
+        Value::Object(mapa) => mapa.values_mut().for_each(|v| preencher_json(v, valores)),
+        _ => {}
+    }
+}
+
+// O contrário do 'preencher': valor vira '{NOME}', mas só token inteiro
+// (variável valendo '1' não come o '10.0.0.1' nem o 'scan1.txt').
+// 'shell' = é o comando, que já veio com o valor citado ('curl 'http://x/?a=1&b=2''):
+// a aspa sai junto (o 'preencher' cita de novo) e nada entre aspas é mexido,
+// senão o valor voltaria sem aspas ou dentro de "..." onde o '$(...)' roda.
+fn parametrizar(texto: &str, trocas: &[(&String, &String)], shell: bool, usados: &mut Vec<String>) -> String {
+    let separa = |c: Option<char>| match c {
+        None => true,
+        Some(c) if shell => c.is_whitespace() || ";&|()<>=".contains(c),
+        Some(c) => c.is_whitespace() || "'\"()[]<>,;=".contains(c),
+    };
+    let formas = |valor: &str| -> Vec<String> {
+        if !shell {
+            return vec![valor.to_string()];
+        }
+        let mut formas = Vec::new();
+        if let Ok(citado) = shlex::try_quote(valor) {
+            formas.push(citado.to_string()); // (Valor sem nada especial: ele mesmo)
+        }
+        if !valor.contains('\'') {
+            formas.push(format!("'{}'", valor));
+        }
+        if !valor.contains(['"', '\\', '$', '`']) {
+            formas.push(format!("\"{}\"", valor));
+        }
+        formas
+    };
+    let trocas: Vec<(Vec<String>, &String)> = trocas.iter().map(|(valor, nome)| (formas(valor), *nome)).collect();
+
+    let mut saida = String::with_capacity(texto.len());
+    let (mut simples, mut duplas) = (false, false);
+    let mut i = 0;
+    'texto: while let Some(c) = texto[i..].chars().next() {
+        if !simples && !duplas && separa(texto[..i].chars().next_back()) {
+            for (formas, nome) in &trocas {
+                for forma in formas {
+                    let fim = i + forma.len();
+                    if texto[i..].starts_with(forma.as_str()) && separa(texto[fim..].chars().next()) {
+                        saida.push_str(&format!("{{{}}}", nome));
+                        if !usados.contains(nome) {
+                            usados.push(nome.to_string());
+                        }
+                        i = fim;
+                        continue 'texto;
+                    }
+                }
+            }
+        }
+        match c {
+            '\\' if shell && !simples => {
+                saida.push(c);
+                i += 1;
+                if let Some(escapado) = texto[i..].chars().next() {
+                    saida.push(escapado);
+                    i += escapado.len_utf8();
+                }
+                continue;
+            }
+            '\'' if shell && !duplas => simples = !simples,
+            '"' if shell && !simples => duplas = !duplas,
+            _ => {}
+        }
+        saida.push(c);
+        i += c.len_utf8();
+    }
+    saida
+}
+
+fn parametrizar_json(valor: &Value, trocar: &mut impl FnMut(&str) -> String) -> Value {
+    match valor {
+        Value::String(texto) => Value::String(trocar(texto)),
+        Value::Array(lista) => Value::Array(lista.iter().map(|v| parametrizar_json(v, trocar)).collect()),
+        Value::Object(mapa) => Value::Object(mapa.iter().map(|(k, v)| (k.clone(), parametrizar_json(v, trocar))).collect()),
+        outro => outro.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valores(pares: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pares.iter().map(|(n, v)| (n.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn preenche_parametro_e_host() {
+        let v = valores(&[("url", "http://alvo.com:8080/app")]);
+        assert_eq!(preencher("gobuster em {url} ({url:host})", &v, false), "gobuster em http://alvo.com:8080/app (alvo.com)");
+        // Desconhecido e variável da sessão passam reto
+        assert_eq!(preencher("{nada} {{URL}}", &v, false), "{nada} {{URL}}");
+    }
+
+    #[test]
+    fn no_comando_o_valor_vai_com_aspas() {
+        let v = valores(&[("url", "http://x/?a=1&b=2"), ("mau", "x; rm -rf ~ $(id)")]);
+        assert_eq!(preencher("curl -sI {url}", &v, true), "curl -sI 'http://x/?a=1&b=2'");
+        let comando = preencher("echo {mau}", &v, true);
+        assert_eq!(shlex::split(&comando).unwrap(), vec!["echo", "x; rm -rf ~ $(id)"]);
+    }
+
+    #[test]
+    fn expandir_cita_so_o_comando() {
+        let receita: Macro = toml::from_str(
+            r#"
+            parametros = ["url"]
+            passos = [
+              { tarefa = "gobuster", args = { url = "{url}" } },
+              { tarefa = "execute_command", comando = "curl -sI {url}" },
+              { pedido = "subdomínios de {url:host}" },
+            ]
+            "#,
+        )
+        .unwrap();
+        let etapas = receita.expandir("t", &["http://a.com/?x=1&y=2".to_string()], &Variaveis::default()).unwrap();
+        let [Etapa::Tarefa(gobuster), Etapa::Tarefa(curl), Etapa::Pedido(pedido)] = etapas.as_slice() else {
+            panic!("etapas erradas");
+        };
+        assert_eq!(gobuster.task_args.as_ref().unwrap()["url"], "http://a.com/?x=1&y=2");
+        assert_eq!(curl.command_to_run.as_deref(), Some("curl -sI 'http://a.com/?x=1&y=2'"));
+        assert_eq!(pedido, "subdomínios de a.com");
+    }
+
+    fn executada(comando: &str, alvo: &str) -> FenrirTask {
+        FenrirTask {
+            task_type: "execute_command".to_string(),
+            ia_explanation: format!("Headers de {}", alvo),
+            command_to_run: Some(comando.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn salvar_e_rodar_de_novo_mantem_as_aspas() {
+        let url = "http://x/?a=1&b=2";
+        let sessao = Variaveis::de(&[("URL", url), ("N", "1")]);
+        let comando = format!("curl -sI {} -o scan1.txt; echo \"viu {}\"", shlex::try_quote(url).unwrap(), url);
+        let salva = Macro::das_tarefas(&[executada(&comando, url)], &sessao);
+
+        assert_eq!(salva.parametros, vec!["URL"]);
+        assert_eq!(salva.passos[0].comando.as_deref(), Some(format!("curl -sI {{URL}} -o scan1.txt; echo \"viu {}\"", url).as_str()));
+        assert_eq!(salva.passos[0].explicacao.as_deref(), Some("Headers de {URL}"));
+
+        let etapas = salva.expandir("t", &[], &sessao).unwrap();
+        let [Etapa::Tarefa(tarefa)] = etapas.as_slice() else {
+            panic!("etapas erradas");
+        };
+        assert_eq!(tarefa.command_to_run.as_deref(), Some(comando.as_str()));
+        let segmentos = crate::politica::segmentos_shell(tarefa.command_to_run.as_deref().unwrap());
+        assert_eq!(segmentos[0], vec!["curl", "-sI", url, "-o", "scan1.txt"]);
+    }
+
+    #[test]
+    fn expandir_sem_parametro_reclama() {
+        let receita: Macro = toml::from_str(r#"parametros = ["url"]
+            passos = [{ pedido = "{url}" }]"#).unwrap();
+        assert!(receita.expandir("t", &[], &Variaveis::default()).is_err());
+    }
+}
@@ -2,9 +2,32 @@
 // A "cagada de junior" (tudo no main) ACABOU.
 // Declaramos os módulos que o Rust vai procurar.
 // (ex: 'mod oraculo' faz o Rust procurar 'src/oraculo.rs')
+mod anexos;
+mod arquivos;
+mod comandos;
+mod config;
+mod desfazer;
+mod editor;
+mod escopo;
+mod eventos;
 mod executor;
 mod oraculo;
+mod parada;
 mod ferramentas;
+mod fila;
+mod leitor;
+mod limites;
+mod macros;
+mod mensagens;
+mod politica;
+mod resolvedor;
+mod risco;
+mod sandbox;
+mod segredos;
+mod sessao;
+mod tela;
+mod terminal;
+mod variaveis;
 
 // --- IMPORTS (use) ---
 // Agora a gente chama as funções dos *nossos* módulos.
@@ -13,128 +36,703 @@ mod ferramentas;
 // use crate::executor::{ask_for_confirmation, handle_execute_command, handle_open_editor, log_task};
 // use crate::oraculo::{chamar_gemini_com_timeout, FenrirTask};
 
+use crate::anexos::Anexo;
+use crate::comandos::Meta;
+use crate::escopo::Escopo;
+use crate::eventos::Desfecho;
+use crate::leitor::Leitor;
+use crate::limites::{Limites, TabelaLimites};
+use crate::macros::{Etapa, Macro, Macros};
+use crate::oraculo::FenrirTask;
+use crate::politica::Politica;
+use crate::risco::Analise;
+use crate::sandbox::ConfigSandbox;
+use crate::sessao::Sessao;
+use crate::variaveis::Variaveis;
 use indicatif::{ProgressBar, ProgressStyle};
 use std::env;
-use std::io::{self};
-use std::time::Duration;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+// --- CONSTANTES (só do main) ---
+const JANELA_CTRL_C: Duration = Duration::from_secs(2); // Dois Ctrl-C nesse tempo = PARADA
+
+// Opções que valem pra sessão inteira
+// (a linha de comando e o ambiente definem, o REPL pode mexer)
+struct Opcoes {
+    sandbox: Option<ConfigSandbox>,
+    dry_run: bool,
+    politica: Politica,
+    sessao: Sessao, // Pasta atual (e a pilha) de onde tudo roda
+    limites: TabelaLimites, // Timeout/CPU/memória/saída por tarefa e ferramenta
+    escopo: Escopo,         // Alvos liberados no engagement (':scope')
+    variaveis: Variaveis,   // $TARGET, {{URL}}... (':set NOME=valor')
+    macros: Macros,         // Receitas de várias tarefas (':macro')
+    executadas: Vec<FenrirTask>, // O que foi aprovado nessa sessão (pro ':save-macro')
+    anexos: Vec<Anexo>,          // stdin e '--file': vão junto em toda consulta
+}
+
+// A última proposta do Oráculo (pro ':retry' e o ':explain')
+struct Ultima {
+    pedido: String,
+    task: FenrirTask,
+}
 
 #[tokio::main]
 async fn main() {
-    let args: Vec<String> = env::args().collect();
-    let pb = ProgressBar::new_spinner(); // Spinner pra gente ver rodando
+    let mut args: Vec<String> = env::args().collect();
+    tokio::spawn(vigiar_ctrl_c());
+
+    // Config em camadas; a linha de comando é a última (e ganha de todas).
+    // Sandbox é opt-in: '--sandbox' ou FENRIR_SANDBOX=1.
+    // Dry-run: '--dry-run' ou FENRIR_DRY_RUN=1 (no REPL: ':dry-run on|off').
+    // '--json': stdout só com os eventos em JSON, o resto vai pro stderr.
+    let json = tirar_flag(&mut args, "--json");
+    // CI: '--nao-interativo' (ninguém responde s/n) e '--auto-aprovar <risco>'
+    // (aprova sozinho até esse risco; o resto é recusado e o exit code é 2)
+    if json {
+        if let Err(e) = eventos::ligar() {
+            eprintln!("{}", msg!("geral.erro", e));
+            std::process::exit(1);
+        }
+    }
+    let mut da_linha = Vec::new();
+    if tirar_flag(&mut args, "--sandbox") {
+        da_linha.push(("confirmacao.sandbox".to_string(), "true".to_string(), "--sandbox".to_string()));
+    }
+    if tirar_flag(&mut args, "--dry-run") {
+        da_linha.push(("confirmacao.dry_run".to_string(), "true".to_string(), "--dry-run".to_string()));
+    }
+    if tirar_flag(&mut args, "--nao-interativo") {
+        da_linha.push(("confirmacao.nao_interativo".to_string(), "true".to_string(), "--nao-interativo".to_string()));
+    }
+    if let Some(pos) = args.iter().position(|a| a == "--auto-aprovar") {
+        args.remove(pos);
+        match (pos < args.len()).then(|| args.remove(pos)) {
+            Some(risco) => {
+                da_linha.push(("confirmacao.nao_interativo".to_string(), "true".to_string(), "--auto-aprovar".to_string()));
+                da_linha.push(("confirmacao.risco_maximo".to_string(), risco, "--auto-aprovar".to_string()));
+            }
+            None => eprintln!("{}", msg!("main.auto_aprovar_sem_risco")),
+        }
+    }
+    // '--set chave=valor' (pode repetir), ex: '--set oraculo.timeout_segundos=120'
+    while let Some(pos) = args.iter().position(|a| a == "--set") {
+        args.remove(pos);
+        match (pos < args.len()).then(|| args.remove(pos)) {
+            Some(par) => match par.split_once('=') {
+                Some((chave, valor)) => da_linha.push((chave.trim().to_string(), valor.to_string(), "--set".to_string())),
+                None => eprintln!("{}", msg!("main.set_formato", par)),
+            },
+            None => eprintln!("{}", msg!("main.set_vazio")),
+        }
+    }
+    // '--stdin': o pipe vem de coisa lenta, espera ele (sem isso, pipe mudo é ignorado)
+    let esperar_stdin = tirar_flag(&mut args, "--stdin");
+    // '--file caminho' (pode repetir): o arquivo vai junto pro Oráculo
+    let mut arquivos = Vec::new();
+    while let Some(pos) = args.iter().position(|a| a == "--file") {
+        args.remove(pos);
+        match (pos < args.len()).then(|| args.remove(pos)) {
+            Some(caminho) => arquivos.push(caminho),
+            None => eprintln!("{}", msg!("main.file_vazio")),
+        }
+    }
+    config::carregar(&da_linha);
+    let config = config::atual();
 
-    if args.len() > 1 {
-        // Modo "um comando e vaza"
+    tela::ajustar_cor(config.interface.cor);
+    // Spinner pra gente ver rodando (desligado na config ou no '--json' = escondido)
+    let pb = if config.interface.spinner && !json { ProgressBar::new_spinner() } else { ProgressBar::hidden() };
+
+    let mut opcoes = Opcoes {
+        sandbox: ConfigSandbox::da_config(&config),
+        dry_run: config.confirmacao.dry_run,
+        politica: Politica::carregar(),
+        sessao: Sessao::nova(),
+        limites: TabelaLimites::carregar(),
+        escopo: Escopo::carregar(),
+        variaveis: Variaveis::carregar(),
+        macros: Macros::carregar(),
+        executadas: Vec::new(),
+        anexos: Vec::new(),
+    };
+    for caminho in &arquivos {
+        match anexos::ler_arquivo(caminho, &opcoes.anexos) {
+            Ok(anexo) => opcoes.anexos.push(anexo),
+            Err(e) => desistir_do_anexo(&e),
+        }
+    }
+
+    if args.len() > 1 && args[1] == "config" {
+        // 'fenrir config [show]': o que vale e de onde veio
+        match args.get(2).map(String::as_str) {
+            None | Some("show") => config::mostrar(),
+            Some(outro) => eprintln!("{}", msg!("main.config_nao_existe", outro)),
+        }
+    } else if args.len() > 1 && args[1] == "fila" {
+        // Modo "vários alvos, uma ferramenta"
+        fila::rodar_pela_cli(&args[2..], opcoes.dry_run, &opcoes.politica, &opcoes.escopo).await;
+    } else if args.len() > 1 && args[1] == "stop" {
+        // 'fenrir stop': PARADA DE EMERGÊNCIA (serve de outro terminal)
+        let _ = tokio::task::spawn_blocking(|| parada::parar_tudo("fenrir stop")).await;
+    } else if args.len() > 1 && args[1] == "macro" {
+        // 'fenrir macro <nome> [args...]' (sem nome: lista)
+        match args.get(2) {
+            Some(nome) => rodar_macro(nome, &args[3..], &pb, &mut opcoes).await,
+            None => opcoes.macros.listar(),
+        }
+    } else if args.len() > 1 && args[1] == "undo" {
+        // 'fenrir undo [id|lista]'
+        desfazer::rodar(args.get(2).map(String::as_str)).await;
+    } else if args.len() > 1 {
+        // Modo "um comando e vaza" (o que vier pelo pipe é anexo)
+        match anexos::ler_stdin(&opcoes.anexos, esperar_stdin) {
+            Some(Ok(anexo)) => opcoes.anexos.insert(0, anexo),
+            Some(Err(e)) => desistir_do_anexo(&e),
+            None => {}
+        }
         let consulta_completa = args[1..].join(" ");
-        processar_solicitacao(&consulta_completa, &pb).await;
+        processar_solicitacao(&consulta_completa, &pb, &mut opcoes).await;
     } else {
         // Modo interativo
-        println!("Ei, cara! Modo interativo do Fenrir.");
-        println!("Manda a braba (ou 'sair' pra vazar).");
-        interativo(&pb).await;
+        println!("{}", msg!("main.ola"));
+        println!("{}", msg!("main.ola2"));
+        interativo(&pb, &mut opcoes).await;
+        return;
+    }
+
+    // Fora do REPL o código de saída conta como foi: 0 ok, 1 falhou, 2 recusado
+    let codigo = eventos::encerrar();
+    if codigo != 0 {
+        std::process::exit(codigo);
     }
 }
 
-async fn interativo(pb: &ProgressBar) {
-    let stdin = io::stdin();
-    let mut input_buffer = String::new();
+// Ctrl-C UMA vez: cancela o passo atual (mata a chamada do Oráculo ou
+// repassa o Ctrl-C pro comando rodando) e volta pro prompt.
+// Ctrl-C duas vezes (dentro da janela) = PARADA DE EMERGÊNCIA: derruba tudo
+// que QUALQUER Fenrir subiu e sai.
+// (Programa no PTY ou com o terminal na mão recebe o Ctrl-C ele mesmo.)
+async fn vigiar_ctrl_c() {
+    let mut ultimo: Option<Instant> = None;
+    while tokio::signal::ctrl_c().await.is_ok() {
+        if ultimo.is_some_and(|t| t.elapsed() < JANELA_CTRL_C) {
+            eprintln!("{}", msg!("main.ctrl_c_duplo"));
+            let _ = tokio::task::spawn_blocking(|| parada::parar_tudo("ctrl-c duplo")).await;
+            std::process::exit(130);
+        }
+        ultimo = Some(Instant::now());
+        parada::cancelar_passo();
+        eprintln!("{}", msg!("main.ctrl_c"));
+    }
+}
+
+// Anexo pedido e não lido: melhor parar do que perguntar sem ele
+fn desistir_do_anexo(erro: &str) {
+    eprintln!("{}", msg!("geral.erro", erro));
+    eventos::emitir("erro", &serde_json::json!({ "etapa": "anexo", "erro": erro }));
+    eventos::marcar(Desfecho::Falha);
+    std::process::exit(eventos::encerrar());
+}
+
+// Tira uma flag (ex: '--dry-run') da lista de args. Devolve se ela tava lá.
+fn tirar_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    match args.iter().position(|a| a == flag) {
+        Some(pos) => {
+            args.remove(pos);
+            true
+        }
+        None => false,
+    }
+}
+
+async fn interativo(pb: &ProgressBar, opcoes: &mut Opcoes) {
+    let mut leitor = match Leitor::novo(comandos::nomes()) {
+        Ok(l) => l,
+        Err(e) => {
+            eprintln!("{}", msg!("geral.oxe", e));
+            return;
+        }
+    };
+    let mut ultima: Option<Ultima> = None;
 
     loop {
-        input_buffer.clear();
-        match stdin.read_line(&mut input_buffer) {
-            Ok(0) => break, // Fim da entrada (Ctrl+D)
-            Ok(_) => {
-                let trimado = input_buffer.trim().to_lowercase();
-                if trimado.is_empty() {
-                    continue;
+        // O prompt mostra a pasta da sessão (muda com o 'navigate')
+        let prompt = format!("fenrir {}> ", opcoes.sessao.prompt());
+        let entrada = match leitor.ler(&prompt, opcoes.sessao.cwd()) {
+            Ok(Some(e)) => e,
+            Ok(None) => break, // Fim da entrada (Ctrl+D)
+            Err(e) => {
+                eprintln!("{}", msg!("main.erro_entrada", e));
+                break;
+            }
+        };
+
+        // Do jeito que veio: URL e caminho têm maiúscula, sim senhor
+        let trimado = entrada.trim();
+        if trimado.is_empty() {
+            continue;
+        }
+
+        match comandos::interpretar(trimado) {
+            Some(Ok((Meta::Sair, _))) => {
+                println!("{}", msg!("main.tchau"));
+                break;
+            }
+            Some(Ok((meta, argumentos))) => executar_meta(meta, argumentos, &leitor, ultima.as_ref(), pb, opcoes).await,
+            Some(Err(e)) => eprintln!("{}", msg!("geral.oxe", e)),
+            None => {
+                // Se não for comando do REPL, é pro Oráculo!
+                if let Some(task) = processar_solicitacao(trimado, pb, opcoes).await {
+                    ultima = Some(Ultima { pedido: trimado.to_string(), task });
                 }
-                if trimado == "sair" || trimado == "exit" {
-                    println!("Falou, parceiro! Até a próxima.");
-                    break;
+                println!("{}", msg!("main.proxima"));
+            }
+        }
+    }
+}
+
+// Os comandos ':' do REPL (a tabela e os mais simples tão em 'comandos')
+async fn executar_meta(meta: Meta, argumentos: &str, leitor: &Leitor, ultima: Option<&Ultima>, pb: &ProgressBar, opcoes: &mut Opcoes) {
+    match meta {
+        Meta::Ajuda => comandos::imprimir_ajuda(),
+        Meta::Historico => comandos::mostrar_historico(&leitor.historico(), argumentos),
+        Meta::Jobs => comandos::mostrar_jobs(),
+        Meta::PararTudo => {
+            let _ = tokio::task::spawn_blocking(|| parada::parar_tudo(":stop-all")).await;
+        }
+        Meta::Set if argumentos.is_empty() => {
+            config::mostrar();
+            opcoes.variaveis.mostrar();
+        }
+        Meta::Set => match argumentos.split_once('=') {
+            // Sem ponto no nome = variável da sessão ('TARGET=10.10.11.5')
+            Some((nome, valor)) if variaveis::eh_variavel(nome.trim()) => match opcoes.variaveis.definir(nome.trim(), valor.trim()) {
+                Ok(()) if valor.trim().is_empty() => println!("{}", msg!("main.variavel_apagada", nome.trim())),
+                Ok(()) => println!("{}", msg!("main.variavel_definida", nome.trim(), valor.trim())),
+                Err(e) => eprintln!("{}", msg!("geral.erro", e)),
+            },
+            Some((chave, valor)) => match config::definir(chave.trim(), valor.trim(), ":set") {
+                Ok(()) => {
+                    aplicar_config(chave.trim(), opcoes);
+                    println!("{}", msg!("main.config_definida", chave.trim(), valor.trim()));
                 }
+                Err(e) => eprintln!("{}", msg!("geral.erro", e)),
+            },
+            None => println!("{}", comandos::uso(meta)),
+        },
+        Meta::Backend => comandos::backend(argumentos),
+        Meta::Escopo => comandos::editar_escopo(argumentos, &mut opcoes.escopo),
+        Meta::Engagement => comandos::mostrar_engagement(&opcoes.escopo),
+        Meta::DryRun => {
+            match argumentos.to_lowercase().as_str() {
+                "on" => opcoes.dry_run = true,
+                "off" => opcoes.dry_run = false,
+                "" => {}
+                _ => println!("{}", comandos::uso(meta)),
+            }
+            let estado = if opcoes.dry_run { msg!("main.dry_run_ligado") } else { msg!("main.dry_run_desligado") };
+            println!("{}", msg!("main.dry_run", estado));
+        }
+        Meta::Ultima => comandos::mostrar_ultima_saida(),
+        Meta::Repetir => match ultima {
+            // Sem chamar o Oráculo: a MESMA proposta, pela política de novo
+            Some(u) => {
+                println!("{}", msg!("main.de_novo", u.pedido));
+                executar_tarefa(&u.task, opcoes).await;
+            }
+            None => println!("{}", msg!("main.sem_proposta")),
+        },
+        Meta::Explicar => match ultima {
+            Some(u) => explicar(u, opcoes),
+            None => println!("{}", msg!("main.sem_proposta")),
+        },
+        Meta::Macro => {
+            let partes: Vec<String> = argumentos.split_whitespace().map(String::from).collect();
+            match partes.split_first() {
+                Some((nome, resto)) => rodar_macro(nome, resto, pb, opcoes).await,
+                None => opcoes.macros.listar(),
+            }
+        }
+        Meta::SalvarMacro => salvar_macro(argumentos, opcoes),
+        Meta::Desfazer => desfazer::rodar((!argumentos.is_empty()).then_some(argumentos)).await,
+        Meta::Sair => {} // O loop do REPL que sai
+    }
+}
 
-                // Se não for "sair", é pro Oráculo!
-                processar_solicitacao(&trimado, pb).await;
-                println!("\nPróxima? (ou 'sair' pra vazar)");
+// Roda a macro: cada passo pelo caminho normal (pedido vai pro Oráculo,
+// tarefa pronta vai direto pro risco/escopo/política).
+// Passo recusado ou que falhou? Pergunta antes de seguir (o passo seguinte
+// quase sempre depende dele). No não-interativo, para ali.
+async fn rodar_macro(nome: &str, args: &[String], pb: &ProgressBar, opcoes: &mut Opcoes) {
+    let Some(receita) = opcoes.macros.pegar(nome) else {
+        eprintln!("{}", msg!("main.macro_nao_existe", nome));
+        return;
+    };
+    let etapas = match receita.expandir(nome, args, &opcoes.variaveis) {
+        Ok(e) => e,
+        Err(e) => {
+            eprintln!("{}", msg!("geral.erro", e));
+            return;
+        }
+    };
+
+    let total = etapas.len();
+    for (i, etapa) in etapas.into_iter().enumerate() {
+        if i > 0 && eventos::desfecho_do_passo() != Desfecho::Sucesso {
+            let como = if eventos::desfecho_do_passo() == Desfecho::Recusado { "main.macro_recusado" } else { "main.macro_falhou" };
+            println!("{}", msg!(como, i));
+            if !executor::confirmar_passo(&msg!("main.macro_continua", total - i), false).await {
+                println!("{}", msg!("main.macro_parou", nome, i, total));
+                return;
             }
-            Err(e) => {
-                eprintln!("Oxe! Deu erro lendo sua entrada: {}", e);
-                break;
+        }
+        eventos::novo_passo();
+        println!("{}", msg!("main.macro_passo", nome, i + 1, total));
+        match etapa {
+            Etapa::Pedido(pedido) => {
+                processar_solicitacao(&pedido, pb, opcoes).await;
+            }
+            Etapa::Tarefa(mut task) => {
+                opcoes.variaveis.substituir_tarefa(&mut task);
+                executar_tarefa(&task, opcoes).await;
             }
         }
     }
+    println!("{}", msg!("main.macro_fim", nome));
+}
+
+// ':save-macro <nome> [n]': as últimas n tarefas aprovadas (sem n: todas da sessão)
+fn salvar_macro(argumentos: &str, opcoes: &mut Opcoes) {
+    let mut partes = argumentos.split_whitespace();
+    let (Some(nome), quantas) = (partes.next(), partes.next()) else {
+        println!("{}", comandos::uso(Meta::SalvarMacro));
+        return;
+    };
+    let quantas = match quantas.map(str::parse::<usize>) {
+        None => opcoes.executadas.len(),
+        Some(Ok(n)) if n > 0 => n,
+        Some(_) => {
+            println!("{}", comandos::uso(Meta::SalvarMacro));
+            return;
+        }
+    };
+    if opcoes.executadas.is_empty() {
+        println!("{}", msg!("main.nada_rodou"));
+        return;
+    }
+
+    let inicio = opcoes.executadas.len().saturating_sub(quantas);
+    let nova = Macro::das_tarefas(&opcoes.executadas[inicio..], &opcoes.variaveis);
+    let resumo: Vec<String> = nova.passos.iter().filter_map(|p| p.tarefa.clone()).collect();
+    let parametros = nova.parametros.clone();
+    match opcoes.macros.salvar(nome, nova) {
+        Ok(()) => {
+            println!("{}", msg!("main.macro_salva", nome, resumo.join(" -> ")));
+            if !parametros.is_empty() {
+                println!("{}", msg!("main.macro_parametros", parametros.join(" ")));
+            }
+            println!("{}", msg!("main.macro_como_rodar", nome, nome));
+        }
+        Err(e) => eprintln!("{}", msg!("main.macro_nao_salvou", e)),
+    }
+}
+
+// Depois de um ':set': a sessão copiou umas coisas da config lá no começo
+fn aplicar_config(chave: &str, opcoes: &mut Opcoes) {
+    let config = config::atual();
+    match chave {
+        "confirmacao.dry_run" => opcoes.dry_run = config.confirmacao.dry_run,
+        "confirmacao.sandbox" | "confirmacao.sandbox_rede" | "confirmacao.sandbox_cwd" => {
+            opcoes.sandbox = ConfigSandbox::da_config(&config)
+        }
+        "interface.cor" => tela::ajustar_cor(config.interface.cor),
+        _ => {} // O resto é lido na hora de usar
+    }
+}
+
+// ':explain': o porquê da última proposta (sem chamar ninguém)
+fn explicar(ultima: &Ultima, opcoes: &Opcoes) {
+    let task = &ultima.task;
+    let analise = risco::analisar(task, opcoes.sessao.cwd());
+    let decisao = opcoes.politica.avaliar(&politica::pedido_da_tarefa(task));
+    let limites = limites_da_tarefa(task, opcoes);
+    let fora = opcoes.escopo.fora(task);
+
+    println!("{}", msg!("main.ultima_titulo"));
+    println!("{}", msg!("main.pedido", ultima.pedido));
+    println!("{}", msg!("main.entendeu", task.ia_explanation, task.task_type));
+    if let Some(cmd) = &task.command_to_run {
+        println!("{}", msg!("main.comando", tela::comando(cmd)));
+    }
+    if let Some(path) = &task.target_path {
+        println!("{}", msg!("main.arquivo", path));
+    }
+    if let Some(args) = &task.task_args {
+        println!("{}", msg!("main.args", args));
+    }
+    println!("{}", msg!("main.risco", tela::selo(analise.nivel)));
+    for motivo in &analise.motivos {
+        println!("  - {}", motivo);
+    }
+    if analise.modifica_arquivos() {
+        println!("{}", msg!("main.mexe_arquivo"));
+    }
+    println!("{}", msg!("main.politica", decisao.acao.rotulo(), decisao.origem, decisao.motivo));
+    println!("{}", msg!("main.limites", if limites.vazio() { msg!("main.limites_nenhum") } else { limites.resumo() }));
+    match (opcoes.escopo.alvos.is_empty(), fora.is_empty()) {
+        (true, _) => println!("{}", msg!("main.escopo_indefinido")),
+        (false, true) => println!("{}", msg!("main.escopo_dentro")),
+        (false, false) => println!("{}", msg!("main.escopo_fora", fora.join(", "))),
+    }
+    println!("{}", msg!("main.separador_curto"));
 }
 
 // --- O CÉREBRO DO FENRIR ---
 // O main.rs agora só "orquestra".
 // Ele chama o Oráculo, depois chama o Executor.
-async fn processar_solicitacao(consulta: &str, pb: &ProgressBar) {
+// Devolve a tarefa que o Oráculo propôs (None = nem veio proposta)
+async fn processar_solicitacao(consulta: &str, pb: &ProgressBar, opcoes: &mut Opcoes) -> Option<FenrirTask> {
     pb.set_style(
         ProgressStyle::default_spinner()
-            .tick_strings(&["VAI", "CORNO!", "PENSE", "DESGRAÇA!", "...", "VAI", "LOGO", "CARALHO!", "(ノ°Д°）ノ", "┻━┻", "...", "VAI", "CORNO!"])
+            .tick_strings(&mensagens::quadros("main.spinner"))
             .template("{spinner:.bold.yellow} {msg}")
             .unwrap(),
     );
-    pb.set_message("Chamando o Oráculo (Gemini)...");
+    pb.set_message(msg!("main.chamando"));
     pb.enable_steady_tick(Duration::from_millis(150));
 
+    // $TARGET e cia. já vão trocados (e a lista vai junto, de contexto)
+    let consulta = opcoes.variaveis.substituir(consulta);
+
     // 1. CHAMA O ORÁCULO (que agora tá em 'src/oraculo.rs')
-    match oraculo::chamar_gemini_com_timeout(consulta).await {
-        Ok(task) => {
-            // Oráculo respondeu!
-            pb.finish_with_message("! Oráculo respondeu!");
-
-            // 2. CHAMA O EXECUTOR (log_task)
-            if let Err(e) = executor::log_task(&task) {
-                eprintln!("Xii, deu erro pra logar a tarefa: {}", e);
-            }
-
-            // 3. CHAMA O EXECUTOR (Freio de Mão)
-            let acao_proposta = format!(
-                "O Oráculo sugeriu: '{}' \nTipo: '{}' \nComando: '{}' \nArquivo: '{}'",
-                task.ia_explanation,
-                task.task_type,
-                task.command_to_run.as_deref().unwrap_or("N/A"),
-                task.target_path.as_deref().unwrap_or("N/A")
-            );
-
-            println!("\n--- PROPOSTA DO ORÁCULO ---");
-            println!("{}", acao_proposta);
-            println!("-----------------------------");
-
-            let confirmacao = executor::ask_for_confirmation("Executa essa porra? (s/n):").await;
-
-            if confirmacao {
-                println!("Ok, segurando o volante...");
-
-                // 4. CHAMA O EXECUTOR (As "Mãos")
-                match task.task_type.as_str() {
-                    "execute_command" => {
-                        if let Some(cmd) = task.command_to_run {
-                            executor::handle_execute_command(&cmd);
-                        } else {
-                            eprintln!("Erro: Oráculo mandou 'execute_command' mas não mandou o comando!");
+    let contexto = format!("{}{}", opcoes.variaveis.contexto(), anexos::contexto(&opcoes.anexos));
+    match oraculo::chamar_gemini_com_timeout(&consulta, &contexto).await {
+        Ok(mut task) => {
+            // Oráculo respondeu! (e pode ter respondido com '{{TARGET}}')
+            pb.finish_with_message(msg!("main.respondeu"));
+            opcoes.variaveis.substituir_tarefa(&mut task);
+            executar_tarefa(&task, opcoes).await;
+            Some(task)
+        }
+        Err(e) if e == parada::CANCELADO => {
+            // Ctrl-C no meio da chamada: o 'gemini' já foi pro saco
+            pb.finish_with_message(msg!("main.cancelado"));
+            println!("{}", msg!("main.esquece"));
+            eventos::marcar(Desfecho::Recusado);
+            None
+        }
+        Err(e) => {
+            // Deu ruim no Oráculo
+            pb.finish_with_message(msg!("main.deu_ruim"));
+            eprintln!("{}", msg!("main.erro_oraculo", e));
+            eventos::emitir("erro", &serde_json::json!({ "etapa": "oraculo", "pedido": consulta, "erro": e }));
+            eventos::marcar(Desfecho::Falha);
+            None
+        }
+    }
+}
+
+// Da proposta pra execução: log, risco, limites, escopo, política e as "Mãos".
+// (O ':retry' entra direto aqui, sem incomodar o Oráculo de novo.)
+async fn executar_tarefa(task: &FenrirTask, opcoes: &mut Opcoes) {
+    // 2. CHAMA O EXECUTOR (log_task)
+    if let Err(e) = executor::log_task(task, opcoes.dry_run) {
+        eprintln!("{}", msg!("main.erro_log_tarefa", e));
+    }
+
+    // 'analyze' não roda nada: a resposta É a análise (sem política, sem s/n)
+    if task.task_type == "analyze" {
+        mostrar_analise(task);
+        return;
+    }
+
+    // 3. CHAMA O EXECUTOR (Freio de Mão)
+    // Quão braba é, e quais arquivos do projeto ela mexe (pro undo)
+    let analise = risco::analisar(task, opcoes.sessao.cwd());
+
+    // A proposta na tela: comando colorido, selo do risco, alvos e onde a saída cai
+    let alvos = politica::pedido_da_tarefa(task).alvos;
+    let artefatos: Vec<String> = ferramentas::montar(&task.task_type, task.task_args.clone())
+        .ok()
+        .and_then(|pronto| pronto.saida)
+        .into_iter()
+        .collect();
+    tela::proposta(task, &analise, &alvos, &artefatos);
+
+    let mut limites = limites_da_tarefa(task, opcoes);
+    if !limites.vazio() {
+        println!("{}", msg!("main.limites_ajuste", limites.resumo()));
+    }
+    // Alvo fora do escopo do engagement?
+    let fora = opcoes.escopo.fora(task);
+    if !fora.is_empty() {
+        println!("{}", msg!("main.fora_escopo", fora.join(", ")));
+    }
+    if opcoes.dry_run {
+        println!("{}", msg!("main.dry_run_aviso"));
+    }
+    println!("{}", msg!("geral.separador"));
+    eventos::emitir(
+        "proposta",
+        &serde_json::json!({
+            "tarefa": task,
+            "risco": analise.nivel,
+            "motivos": analise.motivos,
+            "limites": limites,
+            "fora_do_escopo": fora,
+            "dry_run": opcoes.dry_run,
+        }),
+    );
+
+    // Fora do escopo pergunta ANTES da política (mesmo se ela liberar direto)
+    if !opcoes.dry_run && !executor::passar_pelo_escopo(task, &fora).await {
+        eventos::marcar(Desfecho::Recusado);
+        return;
+    }
+
+    // Política (allow/confirm/require-phrase/deny) + Freio de Mão
+    let confirmacao = executor::passar_pela_politica(task, &opcoes.politica, analise.nivel).await;
+
+    if let Some(ajustes) = confirmacao {
+        if !ajustes.is_empty() {
+            match limites.ajustar(&ajustes) {
+                Ok(()) => println!("{}", msg!("main.limites_ajustados", limites.resumo())),
+                Err(e) => {
+                    eprintln!("{}", msg!("main.erro_nada_rodou", e));
+                    eventos::marcar(Desfecho::Falha);
+                    return;
+                }
+            }
+        }
+        println!("{}", msg!("main.segurando"));
+        opcoes.executadas.push(task.clone());
+
+        // 4. CHAMA O EXECUTOR (As "Mãos")
+        match task.task_type.as_str() {
+            "execute_command" => {
+                if let Some(cmd) = &task.command_to_run {
+                    let cmd_json = serde_json::json!({ "cmd": cmd });
+                    match &opcoes.sandbox {
+                        // Dry-run com sandbox: mostra o embrulho e pronto
+                        Some(config) if opcoes.dry_run => {
+                            executor::handle_execute_command_sandbox(Some(cmd_json), opcoes.sessao.cwd(), config, &limites, true).await;
                         }
-                    }
-                    "open_editor" => {
-                        if let (Some(path), Some(app)) = (task.target_path, task.application) {
-                            executor::handle_open_editor(&app, &path);
-                        } else {
-                            eprintln!("Erro: Oráculo mandou 'open_editor' mas faltou o app ou o arquivo!");
+                        // Primeiro na caixinha, depois (se você quiser) de verdade
+                        Some(config) => {
+                            let limpo = executor::handle_execute_command_sandbox(Some(cmd_json.clone()), opcoes.sessao.cwd(), config, &limites, false).await;
+                            let pergunta = if limpo { msg!("main.sandbox_limpo") } else { msg!("main.sandbox_ruim") };
+                            // (Sozinho só promove se o teste passou E o risco cabe no teto)
+                            if executor::confirmar_passo(&pergunta, limpo && executor::risco_liberado(analise.nivel)).await {
+                                executar_de_verdade(task, &analise, cmd_json, opcoes.sessao.cwd(), &limites).await;
+                            } else {
+                                println!("{}", msg!("main.so_teste"));
+                            }
+                        }
+                        None if opcoes.dry_run => {
+                            executor::handle_execute_command(Some(cmd_json), opcoes.sessao.cwd(), &limites, true).await;
                         }
+                        None => executar_de_verdade(task, &analise, cmd_json, opcoes.sessao.cwd(), &limites).await,
                     }
-                    "unknown" | _ => {
-                        println!("O Oráculo não entendeu o que fazer. (Disse: '{}')", task.ia_explanation);
+                } else {
+                    eprintln!("{}", msg!("main.sem_comando"));
+                    eventos::marcar(Desfecho::Falha);
+                }
+            }
+            "open_editor" => {
+                // Sem APP tudo bem: cai no $VISUAL/$EDITOR
+                if let Some(path) = &task.target_path {
+                    let args = serde_json::json!({ "app": task.application, "path": path });
+                    executor::handle_open_editor(Some(args), opcoes.sessao.cwd(), opcoes.dry_run).await;
+                } else {
+                    eprintln!("{}", msg!("main.sem_arquivo", "open_editor"));
+                    eventos::marcar(Desfecho::Falha);
+                }
+            }
+            "read_file" => {
+                if let Some(path) = &task.target_path {
+                    let resumir = task
+                        .task_args
+                        .as_ref()
+                        .and_then(|a| a.get("resumir"))
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(false);
+                    let args = serde_json::json!({ "path": path, "resumir": resumir, "pedido": task.ia_explanation });
+                    arquivos::handle_read_file(Some(args), opcoes.sessao.cwd()).await;
+                } else {
+                    eprintln!("{}", msg!("main.sem_arquivo", "read_file"));
+                    eventos::marcar(Desfecho::Falha);
+                }
+            }
+            "edit_file" => {
+                // A EXPLICACAO vira a instrução pro diff
+                if let Some(path) = &task.target_path {
+                    let args = serde_json::json!({ "path": path, "instrucao": task.ia_explanation, "task_id": task.id, "risco": analise.nivel });
+                    arquivos::handle_edit_file(Some(args), opcoes.sessao.cwd(), opcoes.dry_run).await;
+                } else {
+                    eprintln!("{}", msg!("main.sem_arquivo", "edit_file"));
+                    eventos::marcar(Desfecho::Falha);
+                }
+            }
+            "navigate" => {
+                // Só muda a pasta da SESSÃO ("-" volta pra anterior)
+                let destino = task.target_path.as_deref().unwrap_or("~");
+                if opcoes.dry_run {
+                    println!("{}", msg!("main.dry_run_navegar", opcoes.sessao.caminho_de(destino).display()));
+                } else {
+                    match opcoes.sessao.navegar(destino) {
+                        Ok(pasta) => println!("{}", msg!("main.agora_em", pasta.display())),
+                        Err(e) => {
+                            eprintln!("{}", msg!("geral.erro", e));
+                            eventos::marcar(Desfecho::Falha);
+                        }
                     }
                 }
-            } else {
-                println!("Ação cancelada. Sabonetou!");
+            }
+            "nmap" | "gobuster" | "sqlmap" => {
+                // As ferramentas "hardcoded": a IA só preenche os ARGS
+                ferramentas::rodar(&task.task_type, task.task_args.clone(), opcoes.sessao.cwd(), &limites, opcoes.dry_run).await;
+            }
+            _ => { // "unknown" (ou qualquer coisa que a gente não conhece)
+                println!("{}", msg!("main.nao_entendeu", task.ia_explanation));
+                eventos::marcar(Desfecho::Falha);
             }
         }
-        Err(e) => {
-            // Deu ruim no Oráculo
-            pb.finish_with_message("! DEU RUIM!");
-            eprintln!("Ops! Deu ruim na comunicação com o Oráculo: {}", e);
-        }
+    } else {
+        println!("{}", msg!("main.sabonetou"));
+        eventos::marcar(Desfecho::Recusado);
     }
-}
\ No newline at end of file
+}
+
+// A resposta do 'analyze' (no '--json' vai inteira no evento 'analise')
+fn mostrar_analise(task: &FenrirTask) {
+    let Some(analise) = &task.analysis else {
+        eprintln!("{}", msg!("main.analise_vazia", task.ia_explanation));
+        eventos::marcar(Desfecho::Falha);
+        return;
+    };
+    println!("{}", msg!("main.analise_titulo", task.ia_explanation));
+    println!("{}", analise);
+    println!("{}", msg!("geral.separador"));
+    eventos::emitir("analise", &serde_json::json!({ "explicacao": task.ia_explanation, "analise": analise }));
+}
+
+// Limites da tarefa (execute_command olha o primeiro binário)
+fn limites_da_tarefa(task: &FenrirTask, opcoes: &Opcoes) -> Limites {
+    let ferramenta = match task.task_type.as_str() {
+        "execute_command" => politica::pedido_da_tarefa(task).binarios.into_iter().next(),
+        outro => Some(outro.to_string()),
+    };
+    opcoes.limites.para(&task.task_type, ferramenta.as_deref())
+}
+
+// Execução REAL de um comando shell. Se ele mexe em arquivo do projeto,
+// tira a foto antes e fecha o diário depois (pro 'fenrir undo').
+async fn executar_de_verdade(task: &FenrirTask, analise: &Analise, cmd_json: serde_json::Value, cwd: &Path, limites: &Limites) {
+    let diario = desfazer::preparar(task, analise);
+    executor::handle_execute_command(Some(cmd_json), cwd, limites, false).await;
+    if let Some(diario) = diario {
+        diario.selar();
+    }
+}
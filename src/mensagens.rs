@@ -0,0 +1,642 @@
+// --- MÓDULO MENSAGENS ---
+// O Fenrir fala igual a gente no Discord. Numa call com cliente, com a tela
+// compartilhada, "DESGRAÇA!" no spinner não pega bem.
+// Todo texto que aparece pro usuário mora no catálogo abaixo, em três versões:
+//
+//   pt-br         o de sempre (casual)
+//   pt-br-formal  português, tom profissional
+//   en            inglês
+//
+// Escolhe com 'interface.idioma' (ou FENRIR_IDIOMA, ou ':set interface.idioma=en').
+// O Oráculo responde no mesmo idioma (a EXPLICACAO, os resumos).
+//
+// No código: 'msg!("chave")' ou 'msg!("chave", a, b)'. Cada '{}' do texto
+// é trocado pelo próximo argumento, na ordem. Chave que não existe aparece
+// crua na tela (pra ninguém ficar sem mensagem nenhuma).
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+// --- CONSTANTES (só das Mensagens) ---
+static IDIOMA: AtomicU8 = AtomicU8::new(Idioma::PtBr as u8);
+
+// (chave, [pt-br, pt-br-formal, en]), agrupadas pelo módulo que usa
+const CATALOGO: &[(&str, [&str; 3])] = &[
+    // geral (todo mundo usa)
+    ("geral.aviso", ["Aviso: {}", "Aviso: {}", "Warning: {}"]),
+    ("geral.separador", ["-----------------------------", "-----------------------------", "-----------------------------"]),
+    ("geral.erro", ["Erro: {}", "Erro: {}", "Error: {}"]),
+    ("geral.oxe", ["Oxe! {}", "Erro: {}", "Error: {}"]),
+    ("geral.falha_criar", ["Falha ao criar '{}': {}", "Falha ao criar '{}': {}", "Failed to create '{}': {}"]),
+    ("geral.falha_gravar", ["Falha ao gravar '{}': {}", "Falha ao gravar '{}': {}", "Failed to write '{}': {}"]),
+    ("geral.nao_ler", ["Erro: não deu pra ler '{}': {}", "Erro: não foi possível ler '{}': {}", "Error: could not read '{}': {}"]),
+    ("geral.falha_ler", ["Falha ao ler '{}': {}", "Falha ao ler '{}': {}", "Failed to read '{}': {}"]),
+
+    // config
+    ("config.ajuda.oraculo.binario", ["CLI do Oráculo (recebe o prompt como argumento)", "CLI do Oráculo (recebe o prompt como argumento)", "Oracle CLI (receives the prompt as an argument)"]),
+    ("config.ajuda.oraculo.modelo", ["Modelo passado com '-m' (vazio = o padrão do CLI)", "Modelo passado com '-m' (vazio = padrão do CLI)", "Model passed with '-m' (empty = the CLI default)"]),
+    ("config.ajuda.oraculo.timeout_segundos", ["Tempo máximo esperando o Oráculo", "Tempo máximo de espera pelo Oráculo", "Maximum time waiting for the oracle"]),
+    ("config.ajuda.caminhos.log", ["Log das tarefas e eventos", "Log de tarefas e eventos", "Task and event log"]),
+    ("config.ajuda.caminhos.saida", ["Pasta das saídas das ferramentas e transcrições", "Pasta de saídas das ferramentas e transcrições", "Directory for tool output and transcripts"]),
+    ("config.ajuda.ferramentas.wordlist_gobuster", ["Wordlist do gobuster quando o Oráculo não manda uma", "Wordlist do gobuster quando o Oráculo não informar uma", "Gobuster wordlist when the oracle does not provide one"]),
+    ("config.ajuda.ferramentas.paralelo_fila", ["Quantos alvos a fila roda ao mesmo tempo", "Quantidade de alvos executados simultaneamente na fila", "How many queue targets run at the same time"]),
+    ("config.ajuda.confirmacao.acao_padrao", ["Quando nenhuma regra da política bate (allow, confirm, require-phrase, deny)", "Ação quando nenhuma regra da política se aplica (allow, confirm, require-phrase, deny)", "Action when no policy rule matches (allow, confirm, require-phrase, deny)"]),
+    ("config.ajuda.confirmacao.frase_padrao", ["Frase do 'require-phrase' quando a regra não define", "Frase do 'require-phrase' quando a regra não define uma", "'require-phrase' phrase when the rule does not set one"]),
+    ("config.ajuda.confirmacao.dry_run", ["Só mostra o que ia rodar", "Apenas exibe o que seria executado", "Only show what would run"]),
+    ("config.ajuda.confirmacao.nao_interativo", ["Ninguém responde s/n: a política e o risco decidem (CI)", "Sem confirmação manual: a política e o risco decidem (CI)", "No one answers y/n: policy and risk decide (CI)"]),
+    ("config.ajuda.confirmacao.risco_maximo", ["No não-interativo, até que risco aprova sozinho (baixo, medio, alto)", "No modo não interativo, risco máximo aprovado automaticamente (baixo, medio, alto)", "In non-interactive mode, highest risk approved automatically (baixo, medio, alto)"]),
+    ("config.ajuda.confirmacao.allow_do_projeto", ["'allow' da política do engagement vale (senão vira 'confirm')", "Regras 'allow' da política do engagement são aceitas (caso contrário, viram 'confirm')", "Honor 'allow' rules from the engagement policy (otherwise they become 'confirm')"]),
+    ("config.ajuda.confirmacao.sandbox", ["Testa os comandos na sandbox antes", "Testa os comandos na sandbox antes da execução real", "Try commands in the sandbox first"]),
+    ("config.ajuda.confirmacao.sandbox_rede", ["Rede ligada dentro da sandbox", "Rede habilitada dentro da sandbox", "Network enabled inside the sandbox"]),
+    ("config.ajuda.confirmacao.sandbox_cwd", ["Pasta atual gravável dentro da sandbox", "Pasta atual com escrita dentro da sandbox", "Current directory writable inside the sandbox"]),
+    ("config.ajuda.interface.cor", ["Cores no terminal", "Cores no terminal", "Terminal colours"]),
+    ("config.ajuda.interface.spinner", ["Spinner enquanto espera o Oráculo", "Indicador de progresso enquanto aguarda o Oráculo", "Spinner while waiting for the oracle"]),
+    ("config.ajuda.interface.idioma", ["Idioma das mensagens (pt-br, pt-br-formal, en)", "Idioma das mensagens (pt-br, pt-br-formal, en)", "Message language (pt-br, pt-br-formal, en)"]),
+    ("config.aviso_em", ["Aviso: {} (em '{}')", "Aviso: {} (em '{}')", "Warning: {} (in '{}')"]),
+    ("config.so_do_usuario", ["⚠ ATENÇÃO: '{}' tenta mudar '{}', que só vale na config do usuário, no ambiente ou na linha de comando. IGNORADO.", "⚠ ATENÇÃO: '{}' tenta alterar '{}', que só pode ser definido na configuração do usuário, no ambiente ou na linha de comando. IGNORADO.", "⚠ WARNING: '{}' tries to set '{}', which is only allowed in the user config, the environment or the command line. IGNORED."]),
+    ("config.zoada", ["Aviso: config '{}' tá zoada, ignorando: {}", "Aviso: a configuração '{}' é inválida e foi ignorada: {}", "Warning: config '{}' is invalid, ignoring it: {}"]),
+    ("config.titulo", ["--- CONFIG EFETIVA ---", "--- CONFIGURAÇÃO EFETIVA ---", "--- EFFECTIVE CONFIG ---"]),
+    ("config.arquivos", ["--- ARQUIVOS (o de baixo ganha) ---", "--- ARQUIVOS (o último tem precedência) ---", "--- FILES (the last one wins) ---"]),
+    ("config.carregado", ["carregado", "carregado", "loaded"]),
+    ("config.nao_existe", ["não existe", "não existe", "missing"]),
+    ("config.origem_padrao", ["padrão", "padrão", "default"]),
+    ("config.chave_nao_existe", ["Chave '{}' não existe ('fenrir config show' lista todas)", "A chave '{}' não existe ('fenrir config show' lista todas)", "Key '{}' does not exist ('fenrir config show' lists them all)"]),
+    ("config.tipo_errado", ["'{}' tem que ser {}, veio {}", "'{}' deve ser {}, recebido {}", "'{}' must be {}, got {}"]),
+    ("config.invalido", ["'{}' inválido: {}", "'{}' inválido: {}", "invalid '{}': {}"]),
+    ("config.quer_booleano", ["'{}' quer sim/não, veio '{}'", "'{}' espera sim/não, recebido '{}'", "'{}' expects yes/no, got '{}'"]),
+    ("config.quer_numero", ["'{}' quer número, veio '{}'", "'{}' espera um número, recebido '{}'", "'{}' expects a number, got '{}'"]),
+
+    // main
+    ("main.auto_aprovar_sem_risco", ["Erro: '--auto-aprovar' precisa do risco (baixo, medio ou alto)!", "Erro: '--auto-aprovar' exige o nível de risco (baixo, medio ou alto).", "Error: '--auto-aprovar' needs a risk level (baixo, medio or alto)."]),
+    ("main.set_formato", ["Erro: '--set' tem que ser no formato chave=valor! (ignorei '{}')", "Erro: '--set' deve estar no formato chave=valor (ignorado: '{}').", "Error: '--set' must be key=value (ignored '{}')."]),
+    ("main.set_vazio", ["Erro: '--set' precisa de chave=valor!", "Erro: '--set' exige chave=valor.", "Error: '--set' needs key=value."]),
+    ("main.config_nao_existe", ["Oxe! 'fenrir config {}' não existe. Tenta 'fenrir config show'.", "Erro: 'fenrir config {}' não existe. Use 'fenrir config show'.", "Error: 'fenrir config {}' does not exist. Try 'fenrir config show'."]),
+    ("main.ola", ["Ei, cara! Modo interativo do Fenrir.", "Fenrir: modo interativo.", "Fenrir interactive mode."]),
+    ("main.ola2", ["Manda a braba (ou 'sair' pra vazar).", "Digite a solicitação (ou 'sair' para encerrar).", "Type a request (or 'exit' to quit)."]),
+    ("main.ctrl_c_duplo", ["\n[PARADA] Ctrl-C duplo: PARADA DE EMERGÊNCIA!", "\n[PARADA] Ctrl-C duplo: parada de emergência.", "\n[STOP] Double Ctrl-C: EMERGENCY STOP!"]),
+    ("main.ctrl_c", ["\n(Ctrl-C: passo atual cancelado. De novo rapidinho = PARADA DE EMERGÊNCIA. Pra vazar: 'sair')", "\n(Ctrl-C: etapa atual cancelada. Outro Ctrl-C em seguida aciona a parada de emergência. Para encerrar: 'sair')", "\n(Ctrl-C: current step cancelled. Again quickly = EMERGENCY STOP. To quit: 'exit')"]),
+    ("main.erro_entrada", ["Oxe! Deu erro lendo sua entrada: {}", "Erro ao ler a entrada: {}", "Error reading input: {}"]),
+    ("main.tchau", ["Falou, parceiro! Até a próxima.", "Sessão encerrada.", "Bye!"]),
+    ("main.proxima", ["\nPróxima? (ou 'sair' pra vazar)", "\nPróxima solicitação? (ou 'sair' para encerrar)", "\nNext? (or 'exit' to quit)"]),
+    ("main.variavel_apagada", ["Variável '{}' apagada.", "Variável '{}' removida.", "Variable '{}' removed."]),
+    ("main.variavel_definida", ["Beleza: ${} = {} (fica salva no engagement).", "Definido: ${} = {} (salvo no engagement).", "Set: ${} = {} (saved in the engagement)."]),
+    ("main.config_definida", ["Beleza: {} = {} (só nessa sessão).", "Definido: {} = {} (apenas nesta sessão).", "Set: {} = {} (this session only)."]),
+    ("main.dry_run", ["Dry-run: {}", "Dry-run: {}", "Dry-run: {}"]),
+    ("main.dry_run_ligado", ["LIGADO (nada roda de verdade)", "ativado (nada é executado)", "ON (nothing really runs)"]),
+    ("main.dry_run_desligado", ["desligado", "desativado", "off"]),
+    ("main.de_novo", ["De novo: '{}'", "Repetindo: '{}'", "Again: '{}'"]),
+    ("main.sem_proposta", ["Nenhuma proposta ainda. Manda um pedido primeiro.", "Ainda não há proposta. Envie uma solicitação primeiro.", "No proposal yet. Send a request first."]),
+    ("main.macro_nao_existe", ["Oxe! Macro '{}' não existe. (':macro' lista as que existem)", "Erro: a macro '{}' não existe (':macro' lista as disponíveis).", "Error: macro '{}' does not exist (':macro' lists them)."]),
+    ("main.macro_passo", ["\n=== MACRO '{}': passo {}/{} ===", "\n=== MACRO '{}': etapa {}/{} ===", "\n=== MACRO '{}': step {}/{} ==="]),
+    ("main.macro_recusado", ["O passo {} foi recusado.", "A etapa {} foi recusada.", "Step {} was refused."]),
+    ("main.macro_falhou", ["Xii, o passo {} falhou.", "A etapa {} falhou.", "Step {} failed."]),
+    ("main.macro_continua", ["Segue com os {} passo(s) que faltam mesmo assim? (s/n):", "Prosseguir com a(s) {} etapa(s) restante(s) mesmo assim? (s/n):", "Go on with the {} remaining step(s) anyway? (y/n):"]),
+    ("main.macro_parou", ["=== MACRO '{}' parada no passo {}/{} ===", "=== MACRO '{}' interrompida na etapa {}/{} ===", "=== MACRO '{}' stopped at step {}/{} ==="]),
+    ("main.macro_fim", ["\n=== MACRO '{}' acabou ===", "\n=== MACRO '{}' concluída ===", "\n=== MACRO '{}' done ==="]),
+    ("main.nada_rodou", ["Nada rodou ainda nessa sessão. Roda as tarefas primeiro, depois salva.", "Nenhuma tarefa foi executada nesta sessão. Execute as tarefas antes de salvar.", "Nothing has run in this session yet. Run the tasks first, then save."]),
+    ("main.macro_salva", ["Macro '{}' salva: {}", "Macro '{}' salva: {}", "Macro '{}' saved: {}"]),
+    ("main.macro_parametros", ["Parâmetros (vieram das variáveis): {}", "Parâmetros (derivados das variáveis): {}", "Parameters (from the session variables): {}"]),
+    ("main.macro_como_rodar", ["Roda com ':macro {} ...' ou 'fenrir macro {} ...'.", "Execute com ':macro {} ...' ou 'fenrir macro {} ...'.", "Run it with ':macro {} ...' or 'fenrir macro {} ...'."]),
+    ("main.macro_nao_salvou", ["Xii, não deu pra salvar a macro: {}", "Não foi possível salvar a macro: {}", "Could not save the macro: {}"]),
+    ("main.ultima_titulo", ["--- A ÚLTIMA PROPOSTA ---", "--- ÚLTIMA PROPOSTA ---", "--- LAST PROPOSAL ---"]),
+    ("main.pedido", ["Pedido: '{}'", "Solicitação: '{}'", "Request: '{}'"]),
+    ("main.entendeu", ["O Oráculo entendeu: '{}' (tipo '{}')", "Interpretação do Oráculo: '{}' (tipo '{}')", "The oracle understood: '{}' (type '{}')"]),
+    ("main.comando", ["Comando: '{}'", "Comando: '{}'", "Command: '{}'"]),
+    ("main.arquivo", ["Arquivo: '{}'", "Arquivo: '{}'", "File: '{}'"]),
+    ("main.args", ["Args: {}", "Argumentos: {}", "Args: {}"]),
+    ("main.risco", ["Risco: {}", "Risco: {}", "Risk: {}"]),
+    ("main.mexe_arquivo", ["  (mexe em arquivo do projeto: o ':undo' desfaz)", "  (altera arquivos do projeto: reversível com ':undo')", "  (touches project files: ':undo' reverts it)"]),
+    ("main.politica", ["Política: {} ({}): {}", "Política: {} ({}): {}", "Policy: {} ({}): {}"]),
+    ("main.limites", ["Limites: {}", "Limites: {}", "Limits: {}"]),
+    ("main.limites_nenhum", ["nenhum", "nenhum", "none"]),
+    ("main.escopo_indefinido", ["Escopo: não definido", "Escopo: não definido", "Scope: not set"]),
+    ("main.escopo_dentro", ["Escopo: tudo dentro", "Escopo: todos os alvos dentro", "Scope: everything inside"]),
+    ("main.escopo_fora", ["Escopo: FORA -> {}", "Escopo: FORA -> {}", "Scope: OUTSIDE -> {}"]),
+    ("main.separador_curto", ["-------------------------", "-------------------------", "-------------------------"]),
+    ("main.spinner", ["VAI|CORNO!|PENSE|DESGRAÇA!|...|VAI|LOGO|CARALHO!|(ノ°Д°）ノ|┻━┻|...|VAI|CORNO!", "⠋|⠙|⠹|⠸|⠼|⠴|⠦|⠧|⠇|⠏", "⠋|⠙|⠹|⠸|⠼|⠴|⠦|⠧|⠇|⠏"]),
+    ("main.chamando", ["Chamando o Oráculo (Gemini)...", "Consultando o Oráculo...", "Asking the oracle..."]),
+    ("main.respondeu", ["! Oráculo respondeu!", "Resposta recebida.", "Oracle answered."]),
+    ("main.cancelado", ["! Cancelado.", "Cancelado.", "Cancelled."]),
+    ("main.esquece", ["Beleza, esquece essa. Nada rodou.", "Solicitação cancelada. Nada foi executado.", "Okay, dropped. Nothing ran."]),
+    ("main.deu_ruim", ["! DEU RUIM!", "Falha.", "Failed."]),
+    ("main.erro_oraculo", ["Ops! Deu ruim na comunicação com o Oráculo: {}", "Falha na comunicação com o Oráculo: {}", "Oops, talking to the oracle failed: {}"]),
+    ("main.erro_log_tarefa", ["Xii, deu erro pra logar a tarefa: {}", "Falha ao registrar a tarefa no log: {}", "Could not log the task: {}"]),
+    ("main.limites_ajuste", ["Limites: {}  (muda no 's': ex 's timeout=600 memoria=off')", "Limites: {}  (ajustáveis na confirmação, ex.: 's timeout=600 memoria=off')", "Limits: {}  (change them when confirming, e.g. 'y timeout=600 memoria=off')"]),
+    ("main.fora_escopo", ["FORA DO ESCOPO: {}  (':scope' mostra o escopo)", "FORA DO ESCOPO: {}  (':scope' exibe o escopo)", "OUT OF SCOPE: {}  (':scope' shows the scope)"]),
+    ("main.dry_run_aviso", ["[DRY-RUN] Nada vai rodar de verdade.", "[DRY-RUN] Nada será executado.", "[DRY-RUN] Nothing will really run."]),
+    ("main.limites_ajustados", ["Limites ajustados: {}", "Limites ajustados: {}", "Limits adjusted: {}"]),
+    ("main.erro_nada_rodou", ["Erro: {} (nada rodou)", "Erro: {} (nada foi executado)", "Error: {} (nothing ran)"]),
+    ("main.segurando", ["Ok, segurando o volante...", "Executando...", "Okay, taking the wheel..."]),
+    ("main.sandbox_limpo", ["Rodou limpo na sandbox. Promove pra execução REAL? (s/n):", "Execução na sandbox sem erros. Executar de verdade? (s/n):", "Ran clean in the sandbox. Promote to a REAL run? (y/n):"]),
+    ("main.sandbox_ruim", ["Na sandbox deu ruim. Promove pra execução REAL mesmo assim? (s/n):", "A execução na sandbox falhou. Executar de verdade mesmo assim? (s/n):", "It failed in the sandbox. Promote to a REAL run anyway? (y/n):"]),
+    ("main.so_teste", ["Ficou só no teste. Nada rodou de verdade.", "Somente o teste foi executado. Nada rodou de verdade.", "Test only. Nothing really ran."]),
+    ("main.sem_comando", ["Erro: Oráculo mandou 'execute_command' mas não mandou o comando!", "Erro: o Oráculo retornou 'execute_command' sem o comando.", "Error: the oracle sent 'execute_command' without the command!"]),
+    ("main.sem_arquivo", ["Erro: Oráculo mandou '{}' mas faltou o arquivo!", "Erro: o Oráculo retornou '{}' sem o arquivo.", "Error: the oracle sent '{}' without the file!"]),
+    ("main.dry_run_navegar", ["[DRY-RUN] Ia entrar em '{}'.", "[DRY-RUN] Entraria em '{}'.", "[DRY-RUN] Would enter '{}'."]),
+    ("main.agora_em", ["Agora em '{}'.", "Pasta atual: '{}'.", "Now in '{}'."]),
+    ("main.nao_entendeu", ["O Oráculo não entendeu o que fazer. (Disse: '{}')", "O Oráculo não identificou uma ação. (Resposta: '{}')", "The oracle did not understand what to do. (It said: '{}')"]),
+    ("main.sabonetou", ["Ação cancelada. Sabonetou!", "Ação cancelada.", "Cancelled."]),
+    ("main.file_vazio", ["Erro: '--file' precisa do caminho do arquivo!", "Erro: '--file' exige o caminho do arquivo.", "Error: '--file' needs a file path!"]),
+    ("main.analise_titulo", ["\n--- ANÁLISE: {} ---", "\n--- ANÁLISE: {} ---", "\n--- ANALYSIS: {} ---"]),
+    ("main.analise_vazia", ["Xii, o Oráculo disse que era análise ('{}') mas não mandou a análise.", "O Oráculo indicou uma análise ('{}'), mas não a enviou.", "The oracle said it was an analysis ('{}') but sent no analysis."]),
+
+    // executor
+    ("executor.pergunta_escopo", ["Alvo FORA do escopo do engagement. Segue mesmo assim? (s/n):", "Alvo fora do escopo do engagement. Deseja prosseguir? (s/n):", "Target is OUTSIDE the engagement scope. Go ahead anyway? (y/n):"]),
+    ("executor.erro_log_escopo", ["Xii, deu erro pra logar a decisão do escopo: {}", "Falha ao registrar a decisão de escopo: {}", "Could not log the scope decision: {}"]),
+    ("executor.cancelado_escopo", ["Ação cancelada. Fora do escopo não rola.", "Ação cancelada: alvo fora do escopo.", "Cancelled. Out of scope is a no-go."]),
+    ("executor.dry_run_titulo", ["\n--- DRY-RUN (nada foi executado) ---", "\n--- DRY-RUN (nada foi executado) ---", "\n--- DRY-RUN (nothing was executed) ---"]),
+    ("executor.dry_run_env", ["env:  (herdado do Fenrir)", "env:  (herdado do Fenrir)", "env:  (inherited from Fenrir)"]),
+    ("executor.dry_run_env_mais", ["env:  (herdado do Fenrir) + {}", "env:  (herdado do Fenrir) + {}", "env:  (inherited from Fenrir) + {}"]),
+    ("executor.dry_run_saida", ["saída planejada: {}", "saída planejada: {}", "planned output: {}"]),
+    ("executor.separador_longo", ["-------------------------------------", "-------------------------------------", "-------------------------------------"]),
+    ("executor.erro_log_dry_run", ["Xii, deu erro pra logar o dry-run: {}", "Falha ao registrar o dry-run no log: {}", "Could not log the dry-run: {}"]),
+    ("executor.ninguem_responde", [" [NÃO-INTERATIVO] Ninguém pra responder, fica o não.", " [NÃO-INTERATIVO] Sem confirmação manual: resposta negativa.", " [NON-INTERACTIVE] No one to answer, it's a no."]),
+    ("executor.sem_terminal", ["\nAviso: sem terminal pra perguntar (stdin não é um), fica o não. Pra rodar sem ninguém olhando: '--nao-interativo' ou '--auto-aprovar <risco>'.", "\nAviso: não há terminal para a confirmação (stdin não é um terminal); resposta negativa. Para execução sem supervisão: '--nao-interativo' ou '--auto-aprovar <risco>'.", "\nWarning: no terminal to ask on (stdin is not one), so it's a no. To run unattended: '--nao-interativo' or '--auto-aprovar <risk>'."]),
+    ("executor.passo_automatico", ["{} [NÃO-INTERATIVO] s (tarefa já aprovada)", "{} [NÃO-INTERATIVO] s (tarefa já aprovada)", "{} [NON-INTERACTIVE] y (task already approved)"]),
+    ("executor.passo_recusado", ["{} [NÃO-INTERATIVO] n", "{} [NÃO-INTERATIVO] n", "{} [NON-INTERACTIVE] n"]),
+    ("executor.erro_log_auto", ["Xii, deu erro pra logar a aprovação automática: {}", "Falha ao registrar a aprovação automática: {}", "Could not log the automatic approval: {}"]),
+    ("executor.digite_frase", ["Digite exatamente \"{}\" pra seguir: ", "Digite exatamente \"{}\" para prosseguir: ", "Type exactly \"{}\" to go on: "]),
+    ("executor.barrado", ["BARRADO pela política ({}): {}", "Bloqueado pela política ({}): {}", "BLOCKED by policy ({}): {}"]),
+    ("executor.liberado", ["Liberado pela política ({}): {}", "Liberado pela política ({}): {}", "Allowed by policy ({}): {}"]),
+    ("executor.auto_aprovado", ["[NÃO-INTERATIVO] Aprovado sozinho: risco {} (até {} passa).", "[NÃO-INTERATIVO] Aprovado automaticamente: risco {} (limite: {}).", "[NON-INTERACTIVE] Auto-approved: risk {} (up to {} passes)."]),
+    ("executor.liberado_acima", ["[NÃO-INTERATIVO] A regra ({}) libera, mas o risco {} passa do máximo ({}). Recusado — roda na mão.", "[NÃO-INTERATIVO] A regra ({}) permite, mas o risco {} excede o limite ({}). Recusado — execute manualmente.", "[NON-INTERACTIVE] Rule ({}) allows it, but risk {} is above the maximum ({}). Refused — run it by hand."]),
+    ("executor.auto_recusado", ["[NÃO-INTERATIVO] Recusado: risco {} passa do máximo ({}). Roda na mão ou sobe o '--auto-aprovar'.", "[NÃO-INTERATIVO] Recusado: risco {} acima do limite ({}). Execute manualmente ou ajuste '--auto-aprovar'.", "[NON-INTERACTIVE] Refused: risk {} is above the maximum ({}). Run it by hand or raise '--auto-aprovar'."]),
+    ("executor.auto_frase", ["[NÃO-INTERATIVO] Recusado: a política pede a frase ({}) e não tem ninguém pra digitar.", "[NÃO-INTERATIVO] Recusado: a política exige a frase de confirmação ({}) e não há operador.", "[NON-INTERACTIVE] Refused: the policy asks for the phrase ({}) and no one is there to type it."]),
+    ("executor.executa", ["Executa essa porra? (s/n):", "Confirma a execução? (s/n):", "Run it? (y/n):"]),
+    ("executor.confirmacao_forte", ["A política pede confirmação forte ({}): {}", "A política exige confirmação reforçada ({}): {}", "The policy asks for strong confirmation ({}): {}"]),
+    ("executor.erro_log_politica", ["Xii, deu erro pra logar a decisão da política: {}", "Falha ao registrar a decisão da política: {}", "Could not log the policy decision: {}"]),
+    ("executor.sem_cmd", ["Erro: Oráculo mandou 'command' mas não mandou o JSON de 'cmd'!", "Erro: o Oráculo retornou 'command' sem o JSON de 'cmd'.", "Error: the oracle sent 'command' without the 'cmd' JSON!"]),
+    ("executor.pty_dry_run", ["[PTY] Esse roda num terminal próprio (com transcrição).", "[PTY] Este comando roda em um terminal próprio (com transcrição).", "[PTY] This one runs in its own terminal (with a transcript)."]),
+    ("executor.rodando", ["Rodando: '{}'...", "Executando: '{}'...", "Running: '{}'..."]),
+    ("executor.erro_spawn", ["Oxe! Deu erro ao TENTAR rodar o comando: {}", "Erro ao iniciar o comando: {}", "Error trying to run the command: {}"]),
+    ("executor.terminou", ["Comando terminou.", "Comando concluído.", "Command finished."]),
+    ("executor.saiu_com_erro", ["Xii, o comando saiu com erro ({}).", "O comando terminou com erro ({}).", "The command exited with an error ({})."]),
+    ("executor.perdi_de_vista", ["Oxe! Perdi o comando de vista: {}", "Erro: o acompanhamento do comando falhou: {}", "Error: lost track of the command: {}"]),
+    ("executor.erro_log_sessao", ["Xii, deu erro pra logar a sessão: {}", "Falha ao registrar a sessão: {}", "Could not log the session: {}"]),
+    ("executor.pty_capotou", ["Oxe! A sessão no PTY capotou: {}", "Erro: a sessão no PTY falhou: {}", "Error: the PTY session crashed: {}"]),
+    ("executor.testando_sandbox", ["Testando na sandbox: '{}'...", "Testando na sandbox: '{}'...", "Trying it in the sandbox: '{}'..."]),
+    ("executor.editor_sem_args", ["Erro: Oráculo mandou 'open_editor' mas não mandou os ARGS!", "Erro: o Oráculo retornou 'open_editor' sem os ARGS.", "Error: the oracle sent 'open_editor' without ARGS!"]),
+    ("executor.editor_sem_path", ["Erro: Oráculo mandou 'open_editor' mas faltou o 'path' nos ARGS!", "Erro: o Oráculo retornou 'open_editor' sem o 'path' nos ARGS.", "Error: the oracle sent 'open_editor' without 'path' in ARGS!"]),
+    ("executor.abrindo_editor", ["Tentando abrir '{}' no '{}'...", "Abrindo '{}' em '{}'...", "Trying to open '{}' in '{}'..."]),
+    ("executor.editor_fechado", ["Editor fechado.", "Editor fechado.", "Editor closed."]),
+    ("executor.editor_erro", ["Xii, o editor saiu com erro ({}).", "O editor terminou com erro ({}).", "The editor exited with an error ({})."]),
+    ("executor.editor_falhou", ["Oxe! Deu erro ao TENTAR abrir o editor: {}", "Erro ao abrir o editor: {}", "Error trying to open the editor: {}"]),
+    ("executor.editor_aberto", ["Editor aberto.", "Editor aberto.", "Editor opened."]),
+
+    // comandos (e a ajuda de cada ':comando' e o que mora no engagement)
+    ("comandos.nao_existe", ["Comando '{}' não existe. ':help' mostra os que existem.", "O comando '{}' não existe. ':help' lista os disponíveis.", "Command '{}' does not exist. ':help' shows the ones that do."]),
+    ("comandos.ajuda_titulo", ["--- COMANDOS DO REPL (o resto vai pro Oráculo) ---", "--- COMANDOS DO REPL (o restante é enviado ao Oráculo) ---", "--- REPL COMMANDS (everything else goes to the oracle) ---"]),
+    ("comandos.apelidos", [" (ou {})", " (ou {})", " (or {})"]),
+    ("comandos.ajuda_rodape", ["---------------------------------------------------", "---------------------------------------------------", "---------------------------------------------------"]),
+    ("comandos.uso", ["Uso: {}", "Uso: {}", "Usage: {}"]),
+    ("comandos.sem_jobs", ["Nenhum job do Fenrir rodando.", "Nenhum job do Fenrir em execução.", "No Fenrir jobs running."]),
+    ("comandos.job_daqui", ["daqui", "desta sessão", "this one"]),
+    ("comandos.job_de", ["do PID {}", "do PID {}", "PID {}'s"]),
+    ("comandos.job", ["  [{}] {} (desde {}, {})", "  [{}] {} (desde {}, {})", "  [{}] {} (since {}, {})"]),
+    ("comandos.modelo_padrao", ["(o padrão do CLI)", "(padrão do CLI)", "(the CLI default)"]),
+    ("comandos.backend", ["Oráculo: '{}' | modelo: {} | timeout: {}s", "Oráculo: '{}' | modelo: {} | timeout: {}s", "Oracle: '{}' | model: {} | timeout: {}s"]),
+    ("comandos.erro_salvar_escopo", ["Xii, não deu pra salvar o escopo: {}", "Não foi possível salvar o escopo: {}", "Could not save the scope: {}"]),
+    ("comandos.escopo_vazio", ["Escopo: não definido (nada é barrado). Ex: ':scope add 10.10.0.0/24 cliente.com.br'", "Escopo: não definido (nenhum alvo é bloqueado). Ex.: ':scope add 10.10.0.0/24 cliente.com.br'", "Scope: not set (nothing is blocked). E.g. ':scope add 10.10.0.0/24 client.com'"]),
+    ("comandos.escopo", ["Escopo ('{}'):", "Escopo ('{}'):", "Scope ('{}'):"]),
+    ("comandos.engagement_titulo", ["--- ENGAGEMENT ---", "--- ENGAGEMENT ---", "--- ENGAGEMENT ---"]),
+    ("comandos.pasta", ["Pasta: {}", "Pasta: {}", "Directory: {}"]),
+    ("comandos.log_saidas", ["Log: {} | Saídas: {}", "Log: {} | Saídas: {}", "Log: {} | Output: {}"]),
+    ("comandos.escopo_jobs", ["Escopo: {} alvo(s) | Jobs rodando: {}", "Escopo: {} alvo(s) | Jobs em execução: {}", "Scope: {} target(s) | Jobs running: {}"]),
+    ("comandos.engagement_dica", ["(O engagement é a pasta onde o Fenrir abriu. Pra trocar, sai e abre na pasta do outro.)", "(O engagement é a pasta onde o Fenrir foi iniciado. Para trocar, encerre e inicie na pasta do outro.)", "(The engagement is the directory Fenrir started in. To switch, quit and start it in the other one.)"]),
+    ("comandos.engagement_rodape", ["------------------", "------------------", "------------------"]),
+    ("comandos.nada_rodou", ["Nada rodou ainda nessa sessão.", "Nada foi executado nesta sessão.", "Nothing has run in this session yet."]),
+    ("comandos.ultima_titulo", ["--- ÚLTIMA SAÍDA: '{}' ({}) ---", "--- ÚLTIMA SAÍDA: '{}' ({}) ---", "--- LAST OUTPUT: '{}' ({}) ---"]),
+    ("comandos.foi_direto", ["(a saída foi direto pro terminal, sem passar pelo Fenrir)", "(a saída foi enviada diretamente ao terminal e não foi registrada)", "(the output went straight to the terminal, not through Fenrir)"]),
+    ("comandos.nao_cuspiu", ["(não cuspiu nada)", "(sem saída)", "(no output)"]),
+    ("comandos.saida_completa", ["(Saída completa em: {})", "(Saída completa em: {})", "(Full output in: {})"]),
+    ("cmd.help", ["Essa lista aqui", "Esta lista", "This list"]),
+    ("cmd.history", ["As últimas n entradas (padrão 20)", "As últimas n entradas (padrão: 20)", "The last n entries (default 20)"]),
+    ("cmd.jobs", ["Jobs do Fenrir rodando agora (de qualquer terminal)", "Jobs do Fenrir em execução (de qualquer terminal)", "Fenrir jobs running now (from any terminal)"]),
+    ("cmd.stop-all", ["PARADA DE EMERGÊNCIA: derruba todos os jobs", "Parada de emergência: encerra todos os jobs", "EMERGENCY STOP: kills every job"]),
+    ("cmd.set", ["Config só nessa sessão (chave com ponto) ou variável $NOME (sem nada: mostra tudo)", "Configuração apenas nesta sessão (chave com ponto) ou variável $NOME (sem argumentos: exibe tudo)", "Config for this session only (key with a dot) or a $NAME variable (no args: show everything)"]),
+    ("cmd.backend", ["Mostra ou troca o CLI do Oráculo (e o modelo)", "Exibe ou altera o CLI do Oráculo (e o modelo)", "Show or switch the oracle CLI (and model)"]),
+    ("cmd.scope", ["Mostra ou edita o escopo (alvo fora dele pede confirmação)", "Exibe ou edita o escopo (alvos fora dele exigem confirmação)", "Show or edit the scope (targets outside it need confirmation)"]),
+    ("cmd.engagement", ["Pasta do engagement e os arquivos dele", "Pasta do engagement e seus arquivos", "The engagement directory and its files"]),
+    ("cmd.dry-run", ["Liga/desliga o dry-run (sem nada: mostra)", "Ativa/desativa o dry-run (sem argumentos: exibe)", "Turn dry-run on/off (no args: show)"]),
+    ("cmd.last", ["A saída do último comando", "Saída do último comando", "Output of the last command"]),
+    ("cmd.retry", ["Roda a última proposta de novo (passa pela política de novo)", "Executa novamente a última proposta (passando outra vez pela política)", "Run the last proposal again (through the policy again)"]),
+    ("cmd.explain", ["Destrincha a última proposta: risco, política, limites e escopo", "Detalha a última proposta: risco, política, limites e escopo", "Break down the last proposal: risk, policy, limits and scope"]),
+    ("cmd.macro", ["Roda uma macro (sem nada: lista as macros)", "Executa uma macro (sem argumentos: lista as macros)", "Run a macro (no args: list the macros)"]),
+    ("cmd.save-macro", ["Salva as últimas n tarefas aprovadas como macro (sem n: todas)", "Salva as últimas n tarefas aprovadas como macro (sem n: todas)", "Save the last n approved tasks as a macro (no n: all of them)"]),
+    ("cmd.undo", ["Desfaz a última tarefa que mexeu em arquivo", "Desfaz a última tarefa que alterou arquivos", "Undo the last task that changed files"]),
+    ("cmd.sair", ["Vaza", "Encerra o Fenrir", "Quit"]),
+    ("engagement.config", ["config", "configuração", "config"]),
+    ("engagement.politica", ["política", "política", "policy"]),
+    ("engagement.limites", ["limites", "limites", "limits"]),
+    ("engagement.escopo", ["escopo", "escopo", "scope"]),
+    ("engagement.variaveis", ["variáveis ($TARGET...)", "variáveis ($TARGET...)", "variables ($TARGET...)"]),
+    ("engagement.macros", ["macros", "macros", "macros"]),
+    ("engagement.segredos", ["padrões de segredo", "padrões de segredo", "secret patterns"]),
+    ("engagement.mapa_segredos", ["mapa dos segredos", "mapa dos segredos", "secret map"]),
+    ("engagement.historico", ["histórico do REPL", "histórico do REPL", "REPL history"]),
+    ("engagement.undo", ["diários do undo", "registros do undo", "undo journals"]),
+
+    // arquivos
+    ("arquivos.sem_args", ["Erro: Oráculo mandou '{}' mas não mandou os ARGS!", "Erro: o Oráculo retornou '{}' sem os ARGS.", "Error: the oracle sent '{}' without ARGS!"]),
+    ("arquivos.so_tem", ["Xii, '{}' só tem {} linhas.", "'{}' tem apenas {} linhas.", "'{}' only has {} lines."]),
+    ("arquivos.trecho", ["\n--- {} (linhas {}-{} de {}) ---", "\n--- {} (linhas {}-{} de {}) ---", "\n--- {} (lines {}-{} of {}) ---"]),
+    ("arquivos.grandao", ["(Arquivo grandão: só os primeiros {} bytes vão pro Oráculo.)", "(Arquivo extenso: apenas os primeiros {} bytes serão enviados ao Oráculo.)", "(Big file: only the first {} bytes go to the oracle.)"]),
+    ("arquivos.pedindo_resumo", ["Pedindo o resumo pro Oráculo...", "Solicitando o resumo ao Oráculo...", "Asking the oracle for a summary..."]),
+    ("arquivos.resumo", ["\n--- RESUMO DO ORÁCULO ---\n{}\n-----------------------------", "\n--- RESUMO DO ORÁCULO ---\n{}\n-----------------------------", "\n--- ORACLE SUMMARY ---\n{}\n-----------------------------"]),
+    ("arquivos.erro_resumo", ["Ops! Deu ruim no resumo: {}", "Falha ao gerar o resumo: {}", "Oops, the summary failed: {}"]),
+    ("arquivos.grande_demais", ["Xii, '{}' tem {} bytes. Acima de {} o Oráculo não edita (abre no editor, vai).", "'{}' tem {} bytes. Acima de {} o Oráculo não edita; use o editor.", "'{}' is {} bytes. Above {} the oracle won't edit it (open it in the editor)."]),
+    ("arquivos.pedindo_diff", ["Pedindo o diff pro Oráculo...", "Solicitando o diff ao Oráculo...", "Asking the oracle for a diff..."]),
+    ("arquivos.erro_diff", ["Ops! Deu ruim pedindo o diff: {}", "Falha ao solicitar o diff: {}", "Oops, asking for the diff failed: {}"]),
+    ("arquivos.diff_titulo", ["\n--- DIFF PROPOSTO ({}) ---", "\n--- DIFF PROPOSTO ({}) ---", "\n--- PROPOSED DIFF ({}) ---"]),
+    ("arquivos.nao_aplica", ["Xii, esse diff NÃO aplica limpo no '{}': {}", "O diff não se aplica corretamente em '{}': {}", "This diff does NOT apply cleanly to '{}': {}"]),
+    ("arquivos.nada_escrito", ["Nada foi escrito.", "Nada foi gravado.", "Nothing was written."]),
+    ("arquivos.nao_muda", ["O diff não muda nada. Deixa quieto.", "O diff não altera nada.", "The diff changes nothing. Leaving it alone."]),
+    ("arquivos.dry_run", ["[DRY-RUN] O diff aplica limpo, mas nada foi escrito.", "[DRY-RUN] O diff se aplica corretamente, mas nada foi gravado.", "[DRY-RUN] The diff applies cleanly, but nothing was written."]),
+    ("arquivos.aplica", ["Aplica esse diff? (s/n):", "Aplicar este diff? (s/n):", "Apply this diff? (y/n):"]),
+    ("arquivos.intocado", ["Beleza, arquivo intocado.", "Arquivo mantido sem alterações.", "Okay, file untouched."]),
+    ("arquivos.sem_undo", ["Erro: {} (sem undo, sem edição)", "Erro: {} (sem undo não há edição)", "Error: {} (no undo, no edit)"]),
+    ("arquivos.feito", ["Feito! '{}' editado.", "'{}' editado.", "Done! '{}' edited."]),
+    ("arquivos.erro_log_edicao", ["Xii, deu erro pra logar a edição: {}", "Falha ao registrar a edição: {}", "Could not log the edit: {}"]),
+    ("arquivos.erro_escrever", ["Oxe! Deu erro ao escrever '{}': {}", "Erro ao gravar '{}': {}", "Error writing '{}': {}"]),
+    ("arquivos.nao_leu", ["Não deu pra ler '{}': {}", "Não foi possível ler '{}': {}", "Could not read '{}': {}"]),
+    ("arquivos.nao_texto", ["'{}' não é texto (UTF-8). Aqui não rola.", "'{}' não é texto (UTF-8).", "'{}' is not text (UTF-8). Can't do it here."]),
+
+    // desfazer
+    ("desfazer.sem_undo", ["Aviso: sem undo pra essa tarefa: {}", "Aviso: esta tarefa não terá undo: {}", "Warning: no undo for this task: {}"]),
+    ("desfazer.arquivos_demais", ["{} arquivos é arquivo demais pra foto (máx {}).", "{} arquivos excedem o limite do registro (máx. {}).", "{} files is too many for a snapshot (max {})."]),
+    ("desfazer.grande_demais", ["{} MB é muita coisa pra foto (máx {} MB).", "{} MB excedem o limite do registro (máx. {} MB).", "{} MB is too much for a snapshot (max {} MB)."]),
+    ("desfazer.falha_copiar", ["Falha ao copiar '{}': {}", "Falha ao copiar '{}': {}", "Failed to copy '{}': {}"]),
+    ("desfazer.foto", ["[UNDO] Foto de {} arquivo(s) guardada. ('fenrir undo {}' desfaz)", "[UNDO] Cópia de {} arquivo(s) registrada. ('fenrir undo {}' desfaz)", "[UNDO] Snapshot of {} file(s) saved. ('fenrir undo {}' reverts it)"]),
+    ("desfazer.nao_fechou", ["Aviso: não deu pra fechar o diário de undo: {}", "Aviso: não foi possível fechar o registro de undo: {}", "Warning: could not close the undo journal: {}"]),
+    ("desfazer.nada", ["Nada pra desfazer por aqui.", "Não há nada para desfazer.", "Nothing to undo here."]),
+    ("desfazer.nenhum_diario", ["Nenhum diário de undo ainda.", "Nenhum registro de undo até o momento.", "No undo journals yet."]),
+    ("desfazer.desfeito", [" (desfeito)", " (desfeito)", " (undone)"]),
+    ("desfazer.ja_desfeita", ["A tarefa {} já foi desfeita.", "A tarefa {} já foi desfeita.", "Task {} was already undone."]),
+    ("desfazer.desfazendo", ["Desfazendo a tarefa {} ({}): {}", "Desfazendo a tarefa {} ({}): {}", "Undoing task {} ({}): {}"]),
+    ("desfazer.mudaram", ["Xii, esses arquivos mudaram DEPOIS da tarefa:", "Os arquivos abaixo foram alterados depois da tarefa:", "These files changed AFTER the task:"]),
+    ("desfazer.restaura_tambem", ["Restaura esses também (perde o que mudou)? (s/n):", "Restaurar também estes arquivos (as alterações posteriores serão perdidas)? (s/n):", "Restore these too (losing what changed)? (y/n):"]),
+    ("desfazer.restaurados", ["Restaurados: {}", "Restaurados: {}", "Restored: {}"]),
+    ("desfazer.pulado", ["  - {} (mudou depois, deixei quieto)", "  - {} (alterado depois; mantido)", "  - {} (changed later, left alone)"]),
+    ("desfazer.pasta_ficou", ["  - {} (pasta criada pela tarefa, mas tem coisa dentro: deixei)", "  - {} (diretório criado pela tarefa, mas não está vazio; mantido)", "  - {} (folder created by the task, but not empty: left alone)"]),
+    ("desfazer.erro_log", ["Xii, deu erro pra logar o undo: {}", "Falha ao registrar o undo: {}", "Could not log the undo: {}"]),
+    ("desfazer.nao_tem", ["Não tem diário de undo com o id '{}'. ('fenrir undo lista')", "Não existe registro de undo com o id '{}'. ('fenrir undo lista')", "There is no undo journal with id '{}'. ('fenrir undo list')"]),
+    ("desfazer.id_invalido", ["Id de undo esquisito: '{}'. (só letra, número, '-' e '_')", "Id de undo inválido: '{}'. (apenas letras, números, '-' e '_')", "Invalid undo id: '{}'. (only letters, digits, '-' and '_')"]),
+    ("desfazer.zoado", ["Diário '{}' tá zoado: {}", "O registro '{}' está corrompido: {}", "Journal '{}' is broken: {}"]),
+
+    // fila
+    ("fila.nao_sabe", ["Ferramenta '{}' não sabe rodar em fila.", "A ferramenta '{}' não suporta execução em fila.", "Tool '{}' can't run in a queue."]),
+    ("fila.args_objeto", ["Os ARGS da fila têm que ser um objeto JSON.", "Os ARGS da fila devem ser um objeto JSON.", "Queue ARGS must be a JSON object."]),
+    ("fila.na_fila", ["na fila...", "na fila...", "queued..."]),
+    ("fila.explicacao", ["Fila de {}", "Fila de {}", "{} queue"]),
+    ("fila.avaliando", ["\n[{}/{}] {}", "\n[{}/{}] {}", "\n[{}/{}] {}"]),
+    ("fila.dry_run_politica", ["[DRY-RUN] Política: '{}' ({}): {}", "[DRY-RUN] Política: '{}' ({}): {}", "[DRY-RUN] Policy: '{}' ({}): {}"]),
+    ("fila.recusado", ["recusado (política ou Freio de Mão)", "recusado (política ou confirmação)", "refused (policy or confirmation)"]),
+    ("fila.fora_escopo", ["fora do escopo", "fora do escopo", "out of scope"]),
+    ("fila.ok", ["OK", "OK", "OK"]),
+    ("fila.falhou", ["FALHOU", "FALHOU", "FAILED"]),
+    ("fila.nem_rodou", ["NEM RODOU", "NÃO EXECUTOU", "NOT RUN"]),
+    ("fila.capotou", ["Xii, uma tarefa da fila capotou: {}", "Uma tarefa da fila foi interrompida: {}", "A queue task crashed: {}"]),
+    ("fila.concluida", ["fila concluída!", "fila concluída.", "queue done!"]),
+    ("fila.resumo", ["\n--- RESUMO DA FILA ({} de {} OK) ---", "\n--- RESUMO DA FILA ({} de {} OK) ---", "\n--- QUEUE SUMMARY ({} of {} OK) ---"]),
+    ("fila.saida_em", ["saída em {}", "saída em {}", "output in {}"]),
+    ("fila.nada_executado", ["nada foi executado", "nada foi executado", "nothing was executed"]),
+    ("fila.linha", ["[{}] {} ({}s, {} achado(s)) {}", "[{}] {} ({}s, {} achado(s)) {}", "[{}] {} ({}s, {} finding(s)) {}"]),
+    ("fila.uso", ["Uso: fenrir fila <ferramenta> <alvo>... [--alvos arquivo] [--paralelo N] [--intervalo SEG] [--flag FLAG] [--arg chave=valor]", "Uso: fenrir fila <ferramenta> <alvo>... [--alvos arquivo] [--paralelo N] [--intervalo SEG] [--flag FLAG] [--arg chave=valor]", "Usage: fenrir fila <tool> <target>... [--alvos file] [--paralelo N] [--intervalo SECS] [--flag FLAG] [--arg key=value]"]),
+    ("fila.paralelo", ["Erro: '--paralelo' precisa de um número maior que zero!", "Erro: '--paralelo' exige um número maior que zero.", "Error: '--paralelo' needs a number greater than zero!"]),
+    ("fila.intervalo", ["Erro: '--intervalo' precisa de um número de segundos!", "Erro: '--intervalo' exige um número de segundos.", "Error: '--intervalo' needs a number of seconds!"]),
+    ("fila.alvos", ["Erro: '--alvos' precisa do caminho do arquivo!", "Erro: '--alvos' exige o caminho do arquivo.", "Error: '--alvos' needs a file path!"]),
+    ("fila.flag", ["Erro: '--flag' precisa de um valor!", "Erro: '--flag' exige um valor.", "Error: '--flag' needs a value!"]),
+    ("fila.arg", ["Erro: '--arg' tem que ser no formato chave=valor!", "Erro: '--arg' deve estar no formato chave=valor.", "Error: '--arg' must be in key=value form!"]),
+    ("fila.sem_alvos", ["Erro: cadê os alvos? Passa na linha de comando ou com '--alvos arquivo'.", "Erro: nenhum alvo informado. Informe na linha de comando ou com '--alvos arquivo'.", "Error: no targets? Pass them on the command line or with '--alvos file'."]),
+    ("fila.inicio", ["Fila de {} com {} alvo(s), até {} por vez.", "Fila de {} com {} alvo(s), até {} por vez.", "{} queue with {} target(s), up to {} at a time."]),
+
+    // macros
+    ("macros.zoadas", ["Aviso: macros '{}' tão zoadas, ignorando: {}", "Aviso: as macros em '{}' são inválidas e serão ignoradas: {}", "Warning: macros in '{}' are broken, ignoring: {}"]),
+    ("macros.nenhuma", ["Nenhuma macro. Roda umas tarefas e salva com ':save-macro <nome>' (ou escreve em '{}').", "Nenhuma macro cadastrada. Execute algumas tarefas e salve com ':save-macro <nome>' (ou edite '{}').", "No macros. Run some tasks and save them with ':save-macro <name>' (or write them in '{}')."]),
+    ("macros.titulo", ["--- MACROS ---", "--- MACROS ---", "--- MACROS ---"]),
+    ("macros.sem_descricao", ["(sem descrição)", "(sem descrição)", "(no description)"]),
+    ("macros.linha", ["{}{}  {} [{} passo(s), {}]", "{}{}  {} [{} passo(s), {}]", "{}{}  {} [{} step(s), {}]"]),
+    ("macros.arquivo_zoado", ["'{}' tá zoado, não mexi: {}", "'{}' está inválido e não foi alterado: {}", "'{}' is broken, left it alone: {}"]),
+    ("macros.precisa", ["A macro '{}' precisa de: {}", "A macro '{}' exige: {}", "Macro '{}' needs: {}"]),
+    ("macros.sobrou", ["Aviso: a macro '{}' só usa {} argumento(s), o resto ficou de fora.", "Aviso: a macro '{}' usa apenas {} argumento(s); os demais foram ignorados.", "Warning: macro '{}' only takes {} argument(s), the rest were dropped."]),
+    ("macros.passo_vazio", ["Passo {} da macro '{}' não tem nem 'pedido' nem 'tarefa'.", "O passo {} da macro '{}' não possui 'pedido' nem 'tarefa'.", "Step {} of macro '{}' has neither 'pedido' nor 'tarefa'."]),
+    ("macros.passo", ["Macro '{}', passo {}/{}", "Macro '{}', passo {}/{}", "Macro '{}', step {}/{}"]),
+
+    // terminal
+    ("terminal.falha_transcricao", ["Falha ao criar a transcrição '{}': {}", "Falha ao criar a transcrição '{}': {}", "Failed to create transcript '{}': {}"]),
+    ("terminal.sem_pty", ["Não deu pra abrir o PTY: {}", "Não foi possível abrir o PTY: {}", "Could not open the PTY: {}"]),
+    ("terminal.erro_rodar", ["Deu erro ao TENTAR rodar no PTY: {}", "Falha ao tentar executar no PTY: {}", "Error while TRYING to run in the PTY: {}"]),
+    ("terminal.teclado", ["[PTY] Teclado entregue pro '{}'. Quando ele sair, volta pro Fenrir.", "[PTY] Teclado repassado para '{}'. Ao encerrar, o controle retorna ao Fenrir.", "[PTY] Keyboard handed to '{}'. When it exits, you're back in Fenrir."]),
+    ("terminal.perdi", ["Perdi o filho no PTY: {}", "O processo no PTY foi perdido: {}", "Lost the child process in the PTY: {}"]),
+    ("terminal.sem_mascara", ["Aviso: não deu pra mascarar os segredos da transcrição: {}", "Aviso: não foi possível mascarar os segredos da transcrição: {}", "Warning: could not mask secrets in the transcript: {}"]),
+    ("terminal.encerrada", ["\n[PTY] Sessão encerrada ({}). Transcrição: {}", "\n[PTY] Sessão encerrada ({}). Transcrição: {}", "\n[PTY] Session ended ({}). Transcript: {}"]),
+
+    // limites
+    ("limites.zoados", ["Aviso: limites '{}' tão zoados, ignorando: {}", "Aviso: os limites em '{}' são inválidos e serão ignorados: {}", "Warning: limits in '{}' are broken, ignoring: {}"]),
+    ("limites.sem_igual", ["Ajuste '{}' sem '=' (ex: timeout=600).", "O ajuste '{}' não possui '=' (ex.: timeout=600).", "Adjustment '{}' has no '=' (e.g. timeout=600)."]),
+    ("limites.nao_numero", ["'{}' não é número em '{}'.", "'{}' não é um número em '{}'.", "'{}' is not a number in '{}'."]),
+    ("limites.nao_existe", ["Limite '{}' não existe (timeout, cpu, memoria, saida).", "O limite '{}' não existe (timeout, cpu, memoria, saida).", "Limit '{}' doesn't exist (timeout, cpu, memoria, saida)."]),
+    ("limites.resumo", ["timeout {} | cpu {} | memória {} | saída {}", "timeout {} | cpu {} | memória {} | saída {}", "timeout {} | cpu {} | memory {} | output {}"]),
+    ("limites.ctrl_c", ["\n[Ctrl-C] Repassando pro '{}'...", "\n[Ctrl-C] Repassando para '{}'...", "\n[Ctrl-C] Forwarding to '{}'..."]),
+    ("limites.estourou", ["\n[LIMITE] '{}' estourou ({}). Derrubando o grupo inteiro...", "\n[LIMITE] '{}' excedeu o limite ({}). Encerrando o grupo de processos...", "\n[LIMIT] '{}' blew past its limit ({}). Killing the whole group..."]),
+    ("limites.erro_log", ["Xii, deu erro pra logar o desfecho: {}", "Falha ao registrar o desfecho: {}", "Could not log the outcome: {}"]),
+
+    // ferramentas (e o progresso delas)
+    ("ferramentas.sem_args", ["Oráculo mandou '{}' mas não mandou os ARGS!", "O Oráculo indicou '{}', mas não enviou os ARGS.", "The Oracle asked for '{}' but sent no ARGS!"]),
+    ("ferramentas.faltou", ["Oráculo mandou '{}' mas faltou o '{}'!", "O Oráculo indicou '{}', mas faltou o '{}'.", "The Oracle asked for '{}' but '{}' is missing!"]),
+    ("ferramentas.rodando", ["Rodando {} (Hardcoded)...", "Executando {} (Hardcoded)...", "Running {} (Hardcoded)..."]),
+    ("ferramentas.concluido", ["Scan {} concluído, {} achado(s). (Saída em: {})", "Varredura {} concluída, {} achado(s). (Saída em: {})", "{} scan done, {} finding(s). (Output in: {})"]),
+    ("ferramentas.saiu_com_erro", ["Xii, o '{}' saiu com erro ({}): {}", "O '{}' terminou com erro ({}): {}", "'{}' exited with an error ({}): {}"]),
+    ("ferramentas.erro_rodar", ["Oxe! Deu erro ao TENTAR rodar o '{}': {}", "Falha ao tentar executar '{}': {}", "Whoa! Error while TRYING to run '{}': {}"]),
+    ("ferramentas.sqlmap_saida", ["(Saída do Sqlmap será salva em: {})", "(A saída do Sqlmap será salva em: {})", "(Sqlmap output will be saved in: {})"]),
+    ("ferramentas.sqlmap_fim", ["SQLMap terminou.", "SQLMap concluído.", "SQLMap finished."]),
+    ("ferramentas.sqlmap_erro", ["Xii, o 'sqlmap' saiu com erro ({}).", "O 'sqlmap' terminou com erro ({}).", "'sqlmap' exited with an error ({})."]),
+    ("ferramentas.sqlmap_perdi", ["Oxe! Perdi o 'sqlmap' de vista: {}", "O processo 'sqlmap' foi perdido: {}", "Whoa! Lost track of 'sqlmap': {}"]),
+    ("ferramentas.falha_pasta", ["Aviso: Falha ao criar diretório de log '{}': {}", "Aviso: falha ao criar o diretório de log '{}': {}", "Warning: failed to create log directory '{}': {}"]),
+    ("ferramentas.nem_rodou", ["nem rodou", "não executou", "did not run"]),
+    ("ferramentas.nao_existe", ["Ferramenta '{}' não existe no arsenal.", "A ferramenta '{}' não existe no arsenal.", "Tool '{}' isn't in the arsenal."]),
+    ("ferramentas.sem_wordlist", ["Wordlist '{}' não encontrada, seu corno!\nA IA sugeriu essa, mas talvez você precise de outra?\n(Ex: /usr/share/wordlists/rockyou.txt, /usr/share/seclists/...)", "Wordlist '{}' não encontrada.\nO Oráculo sugeriu esta, mas talvez seja necessária outra.\n(Ex.: /usr/share/wordlists/rockyou.txt, /usr/share/seclists/...)", "Wordlist '{}' not found!\nThe AI suggested this one, but maybe you need another?\n(E.g. /usr/share/wordlists/rockyou.txt, /usr/share/seclists/...)"]),
+    ("progresso.achados_eta", ["{} achado(s) | falta ~{}", "{} achado(s) | restam ~{}", "{} finding(s) | ~{} left"]),
+    ("progresso.achados", ["{} achado(s)", "{} achado(s)", "{} finding(s)"]),
+
+    // variáveis
+    ("variaveis.zoadas", ["Aviso: variáveis '{}' tão zoadas, ignorando: {}", "Aviso: as variáveis em '{}' são inválidas e serão ignoradas: {}", "Warning: variables in '{}' are broken, ignoring: {}"]),
+    ("variaveis.nome_invalido", ["'{}' não serve de nome de variável (letra, número e '_', sem começar com número)", "'{}' não é um nome de variável válido (letras, números e '_', sem iniciar com número)", "'{}' is not a valid variable name (letters, digits and '_', not starting with a digit)"]),
+    ("variaveis.nenhuma", ["Nenhuma variável. Ex: ':set TARGET=10.10.11.5' (e aí 'escaneia $TARGET').", "Nenhuma variável definida. Ex.: ':set TARGET=10.10.11.5' (e então 'escaneia $TARGET').", "No variables. E.g. ':set TARGET=10.10.11.5' (then 'scan $TARGET')."]),
+    ("variaveis.titulo", ["--- VARIÁVEIS ('{}') ---", "--- VARIÁVEIS ('{}') ---", "--- VARIABLES ('{}') ---"]),
+
+    // sandbox
+    ("sandbox.so_linux", ["Sandbox só existe no Linux (namespaces). Aqui não rola.", "A sandbox só está disponível no Linux (namespaces).", "The sandbox only exists on Linux (namespaces). Not here."]),
+    ("sandbox.sem_bwrap", ["Sandbox pedida, mas o '{}' (bubblewrap) não tá no PATH. Instala ele (ex: apt install bubblewrap).", "Sandbox solicitada, mas o '{}' (bubblewrap) não está no PATH. Instale-o (ex.: apt install bubblewrap).", "Sandbox requested, but '{}' (bubblewrap) isn't in PATH. Install it (e.g. apt install bubblewrap)."]),
+    ("sandbox.falha_scratch", ["Falha ao criar o scratch '{}': {}", "Falha ao criar o scratch '{}': {}", "Failed to create scratch '{}': {}"]),
+    ("sandbox.cabecalho", ["[SANDBOX] raiz só-leitura | rede: {} | cwd: {} | scratch: {}", "[SANDBOX] raiz somente leitura | rede: {} | cwd: {} | scratch: {}", "[SANDBOX] read-only root | network: {} | cwd: {} | scratch: {}"]),
+    ("sandbox.ligada", ["LIGADA", "LIGADA", "ON"]),
+    ("sandbox.desligada", ["desligada", "desligada", "off"]),
+    ("sandbox.gravavel", ["gravável", "gravável", "writable"]),
+    ("sandbox.so_leitura", ["só-leitura", "somente leitura", "read-only"]),
+    ("sandbox.erro_rodar", ["Deu erro ao TENTAR rodar a sandbox: {}", "Falha ao tentar executar a sandbox: {}", "Error while TRYING to run the sandbox: {}"]),
+    ("sandbox.perdi", ["Perdi a sandbox de vista: {}", "O processo da sandbox foi perdido: {}", "Lost track of the sandbox: {}"]),
+    ("sandbox.terminou", ["[SANDBOX] Terminou: {}", "[SANDBOX] Concluída: {}", "[SANDBOX] Finished: {}"]),
+
+    // resolvedor
+    ("resolvedor.nada", ["Não achei nada parecido com '{}' em '{}'.", "Nada semelhante a '{}' foi encontrado em '{}'.", "Found nothing like '{}' in '{}'."]),
+    ("resolvedor.achei", ["(Achei '{}')", "(Encontrado: '{}')", "(Found '{}')"]),
+    ("resolvedor.varios", ["\nTem mais de um '{}' por aqui:", "\nHá mais de um '{}' aqui:", "\nThere's more than one '{}' here:"]),
+    ("resolvedor.parecido", ["\nNão achei '{}' certinho, só isso aqui parecido:", "\nNão encontrei '{}' exatamente; o mais parecido é:", "\nNo exact '{}', only this lookalike:"]),
+    ("resolvedor.qual", ["Qual deles? (número, ou Enter pra cancelar): ", "Qual deles? (número, ou Enter para cancelar): ", "Which one? (number, or Enter to cancel): "]),
+    ("resolvedor.nenhum", ["Beleza, nenhum.", "Certo, nenhum.", "Okay, none."]),
+
+    // parada
+    ("parada.anotar", ["Aviso: não deu pra anotar o job '{}' (o 'fenrir stop' não vai ver ele): {}", "Aviso: não foi possível registrar o job '{}' (o 'fenrir stop' não o verá): {}", "Warning: could not record job '{}' ('fenrir stop' won't see it): {}"]),
+    ("parada.nenhum", ["[PARADA] Nenhum job do Fenrir rodando.", "[PARADA] Nenhum job do Fenrir em execução.", "[STOP] No Fenrir jobs running."]),
+    ("parada.derrubando", ["[PARADA] Derrubando {} job(s)...", "[PARADA] Encerrando {} job(s)...", "[STOP] Killing {} job(s)..."]),
+    ("parada.job", ["  ✗ [{}] {} (desde {})", "  ✗ [{}] {} (desde {})", "  ✗ [{}] {} (since {})"]),
+    ("parada.teimosos", ["  ({} grupo(s) ignorou o SIGTERM e levou SIGKILL)", "  ({} grupo(s) ignorou(aram) o SIGTERM e recebeu(ram) SIGKILL)", "  ({} group(s) ignored SIGTERM and got SIGKILL)"]),
+    ("parada.erro_log", ["Xii, deu erro pra logar a parada: {}", "Falha ao registrar a parada: {}", "Could not log the stop: {}"]),
+
+    // editor
+    ("editor.sem_editor", ["O Oráculo não disse o APP e não tem $VISUAL nem $EDITOR definido.", "O Oráculo não informou o APP e não há $VISUAL nem $EDITOR definido.", "The Oracle gave no APP and neither $VISUAL nor $EDITOR is set."]),
+    ("editor.vazio", ["Editor vazio? Aí não dá.", "O editor está vazio.", "Empty editor? Can't do that."]),
+
+    // segredos
+    ("segredos.padrao_invalido", ["Aviso: padrão de segredo '{}' inválido, ignorando: {}", "Aviso: o padrão de segredo '{}' é inválido e será ignorado: {}", "Warning: secret pattern '{}' is invalid, ignoring: {}"]),
+    ("segredos.zoados", ["Aviso: segredos '{}' tão zoados, ignorando: {}", "Aviso: os segredos em '{}' são inválidos e serão ignorados: {}", "Warning: secrets in '{}' are broken, ignoring: {}"]),
+    ("segredos.mapa", ["Aviso: não deu pra guardar o mapa de segredos: {}", "Aviso: não foi possível salvar o mapa de segredos: {}", "Warning: could not save the secrets map: {}"]),
+
+    // sessão
+    ("sessao.nao_achei", ["Não achei a pasta '{}': {}", "A pasta '{}' não foi encontrada: {}", "Couldn't find folder '{}': {}"]),
+    ("sessao.nao_pasta", ["'{}' não é pasta.", "'{}' não é uma pasta.", "'{}' is not a folder."]),
+    ("sessao.pilha_vazia", ["A pilha de pastas tá vazia, não tem pra onde voltar.", "A pilha de pastas está vazia; não há para onde voltar.", "The folder stack is empty, nowhere to go back to."]),
+
+    // leitor
+    ("leitor.sem_editor", ["Não deu pra abrir o editor de linha: {}", "Não foi possível abrir o editor de linha: {}", "Could not open the line editor: {}"]),
+    ("leitor.historico", ["Aviso: não deu pra salvar o histórico: {}", "Aviso: não foi possível salvar o histórico: {}", "Warning: could not save history: {}"]),
+
+    // escopo
+    ("escopo.zoado", ["Aviso: escopo '{}' tá zoado, ignorando: {}", "Aviso: o escopo em '{}' é inválido e será ignorado: {}", "Warning: scope '{}' is broken, ignoring: {}"]),
+
+    // risco
+    ("risco.baixo", ["baixo", "baixo", "low"]),
+    ("risco.medio", ["médio", "médio", "medium"]),
+    ("risco.alto", ["ALTO", "ALTO", "HIGH"]),
+    ("risco.edita", ["Edita arquivo", "Edita arquivo", "Edits a file"]),
+    ("risco.scan", ["Scan ativo na rede", "Varredura ativa na rede", "Active network scan"]),
+    ("risco.sqli", ["Exploração de SQLi", "Exploração de SQLi", "SQLi exploitation"]),
+    ("risco.sqlmap_brabo", ["sqlmap com '{}'", "sqlmap com '{}'", "sqlmap with '{}'"]),
+    ("risco.pipe_shell", ["Baixa e executa script (pipe pro shell)", "Baixa e executa script (pipe para o shell)", "Downloads and runs a script (piped to the shell)"]),
+    ("risco.sudo", ["Roda como root (sudo)", "Executa como root (sudo)", "Runs as root (sudo)"]),
+    ("risco.estrago", ["'{}' é ferramenta de estrago", "'{}' é uma ferramenta destrutiva", "'{}' is a destructive tool"]),
+    ("risco.remoto", ["'{}' fala com outra máquina", "'{}' se comunica com outra máquina", "'{}' talks to another machine"]),
+    ("risco.shell_remoto", ["'{}' entregando shell", "'{}' expondo um shell", "'{}' handing out a shell"]),
+    ("risco.desconhecido", ["'{}' eu não conheço: na dúvida, médio", "'{}' não é conhecido: classificado como médio por precaução", "'{}' is unknown: rated medium to be safe"]),
+    ("risco.leva_segredo", ["Leva um segredo ('__SEGREDO_n__') pro comando", "Envia um segredo ('__SEGREDO_n__') ao comando", "Passes a secret ('__SEGREDO_n__') to the command"]),
+    ("risco.apaga", ["'{}' apaga arquivo", "'{}' remove arquivos", "'{}' deletes files"]),
+    ("risco.rf", ["'{} -rf'", "'{} -rf'", "'{} -rf'"]),
+    ("risco.mexe_projeto", ["Mexe em arquivo do projeto", "Altera arquivo do projeto", "Touches project files"]),
+    ("risco.mexe_fora", ["Mexe FORA do projeto: '{}'", "Altera arquivo FORA do projeto: '{}'", "Touches files OUTSIDE the project: '{}'"]),
+
+    // política
+    ("politica.zoada", ["Aviso: política '{}' tá zoada, ignorando: {}", "Aviso: a política em '{}' é inválida e será ignorada: {}", "Warning: policy '{}' is broken, ignoring: {}"]),
+    ("politica.sem_motivo", ["(sem motivo na regra)", "(regra sem motivo)", "(rule has no reason)"]),
+    ("politica.nenhuma_regra", ["Nenhuma regra bateu, vale a ação padrão da config.", "Nenhuma regra se aplica; vale a ação padrão da configuração.", "No rule matched, using the config's default action."]),
+    ("politica.allow_rebaixado", ["⚠ '{}' tem {} regra(s) 'allow': política do projeto não libera sozinha, valem como 'confirm'. Confia nela? 'confirmacao.allow_do_projeto = true' na SUA config.", "⚠ '{}' contém {} regra(s) 'allow': a política do projeto não libera comandos sozinha, elas valem como 'confirm'. Para aceitá-las, defina 'confirmacao.allow_do_projeto = true' na configuração do usuário.", "⚠ '{}' has {} 'allow' rule(s): a project policy can't approve on its own, so they count as 'confirm'. Trust it? Set 'confirmacao.allow_do_projeto = true' in YOUR config."]),
+    ("politica.allow_do_projeto", ["{} ('allow' do projeto vale como 'confirm')", "{} ('allow' do projeto tratado como 'confirm')", "{} (project 'allow' counts as 'confirm')"]),
+    ("politica.padrao", ["padrão", "padrão", "default"]),
+    ("politica.substituicao", ["{} (mas tem '$(...)' ou crase no comando: allow vira confirm)", "{} (o comando contém '$(...)' ou crase: allow passa a exigir confirmação)", "{} (but the command has '$(...)' or a backtick: allow becomes confirm)"]),
+
+    // oráculo
+    ("oraculo.ficha_invalida", ["Oráculo não devolveu uma Ficha Markdown válida. \nSaída crua: '{}'", "O Oráculo não retornou uma Ficha Markdown válida. \nSaída bruta: '{}'", "The Oracle didn't return a valid Markdown Ficha. \nRaw output: '{}'"]),
+    ("oraculo.diff_invalido", ["O Oráculo não devolveu um diff de verdade. \nSaída crua: '{}'", "O Oráculo não retornou um diff válido. \nSaída bruta: '{}'", "The Oracle didn't return a real diff. \nRaw output: '{}'"]),
+    ("oraculo.erro_processo", ["O processo '{}' deu erro (stderr): {}", "O processo '{}' retornou erro (stderr): {}", "The '{}' process failed (stderr): {}"]),
+    ("oraculo.falha_executar", ["Falha ao executar o processo '{}'. Tá instalado? Tá no PATH? Erro: {}", "Falha ao executar o processo '{}'. Verifique se está instalado e no PATH. Erro: {}", "Failed to run the '{}' process. Is it installed? In PATH? Error: {}"]),
+    ("oraculo.tempo_esgotado", ["Tente novamente, tempo esgotado.", "Tempo esgotado. Tente novamente.", "Timed out, try again."]),
+
+    // eventos
+    ("eventos.sem_separar", ["não deu pra separar stdout e stderr: {}", "não foi possível separar stdout e stderr: {}", "could not split stdout and stderr: {}"]),
+    ("eventos.so_unix", ["o '--json' só funciona em Unix por enquanto", "o '--json' está disponível apenas em Unix por enquanto", "'--json' only works on Unix for now"]),
+
+    // tela
+    ("tela.proposta", ["PROPOSTA DO ORÁCULO", "PROPOSTA DO ORÁCULO", "ORACLE PROPOSAL"]),
+    ("tela.tipo", ["Tipo", "Tipo", "Type"]),
+    ("tela.comando", ["Comando", "Comando", "Command"]),
+    ("tela.arquivo", ["Arquivo", "Arquivo", "File"]),
+    ("tela.app", ["App", "Aplicativo", "App"]),
+    ("tela.args", ["Args", "Argumentos", "Args"]),
+    ("tela.alvo", ["Alvo", "Alvo", "Target"]),
+    ("tela.saida", ["Saída em", "Saída em", "Output in"]),
+    ("tela.mexe", ["Mexe em", "Altera", "Touches"]),
+    ("tela.risco", ["Risco", "Risco", "Risk"]),
+    ("tela.titulo_tabela", ["{} ({})", "{} ({})", "{} ({})"]),
+    ("tela.nmap_titulo", ["Portas", "Portas", "Ports"]),
+    ("tela.gobuster_titulo", ["Caminhos achados", "Caminhos encontrados", "Paths found"]),
+    ("tela.sqlmap_titulo", ["Pontos de injeção", "Pontos de injeção", "Injection points"]),
+    ("tela.col_host", ["HOST", "HOST", "HOST"]),
+    ("tela.col_porta", ["PORTA", "PORTA", "PORT"]),
+    ("tela.col_estado", ["ESTADO", "ESTADO", "STATE"]),
+    ("tela.col_servico", ["SERVIÇO", "SERVIÇO", "SERVICE"]),
+    ("tela.col_versao", ["VERSÃO", "VERSÃO", "VERSION"]),
+    ("tela.col_caminho", ["CAMINHO", "CAMINHO", "PATH"]),
+    ("tela.col_status", ["STATUS", "STATUS", "STATUS"]),
+    ("tela.col_tamanho", ["TAMANHO", "TAMANHO", "SIZE"]),
+    ("tela.col_destino", ["REDIRECIONA", "REDIRECIONA PARA", "REDIRECT"]),
+    ("tela.col_parametro", ["PARÂMETRO", "PARÂMETRO", "PARAMETER"]),
+    ("tela.col_tecnica", ["TÉCNICA", "TÉCNICA", "TECHNIQUE"]),
+    ("tela.col_titulo", ["TÍTULO", "TÍTULO", "TITLE"]),
+
+    // anexos
+    ("anexos.nao_texto", ["'{}' não é texto (tem byte zero), não vai pro Oráculo.", "'{}' não é um arquivo de texto (contém byte nulo) e não será enviado ao Oráculo.", "'{}' isn't text (has a zero byte), not sending it to the oracle."]),
+    ("anexos.stdin_parado", ["Aviso: o stdin tá aberto mas não mandou nada em {}s (cron? ssh?). Seguindo sem anexo ('--stdin' espera o pipe).", "Aviso: o stdin está aberto, mas não enviou dados em {}s (cron? ssh?). Prosseguindo sem anexo ('--stdin' aguarda o pipe).", "Warning: stdin is open but sent nothing in {}s (cron? ssh?). Going on without an attachment ('--stdin' waits for the pipe)."]),
+    ("anexos.sem_espaco", ["Não cabe mais anexo: '{}' ficou de fora (máx {} bytes somando tudo).", "Limite de anexos atingido: '{}' não foi incluído (máx. {} bytes no total).", "No room for more attachments: '{}' was left out (max {} bytes in total)."]),
+    ("anexos.anexado", ["[ANEXO] '{}' ({} bytes) vai junto pro Oráculo, com os segredos mascarados.", "[ANEXO] '{}' ({} bytes) será enviado ao Oráculo, com os segredos mascarados.", "[ATTACHMENT] '{}' ({} bytes) goes to the oracle, with secrets masked."]),
+    ("anexos.cortado", ["[ANEXO] '{}' tem {} bytes: vai cortado em {} (começo + fim), com os segredos mascarados.", "[ANEXO] '{}' possui {} bytes: será enviado reduzido a {} (início + fim), com os segredos mascarados.", "[ATTACHMENT] '{}' has {} bytes: sending it cut to {} (start + end), with secrets masked."]),
+];
+
+// --- CONTRATO ---
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+#[repr(u8)]
+pub enum Idioma {
+    #[default]
+    PtBr = 0,
+    PtBrFormal = 1,
+    En = 2,
+}
+
+// O texto no idioma atual, já com os '{}' preenchidos
+#[macro_export]
+macro_rules! msg {
+    ($chave:expr) => {
+        $crate::mensagens::texto($chave).to_string()
+    };
+    ($chave:expr, $($arg:expr),+ $(,)?) => {
+        $crate::mensagens::preencher($crate::mensagens::texto($chave), &[$(&$arg as &dyn std::fmt::Display),+])
+    };
+}
+
+// --- FUNÇÕES PÚBLICAS ---
+
+// A config chama quando carrega (ou quando o ':set' troca)
+pub fn usar(idioma: Idioma) {
+    IDIOMA.store(idioma as u8, Ordering::Relaxed);
+}
+
+pub fn idioma() -> Idioma {
+    match IDIOMA.load(Ordering::Relaxed) {
+        1 => Idioma::PtBrFormal,
+        2 => Idioma::En,
+        _ => Idioma::PtBr,
+    }
+}
+
+// Chave fora do catálogo volta crua (quem pega isso é o teste 'toda_chave_usada_existe')
+pub fn texto(chave: &'static str) -> &'static str {
+    static INDICE: OnceLock<HashMap<&'static str, [&'static str; 3]>> = OnceLock::new();
+    let indice = INDICE.get_or_init(|| CATALOGO.iter().copied().collect());
+    match indice.get(chave) {
+        Some(versoes) => versoes[idioma() as usize],
+        None => chave,
+    }
+}
+
+// Troca cada '{}' pelo próximo argumento (sobrou '{}'? fica como está)
+pub fn preencher(modelo: &str, args: &[&dyn Display]) -> String {
+    let mut saida = String::with_capacity(modelo.len());
+    let mut args = args.iter();
+    let mut resto = modelo;
+    while let Some(pos) = resto.find("{}") {
+        saida.push_str(&resto[..pos]);
+        match args.next() {
+            Some(arg) => saida.push_str(&arg.to_string()),
+            None => saida.push_str("{}"),
+        }
+        resto = &resto[pos + 2..];
+    }
+    saida.push_str(resto);
+    saida
+}
+
+// Os quadros do spinner (no catálogo vêm separados por '|')
+pub fn quadros(chave: &'static str) -> Vec<&'static str> {
+    texto(chave).split('|').collect()
+}
+
+// A frase que vai no prompt pro Oráculo responder no idioma certo
+pub fn instrucao_do_oraculo() -> &'static str {
+    match idioma() {
+        Idioma::PtBr => "Escreva os textos livres (EXPLICACAO, resumos) em português do Brasil, num tom descontraído.",
+        Idioma::PtBrFormal => "Escreva os textos livres (EXPLICACAO, resumos) em português do Brasil, num tom profissional e sem gírias.",
+        Idioma::En => "Write every free-text field (EXPLICACAO, summaries) in English. Keep the field names and task types exactly as shown.",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+    use std::collections::HashSet;
+    use std::fs;
+    use std::path::Path;
+
+    fn fontes(pasta: &Path, saida: &mut Vec<(String, String)>) {
+        for entrada in fs::read_dir(pasta).unwrap().flatten() {
+            let caminho = entrada.path();
+            if caminho.is_dir() {
+                fontes(&caminho, saida);
+            } else if caminho.extension().is_some_and(|e| e == "rs") && !caminho.ends_with("mensagens.rs") {
+                saida.push((caminho.display().to_string(), fs::read_to_string(&caminho).unwrap()));
+            }
+        }
+    }
+
+    #[test]
+    fn catalogo_sem_repeticao_nem_buraco() {
+        let mut vistas = HashSet::new();
+        for (chave, versoes) in CATALOGO {
+            assert!(vistas.insert(chave), "chave repetida: {chave}");
+            assert!(versoes.iter().all(|v| !v.is_empty()), "versão vazia em {chave}");
+            let furos: Vec<usize> = versoes.iter().map(|v| v.matches("{}").count()).collect();
+            assert!(furos.iter().all(|&n| n == furos[0]), "quantidade de '{{}}' diferente entre idiomas em {chave}");
+        }
+    }
+
+    // Onde o código cita chave: 'msg!("x")', 'texto("x")', 'msg!(if .. { "x" } else { "y" })',
+    // o 'ajuda: "x"' dos comandos e as tabelas que terminam em '"x"),' (config, engagement)
+    #[test]
+    fn toda_chave_usada_existe() {
+        let padroes = [
+            r#"\b(?:msg!|texto|quadros)\(\s*"([^"]+)""#,
+            r#"\{ "([a-z_]+\.[a-z_.\-]+)" \}"#,
+            r#"ajuda: "([^"]+)""#,
+            r#"(?m), "([a-z_]+\.[a-z_.\-]+)"\),$"#,
+        ]
+        .map(|p| Regex::new(p).unwrap());
+        let catalogo: HashSet<&str> = CATALOGO.iter().map(|(chave, _)| *chave).collect();
+
+        let mut arquivos = Vec::new();
+        fontes(&Path::new(env!("CARGO_MANIFEST_DIR")).join("src"), &mut arquivos);
+        let mut faltando = Vec::new();
+        let mut achadas = 0;
+        for (caminho, codigo) in &arquivos {
+            for padrao in &padroes {
+                for chave in padrao.captures_iter(codigo).map(|c| c.get(1).unwrap().as_str()) {
+                    achadas += 1;
+                    if !catalogo.contains(chave) {
+                        faltando.push(format!("{caminho}: {chave}"));
+                    }
+                }
+            }
+        }
+        assert!(achadas > 100, "o teste não achou as chaves no código ({achadas})");
+        assert!(faltando.is_empty(), "chave fora do catálogo:\n{}", faltando.join("\n"));
+    }
+
+    #[test]
+    fn preencher_na_ordem() {
+        assert_eq!(preencher("{} de {}", &[&1, &"dois"]), "1 de dois");
+        assert_eq!(preencher("{} e {}", &[&"só um"]), "só um e {}");
+    }
+}
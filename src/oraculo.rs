@@ -1,28 +1,38 @@
 // --- MÓDULO DO ORÁCULO ---
 // Toda a lógica de falar com a IA fica aqui.
 
+use crate::config;
+use crate::mensagens;
+use crate::msg;
+use crate::parada;
+use crate::segredos;
+use chrono::Local;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::process::Stdio;
 use std::time::Duration;
 use tokio::process::Command;
 
-// --- CONSTANTES (só do Oráculo) ---
-const TIMEOUT_SEGUNDOS: Duration = Duration::from_secs(60);
-
 // --- CONTRATO ---
 // (Fica 'pub' pra 'main.rs' poder usar)
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct FenrirTask {
+    #[serde(default)]
+    pub id: String, // Carimbo da tarefa (o undo e o log usam)
     pub task_type: String,
     pub ia_explanation: String,
     pub command_to_run: Option<String>,
     pub target_path: Option<String>,
     pub application: Option<String>,
+    pub task_args: Option<Value>, // ARGS em JSON (pras ferramentas "hardcoded")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub analysis: Option<String>, // A resposta do 'analyze' (várias linhas, não roda nada)
 }
 
 // --- FUNÇÃO PRINCIPAL (pública) ---
 // (Fica 'pub' pra 'main.rs' poder usar)
-pub async fn chamar_gemini_com_timeout(consulta: &str) -> Result<FenrirTask, String> {
+// 'contexto' entra antes da consulta (ex: as variáveis da sessão). Vazio = nada.
+pub async fn chamar_gemini_com_timeout(consulta: &str, contexto: &str) -> Result<FenrirTask, String> {
 
     // O "meta_prompt" agora mora aqui.
     // E já ensina o Oráculo a usar as ferramentas do Kali!
@@ -32,13 +42,22 @@ Você é um Oráculo para um CLI em Rust chamado Fenrir.
 Sua ÚNICA função é traduzir a linguagem natural do usuário em uma FICHA DE TAREFA em formato Markdown.
 NÃO responda com explicações. NÃO converse. APENAS A FICHA.
 Use "N/A" para campos não aplicáveis.
+{idioma}
 
 O formato da Ficha é:
-TAREFA: [execute_command | open_editor | unknown]
-EXPLICACAO: [O que você entendeu que o usuário quer, em português.]
+TAREFA: [execute_command | open_editor | read_file | edit_file | navigate | nmap | gobuster | sqlmap | analyze | unknown]
+EXPLICACAO: [O que você entendeu que o usuário quer.]
 COMANDO: [O comando shell completo. (N/A se não for 'execute_command')]
-ARQUIVO: [O arquivo ou pasta alvo. (N/A se não for 'open_editor')]
-APP: [O aplicativo para abrir. (N/A se não for 'open_editor')]
+ARQUIVO: [O arquivo ou pasta alvo. No 'open_editor' pode ter linha e coluna (ex: main.rs:42:7); no 'read_file' pode ter faixa de linhas (ex: main.rs:10-40); no 'navigate' é a pasta de destino ("-" volta pra anterior). (N/A se não for 'open_editor', 'read_file', 'edit_file' ou 'navigate')]
+APP: [O aplicativo para abrir. (N/A se não for 'open_editor' ou se o usuário não disse qual)]
+ARGS: [JSON em UMA linha com os argumentos da ferramenta. (N/A se não for 'nmap', 'gobuster', 'sqlmap', 'read_file' ou um 'execute_command' interativo)]
+  - execute_command: {{"interativo": true}} (só se o programa precisa do teclado: consoles, REPLs, ssh...)
+  - read_file: {{"resumir": true}} (só se o usuário pediu resumo/explicação do arquivo)
+  - nmap: {{"target": "...", "flags": ["..."]}}
+  - gobuster: {{"url": "...", "wordlist": "...", "flags": ["..."]}}
+  - sqlmap: {{"url": "...", "flags": ["..."]}}
+ANALISE: [Só no 'analyze': a resposta completa, pode ter várias linhas. É SEMPRE o último campo. (N/A se não for 'analyze')]
+Use 'analyze' quando o usuário quer uma análise ou explicação (de um anexo, log, saída de scan) e não um comando pra rodar.
 
 --- Exemplos Padrão ---
 Consulta: "liste os arquivos da pasta atual"
@@ -48,6 +67,7 @@ EXPLICACAO: O usuário quer listar os arquivos na pasta atual.
 COMANDO: ls -l
 ARQUIVO: N/A
 APP: N/A
+ARGS: N/A
 
 Consulta: "abre o main.rs no rustrover"
 Ficha:
@@ -56,15 +76,80 @@ EXPLICACAO: O usuário quer abrir o arquivo 'main.rs' no 'rustrover'.
 COMANDO: N/A
 ARQUIVO: main.rs
 APP: rustrover
+ARGS: N/A
+
+Consulta: "abre o executor.rs na linha 80"
+Ficha:
+TAREFA: open_editor
+EXPLICACAO: O usuário quer abrir o arquivo 'executor.rs' na linha 80, no editor padrão.
+COMANDO: N/A
+ARQUIVO: executor.rs:80
+APP: N/A
+ARGS: N/A
+
+Consulta: "mostra as linhas 10 a 40 do main.rs"
+Ficha:
+TAREFA: read_file
+EXPLICACAO: O usuário quer ver as linhas 10 a 40 do 'main.rs'.
+COMANDO: N/A
+ARQUIVO: main.rs:10-40
+APP: N/A
+ARGS: N/A
+
+Consulta: "me explica o que o executor.rs faz"
+Ficha:
+TAREFA: read_file
+EXPLICACAO: O usuário quer um resumo do que o 'executor.rs' faz.
+COMANDO: N/A
+ARQUIVO: executor.rs
+APP: N/A
+ARGS: {{"resumir": true}}
+
+Consulta: "no main.rs troca a mensagem de despedida por 'Tchau!'"
+Ficha:
+TAREFA: edit_file
+EXPLICACAO: No 'main.rs', trocar a mensagem de despedida do modo interativo por 'Tchau!'.
+COMANDO: N/A
+ARQUIVO: main.rs
+APP: N/A
+ARGS: N/A
+
+Consulta: "entra na pasta src"
+Ficha:
+TAREFA: navigate
+EXPLICACAO: O usuário quer mudar a pasta atual para 'src'.
+COMANDO: N/A
+ARQUIVO: src
+APP: N/A
+ARGS: N/A
+
+Consulta: "volta pra pasta anterior"
+Ficha:
+TAREFA: navigate
+EXPLICACAO: O usuário quer voltar para a pasta anterior.
+COMANDO: N/A
+ARQUIVO: -
+APP: N/A
+ARGS: N/A
 
 --- Exemplos de Ferramentas (SecOps) ---
 Consulta: "escaneie as portas do localhost"
 Ficha:
-TAREFA: execute_command
+TAREFA: nmap
 EXPLICACAO: O usuário quer rodar um scan de versão (sV) do Nmap no 'localhost'.
-COMANDO: nmap -sV localhost
+COMANDO: N/A
 ARQUIVO: N/A
 APP: N/A
+ARGS: {{"target": "localhost", "flags": ["-sV"]}}
+
+Consulta: "procura diretórios escondidos em http://10.10.11.5"
+Ficha:
+TAREFA: gobuster
+EXPLICACAO: O usuário quer descobrir diretórios no site 'http://10.10.11.5' com o Gobuster.
+COMANDO: N/A
+ARQUIVO: N/A
+APP: N/A
+ARGS: {{"url": "http://10.10.11.5"}}
 
 Consulta: "inicie o console do metasploit"
 Ficha:
@@ -73,6 +158,7 @@ EXPLICACAO: O usuário quer iniciar o console do Metasploit.
 COMANDO: msfconsole
 ARQUIVO: N/A
 APP: N/A
+ARGS: N/A
 
 Consulta: "verifique a versão do sqlmap"
 Ficha:
@@ -81,6 +167,18 @@ EXPLICACAO: O usuário quer verificar a versão do 'sqlmap'.
 COMANDO: sqlmap --version
 ARQUIVO: N/A
 APP: N/A
+ARGS: N/A
+
+Consulta: "o que deu errado?" (com um anexo 'stdin' contendo "Error: listen EADDRINUSE: address already in use :::3000")
+Ficha:
+TAREFA: analyze
+EXPLICACAO: O usuário quer saber por que o programa do log falhou.
+COMANDO: N/A
+ARQUIVO: N/A
+APP: N/A
+ARGS: N/A
+ANALISE: A porta 3000 já está em uso por outro processo, então o servidor não conseguiu abrir ela.
+Veja quem está usando com 'ss -ltnp | grep 3000' e pare esse processo, ou suba o servidor em outra porta.
 
 Consulta: "quantos pau tem uma canoa"
 Ficha:
@@ -89,68 +187,172 @@ EXPLICACAO: O usuário fez uma pergunta aleatória que não é um comando.
 COMANDO: N/A
 ARQUIVO: N/A
 APP: N/A
+ARGS: N/A
 
+{contexto}
 AGORA, A CONSULTA DO USUÁRIO É:
 '{consulta}'
 
 GERE APENAS A FICHA DE TAREFA.
 "#,
-        consulta = consulta
+        consulta = consulta,
+        contexto = contexto,
+        idioma = mensagens::instrucao_do_oraculo()
     );
 
-    let cmd_future = Command::new("gemini")
-        .arg(meta_prompt)
+    let saida_str = rodar_gemini(meta_prompt).await?;
+
+    // O Parser "Caderninho de Fiado" (robusto pra porra)
+    let mut task = FenrirTask {
+        id: novo_id(),
+        task_type: "unknown".to_string(),
+        ..Default::default()
+    };
+
+    let mut linhas = saida_str.lines();
+    while let Some(line) = linhas.next() {
+        // ANALISE é o último campo e pode ter várias linhas: leva o resto todo
+        if let Some(value) = line.trim_start().strip_prefix("ANALISE:") {
+            let resto: Vec<&str> = std::iter::once(value.trim()).chain(linhas.by_ref()).collect();
+            let analise = resto.join("\n").trim().to_string();
+            if !analise.is_empty() && analise != "N/A" {
+                task.analysis = Some(analise);
+            }
+            break;
+        }
+        if let Some((key, value)) = line.split_once(':') {
+            let key = key.trim();
+            let value = value.trim();
+
+            match key {
+                "TAREFA" => task.task_type = value.to_string(),
+                "EXPLICACAO" => task.ia_explanation = value.to_string(),
+                "COMANDO" if value != "N/A" => task.command_to_run = Some(value.to_string()),
+                "ARQUIVO" if value != "N/A" => task.target_path = Some(value.to_string()),
+                "APP" if value != "N/A" => task.application = Some(value.to_string()),
+                "ARGS" if value != "N/A" => task.task_args = serde_json::from_str(value).ok(),
+                _ => {}
+            }
+        }
+    }
+
+    if task.ia_explanation.is_empty() {
+        Err(msg!("oraculo.ficha_invalida", saida_str))
+    } else {
+        Ok(task) // SUCESSO!
+    }
+}
+
+// Carimbo de tarefa nova (a macro também carimba as dela)
+pub fn novo_id() -> String {
+    Local::now().format("%Y%m%d-%H%M%S-%3f").to_string()
+}
+
+// --- PEDIDOS "DE SEGUNDA RODADA" ---
+// O 'read_file' e o 'edit_file' precisam mostrar o ARQUIVO pro Oráculo,
+// então é uma segunda chamada (a Ficha só diz QUAL arquivo e O QUE fazer).
+
+// Resume o trecho do arquivo (no idioma da interface), focado no que o usuário pediu
+pub async fn resumir_arquivo(caminho: &str, conteudo: &str, pedido: &str) -> Result<String, String> {
+    let prompt = format!(
+r#"
+Você é o Oráculo do Fenrir (um CLI em Rust).
+Resuma, em poucos parágrafos curtos, o conteúdo do arquivo '{caminho}'.
+Foque no que o usuário pediu: '{pedido}'
+NÃO invente nada que não esteja no arquivo.
+{idioma}
+
+--- INÍCIO DO ARQUIVO ---
+{conteudo}
+--- FIM DO ARQUIVO ---
+"#,
+        caminho = caminho,
+        pedido = pedido,
+        conteudo = conteudo,
+        idioma = mensagens::instrucao_do_oraculo()
+    );
+
+    let saida = rodar_gemini(prompt).await?;
+    Ok(saida.trim().to_string())
+}
+
+// Pede um diff unificado aplicando a 'instrucao' no arquivo.
+// Devolve só o diff (sem cerquinha de markdown).
+pub async fn propor_diff(caminho: &str, conteudo: &str, instrucao: &str) -> Result<String, String> {
+    let prompt = format!(
+r#"
+Você é o Oráculo do Fenrir (um CLI em Rust).
+Aplique a mudança abaixo no arquivo '{caminho}' e responda APENAS com um diff unificado
+(formato 'diff -u': cabeçalhos '--- a/{caminho}' e '+++ b/{caminho}', hunks '@@ -l,n +l,n @@'
+com 3 linhas de contexto). NÃO explique. NÃO use cerquinha de markdown.
+As linhas de contexto e as removidas têm que ser IDÊNTICAS às do arquivo.
+
+Mudança pedida: '{instrucao}'
+
+--- INÍCIO DO ARQUIVO ---
+{conteudo}
+--- FIM DO ARQUIVO ---
+"#,
+        caminho = caminho,
+        instrucao = instrucao,
+        conteudo = conteudo
+    );
+
+    let saida = rodar_gemini(prompt).await?;
+    let diff = tirar_cerquinha(&saida);
+    if !diff.lines().any(|l| l.starts_with("@@")) {
+        return Err(msg!("oraculo.diff_invalido", saida));
+    }
+    Ok(diff)
+}
+
+// --- FUNÇÕES PRIVADAS ---
+
+// Roda o 'gemini' com o prompt e devolve o stdout (com timeout)
+async fn rodar_gemini(prompt: String) -> Result<String, String> {
+    // Segredo não sai da máquina: vai o marcador, e a resposta volta revelada
+    let config = config::atual().oraculo;
+    let mut cmd = Command::new(&config.binario);
+    if !config.modelo.is_empty() {
+        cmd.args(["-m", &config.modelo]);
+    }
+    let cmd_future = cmd
+        .arg(segredos::mascarar(&prompt))
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
+        .kill_on_drop(true) // Cancelou? O 'gemini' morre junto com o future
         .output();
 
-    match tokio::time::timeout(TIMEOUT_SEGUNDOS, cmd_future).await {
+    let resultado = tokio::select! {
+        r = tokio::time::timeout(Duration::from_secs(config.timeout_segundos), cmd_future) => r,
+        _ = parada::passo_cancelado() => return Err(parada::CANCELADO.to_string()),
+    };
+
+    match resultado {
         Ok(Ok(output)) => {
             if output.status.success() {
-                let saida_str = String::from_utf8_lossy(&output.stdout).to_string();
-
-                // O Parser "Caderninho de Fiado" (robusto pra porra)
-                let mut task = FenrirTask::default();
-                task.task_type = "unknown".to_string();
-
-                for line in saida_str.lines() {
-                    if let Some((key, value)) = line.split_once(':') {
-                        let key = key.trim();
-                        let value = value.trim();
-
-                        match key {
-                            "TAREFA" => task.task_type = value.to_string(),
-                            "EXPLICACAO" => task.ia_explanation = value.to_string(),
-                            "COMANDO" if value != "N/A" => task.command_to_run = Some(value.to_string()),
-                            "ARQUIVO" if value != "N/A" => task.target_path = Some(value.to_string()),
-                            "APP" if value != "N/A" => task.application = Some(value.to_string()),
-                            _ => {}
-                        }
-                    }
-                }
-
-                if task.ia_explanation.is_empty() {
-                    Err(format!("Oráculo não devolveu uma Ficha Markdown válida. \nSaída crua: '{}'", saida_str))
-                } else {
-                    Ok(task) // SUCESSO!
-                }
-
+                Ok(segredos::revelar(&String::from_utf8_lossy(&output.stdout)))
             } else {
                 let erro_str = String::from_utf8_lossy(&output.stderr).to_string();
-                Err(format!(
-                    "O processo 'gemini' deu erro (stderr): {}",
-                    erro_str
-                ))
+                Err(msg!("oraculo.erro_processo", config.binario, erro_str))
             }
         }
         Ok(Err(e)) => {
-            Err(format!(
-                "Falha ao executar o processo 'gemini'. Tá instalado? Tá no PATH? Erro: {}",
-                e
-            ))
+            Err(msg!("oraculo.falha_executar", config.binario, e))
         }
         Err(_) => {
-            Err("Tente novamente, tempo esgotado.".to_string())
+            Err(msg!("oraculo.tempo_esgotado"))
         }
     }
-}
\ No newline at end of file
+}
+
+// IA adora embrulhar tudo em ```diff ... ```. A gente desembrulha.
+fn tirar_cerquinha(saida: &str) -> String {
+    let mut diff: String = saida
+        .lines()
+        .filter(|l| !l.trim_start().starts_with("```"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    diff.push('\n');
+    diff
+}
@@ -0,0 +1,202 @@
+// --- MÓDULO PARADA (DE EMERGÊNCIA) ---
+// Scan saiu do escopo na rede do cliente? Não dá pra ficar caçando PID.
+// Todo comando que o Fenrir sobe nasce no grupo de processos dele (ver
+// 'limites') e é anotado aqui em '~/.local/state/fenrir/processos/<pgid>.json'.
+// 'fenrir stop', ':stop-all' ou Ctrl-C duas vezes derrubam TODOS os grupos
+// anotados (filhos das ferramentas inclusos) e o log grava quem morreu.
+// (É arquivo, e não memória, pro 'fenrir stop' de OUTRO terminal enxergar,
+// e fica numa pasta fixa do usuário, não na pasta atual, pra ele achar de qualquer lugar.)
+//
+// Ctrl-C UMA vez é mais educado: só cancela o passo atual (a chamada do
+// Oráculo ou o comando rodando) e o Fenrir volta pro prompt.
+
+use crate::executor;
+use crate::limites::{self, Sinal};
+use crate::msg;
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+// --- CONSTANTES (só da Parada) ---
+const PASTA_PROCESSOS: &str = ".local/state/fenrir/processos"; // (relativo ao $HOME)
+const CARENCIA_SIGTERM: Duration = Duration::from_secs(3); // Depois disso, SIGKILL
+pub const CANCELADO: &str = "Cancelado (Ctrl-C)."; // O erro de quem foi cancelado
+
+// Quem tá esperando o "cancela o passo" (oráculo, vigia dos comandos)
+static PASSO: Notify = Notify::const_new();
+
+// --- CONTRATO ---
+
+// Um comando rodando (o que vai pro arquivo)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Job {
+    pub pgid: u32,
+    pub rotulo: String,
+    pub inicio: String,
+    pub dono: u32, // PID do Fenrir que subiu
+}
+
+// Enquanto viver, o job tá anotado. Morreu (Drop), sai da lista.
+#[derive(Debug)]
+pub struct Rastreio {
+    arquivo: PathBuf,
+}
+
+// --- FUNÇÕES PÚBLICAS ---
+
+// Anota o grupo 'pgid'. Falhou? Avisa: o job roda, só o 'stop' não enxerga.
+pub fn rastrear(pgid: u32, rotulo: &str) -> Rastreio {
+    let arquivo = arquivo_do_job(pgid);
+    let job = Job {
+        pgid,
+        rotulo: rotulo.to_string(),
+        inicio: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        dono: std::process::id(),
+    };
+    let gravado = criar_pasta()
+        .map_err(|e| e.to_string())
+        .and_then(|_| serde_json::to_string(&job).map_err(|e| e.to_string()))
+        .and_then(|j| fs::write(&arquivo, j).map_err(|e| e.to_string()));
+    if let Err(e) = gravado {
+        eprintln!("{}", msg!("parada.anotar", rotulo, e));
+    }
+    Rastreio { arquivo }
+}
+
+impl Rastreio {
+    // A parada de emergência apagou a anotação ANTES de mandar o sinal:
+    // se sumiu, foi ela que matou.
+    pub fn foi_parado(&self) -> bool {
+        !self.arquivo.exists()
+    }
+}
+
+impl Drop for Rastreio {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.arquivo);
+    }
+}
+
+// Ctrl-C: acorda TODO mundo que tá esperando em 'passo_cancelado'
+// (ninguém esperando = prompt parado, não acontece nada)
+pub fn cancelar_passo() {
+    PASSO.notify_waiters();
+}
+
+// Termina quando o operador cancela o passo atual (use num 'select!')
+pub async fn passo_cancelado() {
+    PASSO.notified().await
+}
+
+// Os jobs que ainda tão vivos (anotação de grupo morto, ou que não é mais do
+// Fenrir que anotou, é faxinada: a parada só sinaliza o que sobrar daqui)
+pub fn em_andamento() -> Vec<Job> {
+    let Ok(entradas) = fs::read_dir(pasta_processos()) else {
+        return Vec::new();
+    };
+    let mut jobs: Vec<Job> = entradas
+        .filter_map(Result::ok)
+        .filter_map(|e| {
+            let caminho = e.path();
+            let job = fs::read_to_string(&caminho).ok().and_then(|c| serde_json::from_str::<Job>(&c).ok());
+            match job {
+                Some(job) if limites::grupo_vivo(job.pgid) && limites::grupo_do_dono(job.pgid, job.dono) => Some(job),
+                _ => {
+                    let _ = fs::remove_file(&caminho);
+                    None
+                }
+            }
+        })
+        .collect();
+    jobs.sort_by(|a, b| a.inicio.cmp(&b.inicio));
+    jobs
+}
+
+// A PARADA: SIGTERM em todos os grupos, carência, SIGKILL em quem sobrar.
+// Bloqueante (espera a carência). Loga com 'origem' (quem puxou o freio).
+pub fn parar_tudo(origem: &str) -> Vec<Job> {
+    let jobs = em_andamento();
+    if jobs.is_empty() {
+        println!("{}", msg!("parada.nenhum"));
+        return jobs;
+    }
+
+    println!("{}", msg!("parada.derrubando", jobs.len()));
+    let mut mortos = Vec::new();
+    for job in &jobs {
+        let processos: Vec<u32> = limites::processos_do_grupo(job.pgid).into_iter().map(|(pid, _)| pid).collect();
+        // Apaga ANTES do sinal, pro dono saber que foi a parada ("killed: stop")
+        let _ = fs::remove_file(arquivo_do_job(job.pgid));
+        limites::sinalizar_grupo(job.pgid, Sinal::Terminar);
+        mortos.push(json!({
+            "pgid": job.pgid,
+            "rotulo": job.rotulo,
+            "inicio": job.inicio,
+            "dono": job.dono,
+            "processos": processos,
+        }));
+    }
+
+    let limite = Instant::now() + CARENCIA_SIGTERM;
+    while Instant::now() < limite && jobs.iter().any(|j| limites::grupo_vivo(j.pgid)) {
+        thread::sleep(Duration::from_millis(100));
+    }
+    let teimosos: Vec<u32> = jobs.iter().map(|j| j.pgid).filter(|p| limites::grupo_vivo(*p)).collect();
+    for pgid in &teimosos {
+        limites::sinalizar_grupo(*pgid, Sinal::Matar);
+    }
+
+    for job in &jobs {
+        println!("{}", msg!("parada.job", job.pgid, job.rotulo, job.inicio));
+    }
+    if !teimosos.is_empty() {
+        println!("{}", msg!("parada.teimosos", teimosos.len()));
+    }
+
+    let evento = json!({ "origem": origem, "jobs": mortos, "sigkill": teimosos });
+    if let Err(e) = executor::log_evento("PARADA", &evento) {
+        eprintln!("{}", msg!("parada.erro_log", e));
+    }
+    jobs
+}
+
+// --- FUNÇÕES PRIVADAS ---
+
+// Sem $HOME (raro: serviço enxuto), vai pra pasta temporária com o uid no nome
+fn pasta_processos() -> PathBuf {
+    match env::var_os("HOME") {
+        Some(home) => PathBuf::from(home).join(PASTA_PROCESSOS),
+        None => env::temp_dir().join(format!("fenrir-{}", usuario())).join("processos"),
+    }
+}
+
+// Só o dono lê e escreve (0700)
+fn criar_pasta() -> Result<(), String> {
+    let mut construtor = fs::DirBuilder::new();
+    construtor.recursive(true);
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut construtor, 0o700);
+    construtor.create(pasta_processos()).map_err(|e| e.to_string())
+}
+
+fn usuario() -> u32 {
+    #[cfg(unix)]
+    {
+        // SAFETY: getuid() não falha
+        unsafe { libc::getuid() }
+    }
+    #[cfg(not(unix))]
+    {
+        0
+    }
+}
+
+fn arquivo_do_job(pgid: u32) -> PathBuf {
+    pasta_processos().join(format!("{}.json", pgid))
+}
@@ -0,0 +1,599 @@
+// --- MÓDULO POLÍTICA ---
+// O "s/n" é bom, mas o tech lead quer GUARD-RAIL de verdade.
+// Regras num arquivo TOML (do usuário e do engagement) dizendo o que
+// pode, o que pergunta, o que exige frase e o que é PROIBIDO.
+//
+// Exemplo ('.fenrir/politica.toml'):
+//
+//   [[regra]]
+//   binario = "sqlmap"
+//   argumento = "--os-shell"
+//   acao = "deny"
+//   motivo = "Shell no servidor do cliente só com aprovação por escrito."
+//
+//   [[regra]]
+//   alvo = "10.10.*"
+//   acao = "require-phrase"
+//   frase = "ESTÁ NO ESCOPO"
+//   motivo = "Rede do cliente: confirme o escopo."
+
+use crate::config;
+use crate::ferramentas;
+use crate::msg;
+use crate::oraculo::FenrirTask;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// --- CONSTANTES (só da Política) ---
+const ARQUIVO_ENGAGEMENT: &str = ".fenrir/politica.toml";
+const ARQUIVO_USUARIO: &str = ".config/fenrir/politica.toml"; // (relativo ao $HOME)
+
+// --- CONTRATO ---
+
+// Ordem importa: da mais frouxa pra mais braba (a mais braba ganha)
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "kebab-case")]
+pub enum Acao {
+    Allow,
+    Confirm,
+    RequirePhrase,
+    Deny,
+}
+
+impl Acao {
+    // Do jeito que se escreve no TOML
+    pub fn rotulo(&self) -> &'static str {
+        match self {
+            Acao::Allow => "allow",
+            Acao::Confirm => "confirm",
+            Acao::RequirePhrase => "require-phrase",
+            Acao::Deny => "deny",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Regra {
+    pub binario: Option<String>,   // Ex: "sqlmap", "rm", "nmap"
+    pub argumento: Option<String>, // Ex: "--os-shell", "-rf", "*--script*"
+    pub caminho: Option<String>,   // Ex: "/etc/*", "~/.ssh/*"
+    pub alvo: Option<String>,      // Ex: "10.10.*", "*.cliente.com.br"
+    pub acao: Acao,
+    pub motivo: Option<String>,
+    pub frase: Option<String>, // Só pra 'require-phrase'
+}
+
+#[derive(Deserialize, Debug, Default)]
+struct ArquivoPolitica {
+    #[serde(default)]
+    regra: Vec<Regra>,
+}
+
+// Todas as regras carregadas, cada uma com o arquivo de onde veio
+#[derive(Debug, Default)]
+pub struct Politica {
+    regras: Vec<(Regra, String)>,
+}
+
+// O que a tarefa quer fazer, destrinchado pra bater com as regras
+#[derive(Debug, Default)]
+pub struct Pedido {
+    pub binarios: Vec<String>,
+    pub argumentos: Vec<String>,
+    pub caminhos: Vec<String>,
+    pub alvos: Vec<String>,
+    pub substituicao: bool, // '$(...)' ou crase: roda coisa que a regra não vê inteira
+    pub segmentos: Vec<Pedido>, // Cada pedaço do shell sozinho (vazio fora do 'execute_command')
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct Decisao {
+    pub acao: Acao,
+    pub motivo: String,
+    pub origem: String, // Arquivo da regra (ou "padrão")
+    pub frase: Option<String>,
+}
+
+// --- CARREGAMENTO ---
+
+impl Politica {
+    // Lê a política do usuário e a do engagement (pasta atual).
+    // Arquivo quebrado = aviso, não derruba o Fenrir.
+    pub fn carregar() -> Politica {
+        let mut politica = Politica::default();
+        for caminho in arquivos_de_politica() {
+            let Ok(conteudo) = fs::read_to_string(&caminho) else {
+                continue; // Não existe? Sem problema.
+            };
+            match toml::from_str::<ArquivoPolitica>(&conteudo) {
+                Ok(arquivo) => {
+                    let origem = caminho.display().to_string();
+                    let projeto = caminho == Path::new(ARQUIVO_ENGAGEMENT);
+                    let rebaixar = projeto && !config::atual().confirmacao.allow_do_projeto;
+                    let rebaixadas = politica.juntar(arquivo, &origem, rebaixar);
+                    if rebaixadas > 0 {
+                        eprintln!("{}", msg!("politica.allow_rebaixado", origem, rebaixadas));
+                    }
+                }
+                Err(e) => eprintln!("{}", msg!("politica.zoada", caminho.display(), e)),
+            }
+        }
+        politica
+    }
+
+    // A '.fenrir/politica.toml' vem com o repo, igual ao '.fenrir.toml': o 'allow'
+    // dela vira 'confirm' (com 'rebaixar'). Devolve quantas foram rebaixadas.
+    fn juntar(&mut self, arquivo: ArquivoPolitica, origem: &str, rebaixar: bool) -> usize {
+        let mut rebaixadas = 0;
+        for mut regra in arquivo.regra {
+            if rebaixar && regra.acao == Acao::Allow {
+                regra.acao = Acao::Confirm;
+                let motivo = regra.motivo.take().unwrap_or_else(|| msg!("politica.sem_motivo"));
+                regra.motivo = Some(msg!("politica.allow_do_projeto", motivo));
+                rebaixadas += 1;
+            }
+            self.regras.push((regra, origem.to_string()));
+        }
+        rebaixadas
+    }
+
+    // Avalia o pedido: entre as regras que batem, a MAIS braba ganha.
+    // Nenhuma bateu? Vale a 'confirmacao.acao_padrao' da config ('confirm', o bom e velho s/n).
+    // Comando shell é avaliado pedaço por pedaço e vale o pedaço mais brabo:
+    // 'allow ls' não libera o 'ls; rm -rf ~' (o 'rm' cai no padrão).
+    // Com '$(...)' ou crase no comando o 'allow' não vale: no mínimo pergunta.
+    pub fn avaliar(&self, pedido: &Pedido) -> Decisao {
+        let decisao = pedido
+            .segmentos
+            .iter()
+            .map(|segmento| self.decidir(segmento))
+            .max_by_key(|decisao| decisao.acao)
+            .unwrap_or_else(|| self.decidir(pedido));
+        if pedido.substituicao && decisao.acao == Acao::Allow {
+            return Decisao {
+                acao: Acao::Confirm,
+                motivo: msg!("politica.substituicao", decisao.motivo),
+                ..decisao
+            };
+        }
+        decisao
+    }
+
+    fn decidir(&self, pedido: &Pedido) -> Decisao {
+        let padrao = config::atual().confirmacao;
+        self.regras
+            .iter()
+            .filter(|(regra, _)| regra_bate(regra, pedido))
+            .max_by_key(|(regra, _)| regra.acao)
+            .map(|(regra, origem)| Decisao {
+                acao: regra.acao,
+                motivo: regra.motivo.clone().unwrap_or_else(|| msg!("politica.sem_motivo")),
+                origem: origem.clone(),
+                frase: match regra.acao {
+                    Acao::RequirePhrase => Some(regra.frase.clone().unwrap_or_else(|| padrao.frase_padrao.clone())),
+                    _ => None,
+                },
+            })
+            .unwrap_or_else(|| Decisao {
+                acao: padrao.acao_padrao,
+                motivo: msg!("politica.nenhuma_regra"),
+                origem: msg!("politica.padrao"),
+                frase: (padrao.acao_padrao == Acao::RequirePhrase).then(|| padrao.frase_padrao.clone()),
+            })
+    }
+}
+
+fn arquivos_de_politica() -> Vec<PathBuf> {
+    let mut arquivos = Vec::new();
+    if let Some(home) = env::var_os("HOME") {
+        arquivos.push(PathBuf::from(home).join(ARQUIVO_USUARIO));
+    }
+    arquivos.push(PathBuf::from(ARQUIVO_ENGAGEMENT));
+    arquivos
+}
+
+// --- DESTRINCHANDO A TAREFA ---
+
+// Transforma a tarefa do Oráculo num Pedido (binários, args, caminhos, alvos)
+pub fn pedido_da_tarefa(task: &FenrirTask) -> Pedido {
+    let mut pedido = Pedido::default();
+
+    match task.task_type.as_str() {
+        "execute_command" => {
+            if let Some(cmd) = &task.command_to_run {
+                destrinchar_shell(cmd, &mut pedido);
+            }
+        }
+        "open_editor" | "read_file" | "edit_file" | "navigate" => {
+            if let Some(app) = &task.application {
+                pedido.binarios.push(app.clone());
+            }
+            if let Some(path) = &task.target_path {
+                pedido.caminhos.push(path.clone());
+            }
+        }
+        ferramenta => {
+            pedido.binarios.push(ferramenta.to_string());
+            if let Some(args) = task.task_args.as_ref().and_then(|a| a.as_object()) {
+                if let Some(alvo) = ferramentas::chave_alvo(ferramenta)
+                    .and_then(|chave| args.get(chave))
+                    .and_then(|v| v.as_str())
+                {
+                    pedido.alvos.push(alvo.to_string());
+                }
+                if let Some(wordlist) = args.get("wordlist").and_then(|v| v.as_str()) {
+                    pedido.caminhos.push(wordlist.to_string());
+                }
+                if let Some(flags) = args.get("flags").and_then(|v| v.as_array()) {
+                    for flag in flags.iter().filter_map(|f| f.as_str()) {
+                        pedido.argumentos.extend(flag.split_whitespace().map(String::from));
+                    }
+                }
+            }
+        }
+    }
+
+    pedido
+}
+
+// Quebra um comando shell em pedaços (';', '&', '|', quebra de linha, subshell,
+// '$(...)' e crase), cada um já tokenizado e DESEMBRULHADO: 'sh -c "..."' vira
+// os comandos de dentro, e 'env', 'nohup', 'timeout', 'xargs' & cia. somem
+// (junto com 'VAR=valor'). Se teve 'sudo' no caminho, o pedaço começa com "sudo".
+// (O analisador de risco usa também.)
+pub fn segmentos_shell(cmd: &str) -> Vec<Vec<String>> {
+    let mut segmentos = Vec::new();
+    for pedaco in cortar_shell(cmd) {
+        desembrulhar(&tokenizar(&pedaco), false, &mut segmentos);
+    }
+    segmentos
+}
+
+// O comando roda OUTRO comando escondido ('$(...)', crase, '<(...)')?
+pub fn tem_substituicao(cmd: &str) -> bool {
+    cmd.contains("$(") || cmd.contains('`') || cmd.contains("<(") || cmd.contains(">(")
+}
+
+// Pega o binário de cada pedaço do shell + os argumentos, caminhos e alvos.
+fn destrinchar_shell(cmd: &str, pedido: &mut Pedido) {
+    pedido.substituicao = tem_substituicao(cmd);
+    for tokens in segmentos_shell(cmd) {
+        let mut tokens = tokens.into_iter().skip_while(|t| t == "sudo");
+
+        let Some(binario) = tokens.next() else {
+            continue;
+        };
+        let mut segmento = Pedido::default();
+        segmento.binarios.push(binario.rsplit('/').next().unwrap_or(&binario).to_string());
+
+        for token in tokens {
+            if parece_alvo(&token) {
+                segmento.alvos.push(token.clone());
+            }
+            if parece_caminho(&token) {
+                segmento.caminhos.push(token.clone());
+            }
+            segmento.argumentos.push(token);
+        }
+
+        // O pedido inteiro junta tudo (escopo e afins olham o todo)
+        pedido.binarios.extend(segmento.binarios.iter().cloned());
+        pedido.argumentos.extend(segmento.argumentos.iter().cloned());
+        pedido.caminhos.extend(segmento.caminhos.iter().cloned());
+        pedido.alvos.extend(segmento.alvos.iter().cloned());
+        pedido.segmentos.push(segmento);
+    }
+}
+
+// Corta nos operadores sem cortar string: entre aspas simples nada corta,
+// entre duplas só a substituição ('$(' e crase) ainda vale.
+fn cortar_shell(cmd: &str) -> Vec<String> {
+    let mut pedacos = Vec::new();
+    let mut atual = String::new();
+    let (mut simples, mut duplas) = (false, false);
+    let mut chars = cmd.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let corta = match c {
+            '\\' if !simples => {
+                atual.push(c);
+                atual.extend(chars.next());
+                continue;
+            }
+            '\'' if !duplas => {
+                simples = !simples;
+                false
+            }
+            '"' if !simples => {
+                duplas = !duplas;
+                false
+            }
+            _ if simples => false,
+            '`' | ')' => true,
+            '(' => !duplas || atual.ends_with('$'),
+            // '2>&1', '>&2' e '&>arquivo' são redirecionamento, não background
+            '&' if atual.ends_with(['>', '<']) || chars.peek() == Some(&'>') => false,
+            ';' | '&' | '|' | '\n' => !duplas,
+            _ => false,
+        };
+        if corta {
+            if !atual.trim().is_empty() {
+                pedacos.push(std::mem::take(&mut atual));
+            }
+            atual.clear();
+        } else {
+            atual.push(c);
+        }
+    }
+    if !atual.trim().is_empty() {
+        pedacos.push(atual);
+    }
+    pedacos
+}
+
+fn tokenizar(pedaco: &str) -> Vec<String> {
+    shlex::split(pedaco).unwrap_or_else(|| pedaco.split_whitespace().map(String::from).collect())
+}
+
+// Tira os "embrulhos" da frente até achar o comando de verdade.
+// 'sh -c' entra no script (recursivo); o 'sudo' vai junto pra dentro.
+fn desembrulhar(tokens: &[String], sudo: bool, segmentos: &mut Vec<Vec<String>>) {
+    let mut sudo = sudo;
+    let mut resto = tokens;
+
+    while let Some(primeiro) = resto.first() {
+        if eh_atribuicao(primeiro) {
+            resto = &resto[1..];
+            continue;
+        }
+        resto = match primeiro.rsplit('/').next().unwrap_or(primeiro) {
+            "sudo" => {
+                sudo = true;
+                pular_opcoes(&resto[1..], &["-u", "-g", "-C", "-D", "-h", "-p", "-r", "-t", "-U"])
+            }
+            "env" => pular_opcoes(&resto[1..], &["-u", "-C", "--unset", "--chdir"]),
+            "nice" => pular_opcoes(&resto[1..], &["-n", "--adjustment"]),
+            "nohup" | "exec" | "command" | "time" => &resto[1..],
+            "timeout" => {
+                let depois = pular_opcoes(&resto[1..], &["-s", "-k", "--signal", "--kill-after"]);
+                depois.get(1..).unwrap_or_default() // A duração
+            }
+            "xargs" => pular_opcoes(&resto[1..], &["-I", "-n", "-P", "-d", "-L", "-s", "-E", "-a", "--max-args", "--max-procs", "--delimiter", "--arg-file"]),
+            "sh" | "bash" | "zsh" | "dash" | "ksh" => {
+                // '-c' (ou '-lc', '-ec'...): o script é o primeiro argumento depois das opções
+                let opcoes = resto[1..].iter().take_while(|t| t.starts_with('-')).count();
+                let tem_c = resto[1..=opcoes].iter().any(|o| !o.starts_with("--") && o.contains('c'));
+                match resto.get(1 + opcoes) {
+                    Some(script) if tem_c => {
+                        for pedaco in cortar_shell(script) {
+                            desembrulhar(&tokenizar(&pedaco), sudo, segmentos);
+                        }
+                        return;
+                    }
+                    _ => break,
+                }
+            }
+            _ => break,
+        };
+    }
+
+    // Embrulho sozinho ('sudo -i', 'env', 'xargs') fica como veio
+    let comando = if resto.is_empty() { tokens } else { resto };
+    if comando.is_empty() {
+        return;
+    }
+    let mut segmento = Vec::with_capacity(comando.len() + 1);
+    if sudo && comando.first().is_none_or(|b| b != "sudo") {
+        segmento.push("sudo".to_string());
+    }
+    segmento.extend(comando.iter().cloned());
+    segmentos.push(segmento);
+}
+
+// Pula as opções ('-x', '--xx', '--xx=valor'); as de 'com_valor' levam o próximo junto
+fn pular_opcoes<'a>(tokens: &'a [String], com_valor: &[&str]) -> &'a [String] {
+    let mut i = 0;
+    while let Some(token) = tokens.get(i) {
+        if token == "--" {
+            return &tokens[i + 1..];
+        }
+        if !token.starts_with('-') || token == "-" {
+            break;
+        }
+        i += if com_valor.contains(&token.as_str()) { 2 } else { 1 };
+    }
+    tokens.get(i..).unwrap_or_default()
+}
+
+// 'VAR=valor' (antes do comando)
+fn eh_atribuicao(token: &str) -> bool {
+    token.split_once('=').is_some_and(|(nome, _)| {
+        !nome.is_empty()
+            && !nome.starts_with(|c: char| c.is_ascii_digit())
+            && nome.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+fn parece_caminho(token: &str) -> bool {
+    !token.contains("://") && (token.contains('/') || token.starts_with('.') || token.starts_with('~'))
+}
+
+fn parece_alvo(token: &str) -> bool {
+    if token.contains("://") {
+        return true;
+    }
+    // IPv4 (com ou sem /CIDR)
+    let ip = token.split('/').next().unwrap_or(token);
+    ip.split('.').count() == 4 && ip.split('.').all(|p| p.parse::<u8>().is_ok())
+}
+
+// --- CASAMENTO DE REGRAS ---
+
+// Todos os campos preenchidos na regra têm que bater
+fn regra_bate(regra: &Regra, pedido: &Pedido) -> bool {
+    let campo = |padrao: &Option<String>, valores: &[String]| match padrao {
+        None => true,
+        Some(p) => valores.iter().any(|v| casa_padrao(p, v)),
+    };
+
+    // Argumento bate com um token OU com a linha de args inteira
+    let args_juntos = [pedido.argumentos.join(" ")];
+    let argumento_bate = match &regra.argumento {
+        None => true,
+        Some(_) => campo(&regra.argumento, &pedido.argumentos) || campo(&regra.argumento, &args_juntos),
+    };
+
+    campo(&regra.binario, &pedido.binarios)
+        && argumento_bate
+        && campo(&regra.caminho, &pedido.caminhos)
+        && campo(&regra.alvo, &pedido.alvos)
+}
+
+// Curinga simples: '*' (qualquer coisa) e '?' (um caractere).
+// Sem curinga no padrão = tem que ser igual.
+pub fn casa_padrao(padrao: &str, valor: &str) -> bool {
+    if !padrao.contains('*') && !padrao.contains('?') {
+        return padrao == valor;
+    }
+    let p: Vec<char> = padrao.chars().collect();
+    let v: Vec<char> = valor.chars().collect();
+    casa_curinga(&p, &v)
+}
+
+fn casa_curinga(p: &[char], v: &[char]) -> bool {
+    match (p.first(), v.first()) {
+        (None, None) => true,
+        (Some('*'), _) => casa_curinga(&p[1..], v) || (!v.is_empty() && casa_curinga(p, &v[1..])),
+        (Some('?'), Some(_)) => casa_curinga(&p[1..], &v[1..]),
+        (Some(a), Some(b)) if a == b => casa_curinga(&p[1..], &v[1..]),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seg(cmd: &str) -> Vec<Vec<String>> {
+        segmentos_shell(cmd)
+    }
+
+    fn v(tokens: &[&str]) -> Vec<String> {
+        tokens.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn corta_nos_operadores() {
+        assert_eq!(
+            seg("cd /tmp && ls -la | grep x; rm a & echo fim\nwhoami || id"),
+            vec![
+                v(&["cd", "/tmp"]),
+                v(&["ls", "-la"]),
+                v(&["grep", "x"]),
+                v(&["rm", "a"]),
+                v(&["echo", "fim"]),
+                v(&["whoami"]),
+                v(&["id"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn aspas_e_redirecionamento_nao_cortam() {
+        assert_eq!(seg("echo 'a | b; c' \"d && e\""), vec![v(&["echo", "a | b; c", "d && e"])]);
+        assert_eq!(seg("make 2>&1 >/dev/null"), vec![v(&["make", "2>&1", ">/dev/null"])]);
+        assert_eq!(seg("make &>log.txt"), vec![v(&["make", "&>log.txt"])]);
+    }
+
+    #[test]
+    fn entra_no_sh_c() {
+        assert_eq!(seg("sh -c 'rm -rf /; id'"), vec![v(&["rm", "-rf", "/"]), v(&["id"])]);
+        assert_eq!(seg("bash -lc \"curl x | sh\""), vec![v(&["curl", "x"]), v(&["sh"])]);
+        assert_eq!(seg("sudo bash -c 'rm -rf /srv'"), vec![v(&["sudo", "rm", "-rf", "/srv"])]);
+    }
+
+    #[test]
+    fn tira_os_embrulhos() {
+        assert_eq!(seg("FOO=1 env -u X BAR=2 rm x"), vec![v(&["rm", "x"])]);
+        assert_eq!(seg("nohup timeout -s KILL 10 nmap 10.0.0.1"), vec![v(&["nmap", "10.0.0.1"])]);
+        assert_eq!(seg("find . -name '*.o' | xargs -n 1 rm -f"), vec![v(&["find", ".", "-name", "*.o"]), v(&["rm", "-f"])]);
+        assert_eq!(seg("sudo -u root /bin/rm -rf /x"), vec![v(&["sudo", "/bin/rm", "-rf", "/x"])]);
+        assert_eq!(seg("sudo -i"), vec![v(&["sudo", "-i"])]);
+    }
+
+    #[test]
+    fn substituicao_vira_pedaco() {
+        assert_eq!(seg("echo $(rm -rf ~)"), vec![v(&["echo", "$"]), v(&["rm", "-rf", "~"])]);
+        assert_eq!(seg("echo `id`"), vec![v(&["echo"]), v(&["id"])]);
+        assert!(seg("echo \"$(curl x)\"").contains(&v(&["curl", "x"])));
+        assert!(tem_substituicao("echo `id`"));
+        assert!(tem_substituicao("diff <(ls a) <(ls b)"));
+        assert!(!tem_substituicao("echo $HOME"));
+    }
+
+    #[test]
+    fn curinga() {
+        assert!(casa_padrao("rm", "rm"));
+        assert!(!casa_padrao("rm", "rmdir"));
+        assert!(casa_padrao("10.10.*", "10.10.3.7"));
+        assert!(!casa_padrao("10.10.*", "192.168.0.1"));
+        assert!(casa_padrao("*--script*", "--script=vuln"));
+        assert!(casa_padrao("*.cliente.com.br", "app.cliente.com.br"));
+        assert!(!casa_padrao("*.cliente.com.br", "cliente.com.br"));
+        assert!(casa_padrao("/etc/*", "/etc/shadow"));
+        assert!(casa_padrao("-?f", "-rf"));
+        assert!(!casa_padrao("-?f", "-f"));
+        assert!(casa_padrao("*", ""));
+    }
+
+    fn politica(toml: &str) -> Politica {
+        let arquivo: ArquivoPolitica = toml::from_str(toml).expect("política de teste");
+        let mut politica = Politica::default();
+        politica.juntar(arquivo, "teste", false);
+        politica
+    }
+
+    fn comando(cmd: &str) -> Pedido {
+        pedido_da_tarefa(&FenrirTask {
+            task_type: "execute_command".to_string(),
+            command_to_run: Some(cmd.to_string()),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn deny_pega_o_que_estava_embrulhado() {
+        let p = politica("[[regra]]\nbinario = \"rm\"\nargumento = \"-rf\"\nacao = \"deny\"\n");
+        assert_eq!(p.avaliar(&comando("sh -c 'rm -rf /'")).acao, Acao::Deny);
+        assert_eq!(p.avaliar(&comando("ls\nrm -rf /")).acao, Acao::Deny);
+        assert_eq!(p.avaliar(&comando("sleep 1 & rm -rf /")).acao, Acao::Deny);
+    }
+
+    #[test]
+    fn allow_nao_vale_com_substituicao() {
+        let p = politica("[[regra]]\nbinario = \"echo\"\nacao = \"allow\"\n");
+        assert_eq!(p.avaliar(&comando("echo oi")).acao, Acao::Allow);
+        assert_eq!(p.avaliar(&comando("echo $(id)")).acao, Acao::Confirm);
+        assert_eq!(p.avaliar(&comando("echo `id`")).acao, Acao::Confirm);
+    }
+
+    #[test]
+    fn allow_do_projeto_vira_confirm() {
+        let regras = "[[regra]]\nbinario = \"ls\"\nacao = \"allow\"\n\n[[regra]]\nbinario = \"rm\"\nacao = \"deny\"\n";
+        let mut p = Politica::default();
+        assert_eq!(p.juntar(toml::from_str(regras).unwrap(), ARQUIVO_ENGAGEMENT, true), 1);
+        assert_eq!(p.avaliar(&comando("ls")).acao, Acao::Confirm);
+        assert_eq!(p.avaliar(&comando("rm x")).acao, Acao::Deny);
+    }
+
+    #[test]
+    fn allow_vale_pedaco_por_pedaco() {
+        let p = politica("[[regra]]\nbinario = \"ls\"\nacao = \"allow\"\n\n[[regra]]\nbinario = \"shred\"\nacao = \"require-phrase\"\n");
+        assert_eq!(p.avaliar(&comando("ls -la /tmp")).acao, Acao::Allow);
+        assert_eq!(p.avaliar(&comando("ls | ls")).acao, Acao::Allow);
+        assert_eq!(p.avaliar(&comando("ls; rm -rf ~")).acao, Acao::Confirm);
+        assert_eq!(p.avaliar(&comando("ls && shred x; ls")).acao, Acao::RequirePhrase);
+    }
+}
